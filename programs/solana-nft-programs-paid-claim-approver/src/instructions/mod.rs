@@ -1,7 +1,9 @@
 pub mod close;
 pub mod init;
 pub mod pay;
+pub mod quote_claim;
 
 pub use close::*;
 pub use init::*;
 pub use pay::*;
+pub use quote_claim::*;
@@ -9,18 +9,25 @@ use anchor_spl::token::{self};
 use solana_nft_programs_payment_manager::program::SolanaNftProgramsPaymentManager;
 use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
 use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::utils::assert_payment_target;
 use solana_nft_programs_token_manager::utils::assert_payment_token_account;
+use solana_program::program::invoke;
+use solana_program::system_instruction;
 
 #[derive(Accounts)]
 pub struct PayCtx<'info> {
     #[account(constraint = claim_approver.key() == token_manager.claim_approver.expect("No claim approver found") @ ErrorCode::InvalidTokenManager)]
     token_manager: Box<Account<'info, TokenManager>>,
 
-    #[account(mut, constraint = payment_token_account.mint == claim_approver.payment_mint @ ErrorCode::InvalidPaymentTokenAccount)]
-    payment_token_account: Box<Account<'info, TokenAccount>>,
+    // payment target wallet for native payments
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    payment_token_account: UncheckedAccount<'info>,
 
-    #[account(mut, constraint = fee_collector_token_account.mint == claim_approver.payment_mint @ ErrorCode::InvalidPaymentMint)]
-    fee_collector_token_account: Box<Account<'info, TokenAccount>>,
+    // fee collector wallet for native payments
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    fee_collector_token_account: UncheckedAccount<'info>,
 
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(mut, constraint = payment_manager.key() == claim_approver.payment_manager @ ErrorCode::InvalidPaymentManager)]
@@ -31,12 +38,10 @@ pub struct PayCtx<'info> {
 
     #[account(mut)]
     payer: Signer<'info>,
-    #[account(mut, constraint =
-        payer_token_account.owner == payer.key()
-        && payer_token_account.mint == claim_approver.payment_mint
-        @ ErrorCode::InvalidPayerTokenAccount
-    )]
-    payer_token_account: Box<Account<'info, TokenAccount>>,
+    // unused for native payments
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    payer_token_account: UncheckedAccount<'info>,
 
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(mut)]
@@ -51,48 +56,97 @@ pub struct PayCtx<'info> {
 
 pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, PayCtx<'info>>) -> Result<()> {
     let remaining_accs = &mut ctx.remaining_accounts.iter();
-    assert_payment_token_account(&ctx.accounts.payment_token_account, &ctx.accounts.token_manager, remaining_accs)?;
-
     let token_manager = &mut ctx.accounts.token_manager;
     let claim_approver = &mut ctx.accounts.claim_approver;
 
-    if ctx.accounts.payment_manager.owner.key() == ctx.accounts.solana_nft_programs_payment_manager.key() {
-        let payment_mint_info = next_account_info(remaining_accs)?;
-        let payment_mint = Account::<Mint>::try_from(payment_mint_info)?;
-        if claim_approver.payment_mint != payment_mint.key() {
+    // native SOL
+    if claim_approver.payment_mint == Pubkey::default() {
+        assert_payment_target(&ctx.accounts.payment_token_account.key(), token_manager, remaining_accs)?;
+
+        if ctx.accounts.payment_manager.owner.key() == ctx.accounts.solana_nft_programs_payment_manager.key() {
+            let mint_info = next_account_info(remaining_accs)?;
+            if token_manager.mint != mint_info.key() {
+                return Err(error!(ErrorCode::InvalidMint));
+            }
+            let mint_metadata_info = next_account_info(remaining_accs)?;
+
+            let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandleNativePaymentWithRoyaltiesCtx {
+                payment_manager: ctx.accounts.payment_manager.to_account_info(),
+                fee_collector: ctx.accounts.fee_collector_token_account.to_account_info(),
+                payment_target: ctx.accounts.payment_token_account.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                mint: mint_info.to_account_info(),
+                mint_metadata: mint_metadata_info.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            };
+            let cpi_ctx =
+                CpiContext::new(ctx.accounts.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts).with_remaining_accounts(remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>());
+            solana_nft_programs_payment_manager::cpi::handle_native_payment_with_royalties(cpi_ctx, claim_approver.payment_amount)?;
+        } else {
+            invoke(
+                &system_instruction::transfer(&ctx.accounts.payer.key(), &ctx.accounts.payment_token_account.key(), claim_approver.payment_amount),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    ctx.accounts.payment_token_account.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+        }
+    } else {
+        // any SPL token
+        let payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.payment_token_account)?;
+        if payment_token_account.mint != claim_approver.payment_mint {
+            return Err(error!(ErrorCode::InvalidPaymentTokenAccount));
+        }
+        let fee_collector_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.fee_collector_token_account)?;
+        if fee_collector_token_account.mint != claim_approver.payment_mint {
             return Err(error!(ErrorCode::InvalidPaymentMint));
         }
-
-        let mint_info = next_account_info(remaining_accs)?;
-        let mint = Account::<Mint>::try_from(mint_info)?;
-        if token_manager.mint != mint.key() {
-            return Err(error!(ErrorCode::InvalidMint));
+        let payer_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.payer_token_account)?;
+        if payer_token_account.owner != ctx.accounts.payer.key() || payer_token_account.mint != claim_approver.payment_mint {
+            return Err(error!(ErrorCode::InvalidPayerTokenAccount));
+        }
+        assert_payment_token_account(&payment_token_account, token_manager, remaining_accs)?;
+
+        if ctx.accounts.payment_manager.owner.key() == ctx.accounts.solana_nft_programs_payment_manager.key() {
+            let payment_mint_info = next_account_info(remaining_accs)?;
+            let payment_mint = Account::<Mint>::try_from(payment_mint_info)?;
+            if claim_approver.payment_mint != payment_mint.key() {
+                return Err(error!(ErrorCode::InvalidPaymentMint));
+            }
+
+            // payment manager loads the rented mint as an spl token mint so token 2022 rentals cannot pay through it
+            let mint_info = next_account_info(remaining_accs)?;
+            let mint = Account::<Mint>::try_from(mint_info)?;
+            if token_manager.mint != mint.key() {
+                return Err(error!(ErrorCode::InvalidMint));
+            }
+            let mint_metadata_info = next_account_info(remaining_accs)?;
+
+            let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandlePaymentWithRoyaltiesCtx {
+                payment_manager: ctx.accounts.payment_manager.to_account_info(),
+                payer_token_account: ctx.accounts.payer_token_account.to_account_info(),
+                fee_collector_token_account: ctx.accounts.fee_collector_token_account.to_account_info(),
+                payment_token_account: ctx.accounts.payment_token_account.to_account_info(),
+                payment_mint: payment_mint.to_account_info(),
+                mint: mint.to_account_info(),
+                mint_metadata: mint_metadata_info.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            };
+            let cpi_ctx =
+                CpiContext::new(ctx.accounts.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts).with_remaining_accounts(remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>());
+            solana_nft_programs_payment_manager::cpi::handle_payment_with_royalties(cpi_ctx, claim_approver.payment_amount)?;
+        } else {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.payer_token_account.to_account_info(),
+                to: ctx.accounts.payment_token_account.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+            token::transfer(cpi_context, ctx.accounts.claim_approver.payment_amount)?;
         }
-        let mint_metadata_info = next_account_info(remaining_accs)?;
-
-        let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandlePaymentWithRoyaltiesCtx {
-            payment_manager: ctx.accounts.payment_manager.to_account_info(),
-            payer_token_account: ctx.accounts.payer_token_account.to_account_info(),
-            fee_collector_token_account: ctx.accounts.fee_collector_token_account.to_account_info(),
-            payment_token_account: ctx.accounts.payment_token_account.to_account_info(),
-            payment_mint: payment_mint.to_account_info(),
-            mint: mint.to_account_info(),
-            mint_metadata: mint_metadata_info.to_account_info(),
-            payer: ctx.accounts.payer.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        };
-        let cpi_ctx =
-            CpiContext::new(ctx.accounts.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts).with_remaining_accounts(remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>());
-        solana_nft_programs_payment_manager::cpi::handle_payment_with_royalties(cpi_ctx, claim_approver.payment_amount)?;
-    } else {
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.payer_token_account.to_account_info(),
-            to: ctx.accounts.payment_token_account.to_account_info(),
-            authority: ctx.accounts.payer.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_context, ctx.accounts.claim_approver.payment_amount)?;
     }
 
     let token_manager_key = ctx.accounts.token_manager.key();
@@ -0,0 +1,33 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_payment_manager::state::PaymentManager;
+use solana_program::program::set_return_data;
+
+#[derive(Accounts)]
+pub struct QuoteClaimCtx<'info> {
+    claim_approver: Box<Account<'info, PaidClaimApprover>>,
+
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(constraint = payment_manager.key() == claim_approver.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<QuoteClaimCtx>) -> Result<()> {
+    let payment_amount = ctx.accounts.claim_approver.payment_amount;
+    let mut total_amount = payment_amount;
+    // the taker fee is charged on top of the amount handled by the payment manager
+    if ctx.accounts.payment_manager.owner.key() == solana_nft_programs_payment_manager::id() {
+        let payment_manager = Account::<PaymentManager>::try_from(&ctx.accounts.payment_manager)?;
+        let taker_fee = payment_amount
+            .checked_mul(payment_manager.taker_fee_basis_points.into())
+            .expect("Multiplication error")
+            .checked_div(BASIS_POINTS_DIVISOR)
+            .expect("Division error");
+        total_amount = total_amount.checked_add(taker_fee).expect("Addition error");
+    }
+
+    msg!("Quoted claim for {:?}", total_amount);
+    set_return_data(&total_amount.to_le_bytes());
+    Ok(())
+}
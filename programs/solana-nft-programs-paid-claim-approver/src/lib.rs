@@ -19,6 +19,10 @@ pub mod solana_nft_programs_paid_claim_approver {
         pay::handler(ctx)
     }
 
+    pub fn quote_claim(ctx: Context<QuoteClaimCtx>) -> Result<()> {
+        quote_claim::handler(ctx)
+    }
+
     pub fn close(ctx: Context<CloseCtx>) -> Result<()> {
         close::handler(ctx)
     }
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 pub const PAID_CLAIM_APPROVER_SEED: &str = "paid-claim-approver";
 pub const PAID_CLAIM_APPROVER_SIZE: usize = 8 + std::mem::size_of::<PaidClaimApprover>();
+pub const BASIS_POINTS_DIVISOR: u64 = 10_000;
 #[account]
 pub struct PaidClaimApprover {
     pub bump: u8,
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Invalid token manager for this claim approver")]
+    InvalidTokenManager,
+    #[msg("Invalid issuer")]
+    InvalidIssuer,
+    #[msg("Invalid collector")]
+    InvalidCollector,
+    #[msg("Invalid depositor")]
+    InvalidDepositor,
+    #[msg("Token account not owned by the depositor")]
+    InvalidDepositorTokenAccount,
+    #[msg("Invalid deposit token account")]
+    InvalidDepositTokenAccount,
+    #[msg("Token account not owned by the issuer")]
+    InvalidIssuerTokenAccount,
+    #[msg("Deposit is locked while the token can still be claimed or returned")]
+    DepositLocked,
+}
@@ -0,0 +1,104 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+use solana_program::program::invoke;
+use solana_program::system_instruction;
+use spl_associated_token_account::get_associated_token_address;
+
+#[derive(Accounts)]
+pub struct DepositCtx<'info> {
+    #[account(constraint =
+        token_manager.state == TokenManagerState::Issued as u8
+        && claim_approver.key() == token_manager.claim_approver.expect("No claim approver found")
+        @ ErrorCode::InvalidTokenManager
+    )]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(constraint = claim_approver.token_manager == token_manager.key() @ ErrorCode::InvalidTokenManager)]
+    claim_approver: Box<Account<'info, SecurityDepositClaimApprover>>,
+
+    #[account(
+        init,
+        payer = depositor,
+        space = DEPOSIT_SIZE,
+        seeds = [DEPOSIT_SEED.as_bytes(), token_manager.key().as_ref(), depositor.key().as_ref()], bump,
+    )]
+    deposit: Box<Account<'info, Deposit>>,
+
+    #[account(mut)]
+    depositor: Signer<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    claim_receipt: UncheckedAccount<'info>,
+
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    // > Only when depositing spl tokens
+    // depositor_token_account
+    // deposit_token_account
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, DepositCtx<'info>>) -> Result<()> {
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    let claim_approver = &ctx.accounts.claim_approver;
+
+    let deposit = &mut ctx.accounts.deposit;
+    deposit.bump = *ctx.bumps.get("deposit").unwrap();
+    deposit.token_manager = ctx.accounts.token_manager.key();
+    deposit.depositor = ctx.accounts.depositor.key();
+    deposit.mint = claim_approver.deposit_mint;
+    deposit.amount = claim_approver.deposit_amount;
+
+    if claim_approver.deposit_mint == Pubkey::default() {
+        // native deposits are held as lamports on the deposit account on top of its rent
+        invoke(
+            &system_instruction::transfer(&ctx.accounts.depositor.key(), &deposit.key(), claim_approver.deposit_amount),
+            &[ctx.accounts.depositor.to_account_info(), deposit.to_account_info(), ctx.accounts.system_program.to_account_info()],
+        )?;
+    } else {
+        let depositor_token_account_info = next_account_info(remaining_accs)?;
+        let depositor_token_account = Account::<TokenAccount>::try_from(depositor_token_account_info)?;
+        if depositor_token_account.owner != ctx.accounts.depositor.key() || depositor_token_account.mint != claim_approver.deposit_mint {
+            return Err(error!(ErrorCode::InvalidDepositorTokenAccount));
+        }
+        let deposit_token_account_info = next_account_info(remaining_accs)?;
+        if deposit_token_account_info.key() != get_associated_token_address(&deposit.key(), &claim_approver.deposit_mint) {
+            return Err(error!(ErrorCode::InvalidDepositTokenAccount));
+        }
+
+        let cpi_accounts = Transfer {
+            from: depositor_token_account.to_account_info(),
+            to: deposit_token_account_info.to_account_info(),
+            authority: ctx.accounts.depositor.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_context, claim_approver.deposit_amount)?;
+    }
+
+    let token_manager_key = ctx.accounts.token_manager.key();
+    let claim_approver_seeds = &[SECURITY_DEPOSIT_CLAIM_APPROVER_SEED.as_bytes(), token_manager_key.as_ref(), &[claim_approver.bump]];
+    let claim_approver_signer = &[&claim_approver_seeds[..]];
+
+    // approve
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::CreateClaimReceiptCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        claim_approver: ctx.accounts.claim_approver.to_account_info(),
+        claim_receipt: ctx.accounts.claim_receipt.to_account_info(),
+        payer: ctx.accounts.depositor.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts).with_signer(claim_approver_signer);
+    solana_nft_programs_token_manager::cpi::create_claim_receipt(cpi_ctx, ctx.accounts.depositor.key())?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitIx {
+    // default pubkey for native sol deposits
+    pub deposit_mint: Pubkey,
+    pub deposit_amount: u64,
+    pub collector: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct InitCtx<'info> {
+    #[account(constraint = token_manager.state == TokenManagerState::Initialized as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SECURITY_DEPOSIT_CLAIM_APPROVER_SIZE,
+        seeds = [SECURITY_DEPOSIT_CLAIM_APPROVER_SEED.as_bytes(), token_manager.key().as_ref()], bump,
+    )]
+    claim_approver: Box<Account<'info, SecurityDepositClaimApprover>>,
+
+    #[account(mut, constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+    let claim_approver = &mut ctx.accounts.claim_approver;
+    claim_approver.bump = *ctx.bumps.get("claim_approver").unwrap();
+    claim_approver.token_manager = ctx.accounts.token_manager.key();
+    claim_approver.deposit_mint = ix.deposit_mint;
+    claim_approver.deposit_amount = ix.deposit_amount;
+    claim_approver.collector = ix.collector;
+    Ok(())
+}
@@ -0,0 +1,9 @@
+pub mod close;
+pub mod deposit;
+pub mod init;
+pub mod resolve;
+
+pub use close::*;
+pub use deposit::*;
+pub use init::*;
+pub use resolve::*;
@@ -0,0 +1,118 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+use anchor_spl::token::CloseAccount;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+use solana_nft_programs_token_manager::state::CLAIM_RECEIPT_SEED;
+
+#[derive(Accounts)]
+pub struct ResolveCtx<'info> {
+    /// CHECK: This is not dangerous because we expect it to potentially be empty
+    #[account(constraint = token_manager.key() == deposit.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    deposit: Box<Account<'info, Deposit>>,
+
+    /// CHECK: This is not dangerous because we only check whether it has been consumed
+    #[account(
+        seeds = [CLAIM_RECEIPT_SEED.as_bytes(), token_manager.key().as_ref(), deposit.depositor.as_ref()], bump,
+        seeds::program = solana_nft_programs_token_manager::id(),
+    )]
+    claim_receipt: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because this is the pubkey refunded and collecting the closing account lamports
+    #[account(mut, constraint = depositor.key() == deposit.depositor @ ErrorCode::InvalidDepositor)]
+    depositor: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked against the token manager issuer when the deposit is forfeited
+    #[account(mut)]
+    issuer: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    // > Only when the deposit is spl tokens
+    // deposit_token_account
+    // destination_token_account
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ResolveCtx<'info>>) -> Result<()> {
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+
+    // the claim receipt is closed when the depositor claims
+    let claimed = ctx.accounts.claim_receipt.data_is_empty();
+    let mut forfeit = false;
+    if !ctx.accounts.token_manager.data_is_empty() {
+        let token_manager = Account::<TokenManager>::try_from(&ctx.accounts.token_manager)?;
+        match token_manager.state {
+            // the unused receipt can still be claimed with
+            s if s == TokenManagerState::Issued as u8 && !claimed => return Err(error!(ErrorCode::DepositLocked)),
+            // the receipt was never used and can no longer be claimed with so the deposit is refunded
+            _ if !claimed => {}
+            // invalidated without being returned so the depositor keeps the token and loses the deposit
+            s if s == TokenManagerState::Invalidated as u8 => {
+                if ctx.accounts.issuer.key() != token_manager.issuer {
+                    return Err(error!(ErrorCode::InvalidIssuer));
+                }
+                forfeit = true;
+            }
+            // reissued back to the token manager after the depositor claimed
+            s if s == TokenManagerState::Issued as u8 => {}
+            _ => return Err(error!(ErrorCode::DepositLocked)),
+        }
+    }
+    let destination = if forfeit {
+        ctx.accounts.issuer.to_account_info()
+    } else {
+        ctx.accounts.depositor.to_account_info()
+    };
+
+    let deposit = &ctx.accounts.deposit;
+    let token_manager_key = ctx.accounts.token_manager.key();
+    let depositor_key = deposit.depositor;
+    let deposit_seeds = &[DEPOSIT_SEED.as_bytes(), token_manager_key.as_ref(), depositor_key.as_ref(), &[deposit.bump]];
+    let deposit_signer = &[&deposit_seeds[..]];
+
+    if deposit.mint == Pubkey::default() {
+        let deposit_info = deposit.to_account_info();
+        **deposit_info.try_borrow_mut_lamports()? = deposit_info.lamports().checked_sub(deposit.amount).expect("Sub error");
+        **destination.try_borrow_mut_lamports()? = destination.lamports().checked_add(deposit.amount).expect("Add error");
+    } else {
+        let deposit_token_account_info = next_account_info(remaining_accs)?;
+        let deposit_token_account = Account::<TokenAccount>::try_from(deposit_token_account_info)?;
+        if deposit_token_account.owner != deposit.key() || deposit_token_account.mint != deposit.mint {
+            return Err(error!(ErrorCode::InvalidDepositTokenAccount));
+        }
+        let destination_token_account_info = next_account_info(remaining_accs)?;
+        let destination_token_account = Account::<TokenAccount>::try_from(destination_token_account_info)?;
+        if destination_token_account.owner != destination.key() || destination_token_account.mint != deposit.mint {
+            return Err(error!(if forfeit { ErrorCode::InvalidIssuerTokenAccount } else { ErrorCode::InvalidDepositorTokenAccount }));
+        }
+
+        let cpi_accounts = Transfer {
+            from: deposit_token_account.to_account_info(),
+            to: destination_token_account.to_account_info(),
+            authority: deposit.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(deposit_signer);
+        // the whole vault balance so the close cannot fail on tokens sent to it directly
+        token::transfer(cpi_context, deposit_token_account.amount)?;
+
+        let cpi_accounts = CloseAccount {
+            account: deposit_token_account.to_account_info(),
+            destination: ctx.accounts.depositor.to_account_info(),
+            authority: deposit.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(deposit_signer);
+        token::close_account(cpi_context)?;
+    }
+
+    ctx.accounts.deposit.close(ctx.accounts.depositor.to_account_info())?;
+    Ok(())
+}
@@ -0,0 +1,29 @@
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+use anchor_lang::prelude::*;
+use instructions::*;
+
+declare_id!("6zMSkGgRbv4vbKiNh1RH3nT6dLgz2zLG6rULRbn1VGXB");
+
+#[program]
+pub mod solana_nft_programs_security_deposit {
+    use super::*;
+
+    pub fn init(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+        init::handler(ctx, ix)
+    }
+
+    pub fn deposit<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, DepositCtx<'info>>) -> Result<()> {
+        deposit::handler(ctx)
+    }
+
+    pub fn resolve<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ResolveCtx<'info>>) -> Result<()> {
+        resolve::handler(ctx)
+    }
+
+    pub fn close(ctx: Context<CloseCtx>) -> Result<()> {
+        close::handler(ctx)
+    }
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+pub const SECURITY_DEPOSIT_CLAIM_APPROVER_SEED: &str = "security-deposit-claim-approver";
+pub const SECURITY_DEPOSIT_CLAIM_APPROVER_SIZE: usize = 8 + std::mem::size_of::<SecurityDepositClaimApprover>() + 8;
+#[account]
+pub struct SecurityDepositClaimApprover {
+    pub bump: u8,
+    pub token_manager: Pubkey,
+    pub deposit_mint: Pubkey,
+    pub deposit_amount: u64,
+    pub collector: Pubkey,
+}
+
+pub const DEPOSIT_SEED: &str = "deposit";
+pub const DEPOSIT_SIZE: usize = 8 + std::mem::size_of::<Deposit>() + 8;
+#[account]
+pub struct Deposit {
+    pub bump: u8,
+    pub token_manager: Pubkey,
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
@@ -40,4 +40,18 @@ pub enum ErrorCode {
     InvalidMint,
     #[msg("Invalid new max expiration")]
     InvalidNewMaxExpiration,
+    #[msg("Invalid grace period")]
+    InvalidGracePeriod,
+    #[msg("Invalid overdue penalty")]
+    InvalidOverduePenalty,
+    #[msg("Invalid extension escrow")]
+    InvalidExtensionEscrow,
+    #[msg("Invalid escrow token account")]
+    InvalidEscrowTokenAccount,
+    #[msg("Token manager cannot be returned early")]
+    InvalidEarlyReturn,
+    #[msg("Invalid extension price tiers")]
+    InvalidExtensionPriceTiers,
+    #[msg("Extension escrow holds payments from a previous rental and must be released first")]
+    StaleExtensionEscrow,
 }
@@ -0,0 +1,150 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::release_from_escrow;
+use crate::utils::EscrowRelease;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use anchor_spl::token_interface::TokenAccount as InterfaceTokenAccount;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::InvalidationType;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+pub struct EarlyReturnCtx<'info> {
+    #[account(mut, constraint =
+        token_manager.state == TokenManagerState::Claimed as u8
+        && (token_manager.invalidation_type == InvalidationType::Return as u8 || token_manager.invalidation_type == InvalidationType::Reissue as u8)
+        @ ErrorCode::InvalidEarlyReturn
+    )]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, constraint = time_invalidator.token_manager == token_manager.key() @ ErrorCode::InvalidTimeInvalidator)]
+    time_invalidator: Box<Account<'info, TimeInvalidator>>,
+
+    #[account(mut, constraint = extension_escrow.time_invalidator == time_invalidator.key() @ ErrorCode::InvalidExtensionEscrow)]
+    extension_escrow: Box<Account<'info, ExtensionEscrow>>,
+    #[account(mut, constraint =
+        escrow_token_account.owner == extension_escrow.key()
+        && escrow_token_account.mint == extension_escrow.payment_mint
+        @ ErrorCode::InvalidEscrowTokenAccount
+    )]
+    escrow_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = payment_token_account.key() == extension_escrow.payment_token_account @ ErrorCode::InvalidPaymentTokenAccount)]
+    payment_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because it is checked against the escrow when released
+    #[account(mut)]
+    payment_manager: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = recipient.key() == recipient_token_account.owner @ ErrorCode::InvalidEarlyReturn)]
+    recipient: Signer<'info>,
+    #[account(mut, constraint =
+        recipient_payment_token_account.owner == recipient.key()
+        && recipient_payment_token_account.mint == extension_escrow.payment_mint
+        @ ErrorCode::InvalidPayerTokenAccount
+    )]
+    recipient_payment_token_account: Box<Account<'info, TokenAccount>>,
+
+    // programs
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    payment_token_program: Program<'info, Token>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    token_program: UncheckedAccount<'info>,
+
+    // cpi accounts
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    token_manager_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    mint: UncheckedAccount<'info>,
+    #[account(mut, constraint = recipient_token_account.key() == token_manager.recipient_token_account @ ErrorCode::InvalidEarlyReturn)]
+    recipient_token_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, EarlyReturnCtx<'info>>) -> Result<()> {
+    let time_invalidator_key = ctx.accounts.time_invalidator.key();
+    let extension_escrow_seeds = &[EXTENSION_ESCROW_SEED.as_bytes(), time_invalidator_key.as_ref(), &[ctx.accounts.extension_escrow.bump]];
+    let extension_escrow_signer = &[&extension_escrow_seeds[..]];
+
+    // time already used is paid out to the issuer and the refundable share of the unused prepaid time goes back to the recipient
+    let time_invalidator = &ctx.accounts.time_invalidator;
+    let extension_escrow = &mut ctx.accounts.extension_escrow;
+    let mut consumed_seconds = 0;
+    if extension_escrow.is_stale(&ctx.accounts.token_manager) {
+        // prepaid in an earlier rental that ended without settling the escrow so none of it is refunded here
+        consumed_seconds = extension_escrow.seconds;
+    } else if extension_escrow.seconds > 0 {
+        let expiration = time_invalidator.current_expiration(&ctx.accounts.token_manager);
+        consumed_seconds = extension_escrow.consumed_seconds(expiration, Clock::get().unwrap().unix_timestamp);
+    }
+    let unused_amount = extension_escrow.amount.checked_sub(extension_escrow.amount_for_seconds(consumed_seconds)).expect("Sub error");
+    let mut refund_amount = 0;
+    if time_invalidator.escrow_basis_points() > 0 {
+        refund_amount = (unused_amount as u128)
+            .checked_mul(time_invalidator.refund_basis_points.unwrap_or(0) as u128)
+            .expect("Multiplication error")
+            .checked_div(time_invalidator.escrow_basis_points() as u128)
+            .expect("Division error") as u64;
+    }
+    let issuer_amount = extension_escrow.amount.checked_sub(refund_amount).expect("Sub error");
+    msg!(
+        "Returning early with {:?} unused seconds refunding {:?}",
+        extension_escrow.seconds.checked_sub(consumed_seconds).expect("Sub error"),
+        refund_amount
+    );
+    extension_escrow.amount = 0;
+    extension_escrow.seconds = 0;
+
+    // issuer share goes through the payment manager and the recipient gets back everything left including the taker fee held for the refunded share
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    let release = EscrowRelease {
+        extension_escrow: &ctx.accounts.extension_escrow,
+        extension_escrow_info: &ctx.accounts.extension_escrow.to_account_info(),
+        escrow_token_account: &ctx.accounts.escrow_token_account.to_account_info(),
+        payment_token_account: &ctx.accounts.payment_token_account.to_account_info(),
+        payment_manager: &ctx.accounts.payment_manager.to_account_info(),
+        token_program: &ctx.accounts.payment_token_program.to_account_info(),
+        signer: extension_escrow_signer,
+    };
+    release_from_escrow(&release, remaining_accs, issuer_amount)?;
+
+    if refund_amount > 0 {
+        ctx.accounts.escrow_token_account.reload()?;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            to: ctx.accounts.recipient_payment_token_account.to_account_info(),
+            authority: ctx.accounts.extension_escrow.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.payment_token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(extension_escrow_signer);
+        token::transfer(cpi_context, ctx.accounts.escrow_token_account.amount)?;
+    }
+
+    let token_manager_key = ctx.accounts.token_manager.key();
+    let time_invalidator_seeds = &[TIME_INVALIDATOR_SEED.as_bytes(), token_manager_key.as_ref(), &[ctx.accounts.time_invalidator.bump]];
+    let time_invalidator_signer = &[&time_invalidator_seeds[..]];
+    ctx.accounts.time_invalidator.expiration = None;
+
+    // invalidate
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::InvalidateCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        token_manager_token_account: ctx.accounts.token_manager_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        recipient_token_account: ctx.accounts.recipient_token_account.to_account_info(),
+        invalidator: ctx.accounts.time_invalidator.to_account_info(),
+        collector: ctx.accounts.recipient.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts)
+        .with_remaining_accounts(remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>())
+        .with_signer(time_invalidator_signer);
+    solana_nft_programs_token_manager::cpi::invalidate(cpi_ctx)?;
+
+    Ok(())
+}
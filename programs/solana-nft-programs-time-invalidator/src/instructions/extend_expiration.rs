@@ -1,5 +1,6 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::escrow_taker_fee;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 use anchor_spl::token::Token;
@@ -9,7 +10,11 @@ use anchor_spl::token::{self};
 use solana_nft_programs_payment_manager::program::SolanaNftProgramsPaymentManager;
 use solana_nft_programs_token_manager::state::TokenManager;
 use solana_nft_programs_token_manager::state::TokenManagerState;
+use solana_nft_programs_token_manager::utils::assert_payment_target;
 use solana_nft_programs_token_manager::utils::assert_payment_token_account;
+use solana_program::program::invoke;
+use solana_program::system_instruction;
+use spl_associated_token_account::get_associated_token_address;
 use std::cmp::max;
 
 #[derive(Accounts)]
@@ -24,44 +29,119 @@ pub struct ExtendExpirationCtx<'info> {
     #[account(mut, constraint = payment_manager.key() == time_invalidator.payment_manager @ ErrorCode::InvalidPaymentManager)]
     payment_manager: UncheckedAccount<'info>,
 
-    #[account(mut, constraint = payment_token_account.mint == time_invalidator.extension_payment_mint.expect("No extension mint") @ ErrorCode::InvalidPaymentTokenAccount)]
-    payment_token_account: Box<Account<'info, TokenAccount>>,
-    #[account(mut, constraint = fee_collector_token_account.mint == time_invalidator.extension_payment_mint.unwrap() @ ErrorCode::InvalidPaymentMint)]
-    fee_collector_token_account: Box<Account<'info, TokenAccount>>,
+    // payment target wallet for native payments
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    payment_token_account: UncheckedAccount<'info>,
+    // fee collector wallet for native payments
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    fee_collector_token_account: UncheckedAccount<'info>,
 
     #[account(mut)]
     payer: Signer<'info>,
-    #[account(mut, constraint =
-      payer_token_account.owner == payer.key()
-      && payer_token_account.mint == time_invalidator.extension_payment_mint.expect("No extension mint")
-      @ ErrorCode::InvalidPayerTokenAccount
-    )]
-    payer_token_account: Box<Account<'info, TokenAccount>>,
+    // unused for native payments
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    payer_token_account: UncheckedAccount<'info>,
 
     token_program: Program<'info, Token>,
     solana_nft_programs_payment_manager: Program<'info, SolanaNftProgramsPaymentManager>,
+    system_program: Program<'info, System>,
 }
 
 pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ExtendExpirationCtx<'info>>, seconds_to_add: u64) -> Result<()> {
     let remaining_accs = &mut ctx.remaining_accounts.iter();
-    assert_payment_token_account(&ctx.accounts.payment_token_account, &ctx.accounts.token_manager, remaining_accs)?;
-
     let token_manager = &mut ctx.accounts.token_manager;
     let time_invalidator = &mut ctx.accounts.time_invalidator;
     if time_invalidator.extension_payment_amount.is_none() || time_invalidator.extension_duration_seconds.is_none() || time_invalidator.extension_payment_mint.is_none() {
         return Err(error!(ErrorCode::InvalidTimeInvalidator));
     }
 
-    let price_to_pay = seconds_to_add
-        .checked_mul(time_invalidator.extension_payment_amount.expect("No extension amount"))
-        .expect("Multiplication error")
-        .checked_div(time_invalidator.extension_duration_seconds.expect("No extension duration"))
-        .expect("Division error");
+    let extension_payment_mint = time_invalidator.extension_payment_mint.unwrap();
+    // native SOL
+    let is_native = extension_payment_mint == Pubkey::default();
+    if is_native {
+        assert_payment_target(&ctx.accounts.payment_token_account.key(), token_manager, remaining_accs)?;
+    } else {
+        let payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.payment_token_account)?;
+        if payment_token_account.mint != extension_payment_mint {
+            return Err(error!(ErrorCode::InvalidPaymentTokenAccount));
+        }
+        let fee_collector_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.fee_collector_token_account)?;
+        if fee_collector_token_account.mint != extension_payment_mint {
+            return Err(error!(ErrorCode::InvalidPaymentMint));
+        }
+        let payer_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.payer_token_account)?;
+        if payer_token_account.owner != ctx.accounts.payer.key() || payer_token_account.mint != extension_payment_mint {
+            return Err(error!(ErrorCode::InvalidPayerTokenAccount));
+        }
+        assert_payment_token_account(&payment_token_account, token_manager, remaining_accs)?;
+    }
 
-    if price_to_pay == 0 && time_invalidator.extension_payment_amount.unwrap() > 0 {
+    if time_invalidator.extension_price(seconds_to_add) == 0 && time_invalidator.extension_payment_amount.unwrap() > 0 {
         return Err(error!(ErrorCode::InvalidExtensionAmount));
     }
-    msg!("Extending by {:?} seconds by paying {:?}", seconds_to_add, price_to_pay);
+
+    let extension_payment = time_invalidator.extension_payment(token_manager, seconds_to_add, Clock::get().unwrap().unix_timestamp);
+    let price_to_pay = extension_payment.payment_amount;
+    let escrow_amount = extension_payment.escrow_amount;
+    let collector_penalty = extension_payment.collector_penalty;
+    let overdue_penalty = extension_payment.overdue_penalty;
+    if collector_penalty > 0 && is_native {
+        let collector_info = next_account_info(remaining_accs)?;
+        if collector_info.key() != time_invalidator.collector {
+            return Err(error!(ErrorCode::InvalidCollector));
+        }
+        invoke(
+            &system_instruction::transfer(&ctx.accounts.payer.key(), &collector_info.key(), collector_penalty),
+            &[ctx.accounts.payer.to_account_info(), collector_info.to_account_info(), ctx.accounts.system_program.to_account_info()],
+        )?;
+    } else if collector_penalty > 0 {
+        let collector_token_account_info = next_account_info(remaining_accs)?;
+        let collector_token_account = Account::<TokenAccount>::try_from(collector_token_account_info)?;
+        if collector_token_account.owner != time_invalidator.collector || collector_token_account.mint != extension_payment_mint {
+            return Err(error!(ErrorCode::InvalidCollector));
+        }
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.payer_token_account.to_account_info(),
+            to: collector_token_account.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_context, collector_penalty)?;
+    }
+    msg!("Extending by {:?} seconds by paying {:?} with overdue penalty {:?}", seconds_to_add, price_to_pay, overdue_penalty);
+
+    if time_invalidator.uses_escrow() {
+        let extension_escrow_info = next_account_info(remaining_accs)?;
+        let mut extension_escrow = Account::<ExtensionEscrow>::try_from(extension_escrow_info)?;
+        if extension_escrow.time_invalidator != time_invalidator.key() {
+            return Err(error!(ErrorCode::InvalidExtensionEscrow));
+        }
+        if extension_escrow.is_stale(token_manager) {
+            return Err(error!(ErrorCode::StaleExtensionEscrow));
+        }
+        let escrow_token_account_info = next_account_info(remaining_accs)?;
+        if escrow_token_account_info.key() != get_associated_token_address(&extension_escrow.key(), &extension_escrow.payment_mint) {
+            return Err(error!(ErrorCode::InvalidEscrowTokenAccount));
+        }
+        let taker_fee = escrow_taker_fee(&ctx.accounts.payment_manager, escrow_amount)?;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.payer_token_account.to_account_info(),
+            to: escrow_token_account_info.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_context, escrow_amount.checked_add(taker_fee).expect("Addition error"))?;
+
+        extension_escrow.amount = extension_escrow.amount.checked_add(escrow_amount).expect("Addition error");
+        extension_escrow.seconds = extension_escrow.seconds.checked_add(seconds_to_add).expect("Addition error");
+        extension_escrow.claimed_at = token_manager.state_changed_at;
+        extension_escrow.exit(ctx.program_id)?;
+    }
 
     if time_invalidator.disable_partial_extension.is_some()
         && time_invalidator.disable_partial_extension.unwrap()
@@ -86,13 +166,42 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         return Err(error!(ErrorCode::InvalidExtendExpiration));
     }
 
-    if ctx.accounts.payment_manager.owner.key() == ctx.accounts.solana_nft_programs_payment_manager.key() {
+    if is_native && ctx.accounts.payment_manager.owner.key() == ctx.accounts.solana_nft_programs_payment_manager.key() {
+        let mint_info = next_account_info(remaining_accs)?;
+        if token_manager.mint != mint_info.key() {
+            return Err(error!(ErrorCode::InvalidMint));
+        }
+        let mint_metadata_info = next_account_info(remaining_accs)?;
+
+        let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandleNativePaymentWithRoyaltiesCtx {
+            payment_manager: ctx.accounts.payment_manager.to_account_info(),
+            fee_collector: ctx.accounts.fee_collector_token_account.to_account_info(),
+            payment_target: ctx.accounts.payment_token_account.to_account_info(),
+            payer: ctx.accounts.payer.to_account_info(),
+            mint: mint_info.to_account_info(),
+            mint_metadata: mint_metadata_info.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+        let cpi_ctx =
+            CpiContext::new(ctx.accounts.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts).with_remaining_accounts(remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>());
+        solana_nft_programs_payment_manager::cpi::handle_native_payment_with_royalties(cpi_ctx, price_to_pay)?;
+    } else if is_native {
+        invoke(
+            &system_instruction::transfer(&ctx.accounts.payer.key(), &ctx.accounts.payment_token_account.key(), price_to_pay),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.payment_token_account.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    } else if ctx.accounts.payment_manager.owner.key() == ctx.accounts.solana_nft_programs_payment_manager.key() {
         let payment_mint_info = next_account_info(remaining_accs)?;
         let payment_mint = Account::<Mint>::try_from(payment_mint_info)?;
-        if time_invalidator.extension_payment_mint.unwrap() != payment_mint.key() {
+        if extension_payment_mint != payment_mint.key() {
             return Err(error!(ErrorCode::InvalidPaymentMint));
         }
 
+        // payment manager loads the rented mint as an spl token mint so token 2022 rentals cannot pay through it
         let mint_info = next_account_info(remaining_accs)?;
         let mint = Account::<Mint>::try_from(mint_info)?;
         if token_manager.mint != mint.key() {
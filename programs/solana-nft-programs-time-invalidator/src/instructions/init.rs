@@ -14,6 +14,14 @@ pub struct InitIx {
     pub extension_payment_mint: Option<Pubkey>,
     pub max_expiration: Option<i64>,
     pub disable_partial_extension: Option<bool>,
+    pub grace_period_seconds: Option<i64>,
+    pub grace_period_penalty_basis_points: Option<u16>,
+    pub overdue_penalty_amount: Option<u64>,
+    pub overdue_penalty_duration_seconds: Option<u64>,
+    pub overdue_penalty_collector_basis_points: Option<u16>,
+    pub refund_basis_points: Option<u16>,
+    pub escrow_extension_payments: Option<bool>,
+    pub extension_price_tiers: Option<Vec<ExtensionPriceTier>>,
 }
 
 #[derive(Accounts)]
@@ -43,6 +51,34 @@ pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
         return Err(error!(ErrorCode::InvalidInstruction));
     } else if ix.extension_payment_amount.is_some() && ix.extension_payment_mint.is_none() {
         return Err(error!(ErrorCode::InvalidInstruction));
+    } else if ix.grace_period_seconds.is_some() && (ix.grace_period_seconds.unwrap() < 0 || ix.duration_seconds.is_none()) {
+        return Err(error!(ErrorCode::InvalidGracePeriod));
+    } else if ix.grace_period_penalty_basis_points.is_some() && ix.grace_period_seconds.is_none() {
+        return Err(error!(ErrorCode::InvalidGracePeriod));
+    } else if ix.overdue_penalty_amount.is_some() != ix.overdue_penalty_duration_seconds.is_some() || (ix.overdue_penalty_amount.is_some() && ix.extension_payment_amount.is_none()) {
+        return Err(error!(ErrorCode::InvalidOverduePenalty));
+    } else if ix.overdue_penalty_duration_seconds == Some(0) || ix.overdue_penalty_collector_basis_points.unwrap_or(0) as u64 > BASIS_POINTS_DIVISOR {
+        return Err(error!(ErrorCode::InvalidOverduePenalty));
+    } else if ix.refund_basis_points.is_some() && (ix.extension_payment_amount.is_none() || ix.refund_basis_points.unwrap() as u64 > BASIS_POINTS_DIVISOR) {
+        return Err(error!(ErrorCode::InvalidInstruction));
+    } else if ix.escrow_extension_payments == Some(true) && ix.extension_payment_amount.is_none() {
+        return Err(error!(ErrorCode::InvalidInstruction));
+    } else if (ix.refund_basis_points.is_some() || ix.escrow_extension_payments == Some(true)) && ix.extension_payment_mint == Some(Pubkey::default()) {
+        // escrowed extensions are held in a token account so they cannot be paid in native SOL
+        return Err(error!(ErrorCode::InvalidInstruction));
+    }
+    if let Some(extension_price_tiers) = &ix.extension_price_tiers {
+        if ix.extension_payment_amount.is_none() || extension_price_tiers.is_empty() || extension_price_tiers.len() > MAX_EXTENSION_PRICE_TIERS {
+            return Err(error!(ErrorCode::InvalidExtensionPriceTiers));
+        }
+        // tiers must be sorted by threshold so the blended price can be computed in a single pass
+        let mut previous_threshold_seconds = 0;
+        for tier in extension_price_tiers {
+            if tier.threshold_seconds <= previous_threshold_seconds || tier.duration_seconds == 0 {
+                return Err(error!(ErrorCode::InvalidExtensionPriceTiers));
+            }
+            previous_threshold_seconds = tier.threshold_seconds;
+        }
     }
     // discriminator check
     let time_invalidator = &mut ctx.accounts.time_invalidator;
@@ -57,5 +93,13 @@ pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
     time_invalidator.extension_payment_mint = ix.extension_payment_mint;
     time_invalidator.max_expiration = ix.max_expiration;
     time_invalidator.disable_partial_extension = ix.disable_partial_extension;
+    time_invalidator.grace_period_seconds = ix.grace_period_seconds;
+    time_invalidator.grace_period_penalty_basis_points = ix.grace_period_penalty_basis_points;
+    time_invalidator.overdue_penalty_amount = ix.overdue_penalty_amount;
+    time_invalidator.overdue_penalty_duration_seconds = ix.overdue_penalty_duration_seconds;
+    time_invalidator.overdue_penalty_collector_basis_points = ix.overdue_penalty_collector_basis_points;
+    time_invalidator.refund_basis_points = ix.refund_basis_points;
+    time_invalidator.escrow_extension_payments = ix.escrow_extension_payments;
+    time_invalidator.extension_price_tiers = ix.extension_price_tiers;
     Ok(())
 }
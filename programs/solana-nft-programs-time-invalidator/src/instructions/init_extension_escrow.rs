@@ -0,0 +1,61 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Mint;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::utils::assert_payment_token_account;
+
+#[derive(Accounts)]
+pub struct InitExtensionEscrowCtx<'info> {
+    #[account(constraint = time_invalidator.uses_escrow() @ ErrorCode::InvalidTimeInvalidator)]
+    time_invalidator: Box<Account<'info, TimeInvalidator>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = EXTENSION_ESCROW_SIZE,
+        seeds = [EXTENSION_ESCROW_SEED.as_bytes(), time_invalidator.key().as_ref()], bump,
+    )]
+    extension_escrow: Box<Account<'info, ExtensionEscrow>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = extension_escrow,
+    )]
+    escrow_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(constraint = time_invalidator.extension_payment_mint == Some(payment_mint.key()) @ ErrorCode::InvalidPaymentMint)]
+    payment_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    system_program: Program<'info, System>,
+
+    #[account(constraint = token_manager.key() == time_invalidator.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+    #[account(constraint = payment_token_account.mint == payment_mint.key() @ ErrorCode::InvalidPaymentTokenAccount)]
+    payment_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = payment_manager.key() == time_invalidator.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<InitExtensionEscrowCtx>) -> Result<()> {
+    // released and settled escrow always goes to the payment target fixed here, not to whoever extends
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    assert_payment_token_account(&ctx.accounts.payment_token_account, &ctx.accounts.token_manager, remaining_accs)?;
+
+    let extension_escrow = &mut ctx.accounts.extension_escrow;
+    extension_escrow.bump = *ctx.bumps.get("extension_escrow").unwrap();
+    extension_escrow.time_invalidator = ctx.accounts.time_invalidator.key();
+    extension_escrow.payment_mint = ctx.accounts.payment_mint.key();
+    extension_escrow.payment_token_account = ctx.accounts.payment_token_account.key();
+    extension_escrow.payment_manager = ctx.accounts.payment_manager.key();
+    extension_escrow.mint = ctx.accounts.token_manager.mint;
+    Ok(())
+}
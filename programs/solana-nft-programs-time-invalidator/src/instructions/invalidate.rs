@@ -1,21 +1,16 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::settle_extension_escrow;
 use anchor_lang::prelude::*;
 use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
 use solana_nft_programs_token_manager::state::TokenManager;
-use solana_nft_programs_token_manager::state::TokenManagerState;
 
 #[derive(Accounts)]
 pub struct InvalidateCtx<'info> {
     #[account(mut)]
     token_manager: Box<Account<'info, TokenManager>>,
 
-    #[account(mut,
-        constraint = (time_invalidator.max_expiration.is_some() && Clock::get().unwrap().unix_timestamp >= time_invalidator.max_expiration.unwrap())
-        || (time_invalidator.expiration.is_some() && token_manager.state == TokenManagerState::Claimed as u8 && Clock::get().unwrap().unix_timestamp >= time_invalidator.expiration.unwrap())
-        || (time_invalidator.expiration.is_none() && token_manager.state == TokenManagerState::Claimed as u8 && Clock::get().unwrap().unix_timestamp >= token_manager.state_changed_at.checked_add(time_invalidator.duration_seconds.expect("No extension duration")).expect("Addition error"))
-        @ ErrorCode::InvalidTimeInvalidator
-    )]
+    #[account(mut, constraint = time_invalidator.is_expired(&token_manager, Clock::get().unwrap().unix_timestamp) @ ErrorCode::InvalidTimeInvalidator)]
     time_invalidator: Box<Account<'info, TimeInvalidator>>,
 
     /// CHECK: This is not dangerous because we don't read or write from this account
@@ -40,6 +35,9 @@ pub struct InvalidateCtx<'info> {
 }
 
 pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateCtx<'info>>) -> Result<()> {
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    settle_extension_escrow(&ctx.accounts.time_invalidator, remaining_accs)?;
+
     let token_manager_key = ctx.accounts.token_manager.key();
     let time_invalidator_seeds = &[TIME_INVALIDATOR_SEED.as_bytes(), token_manager_key.as_ref(), &[ctx.accounts.time_invalidator.bump]];
     let time_invalidator_signer = &[&time_invalidator_seeds[..]];
@@ -57,7 +55,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         rent: ctx.accounts.rent.to_account_info(),
     };
     let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts)
-        .with_remaining_accounts(ctx.remaining_accounts.to_vec())
+        .with_remaining_accounts(remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>())
         .with_signer(time_invalidator_signer);
     solana_nft_programs_token_manager::cpi::invalidate(cpi_ctx)?;
 
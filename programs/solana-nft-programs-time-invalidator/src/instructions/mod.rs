@@ -1,13 +1,25 @@
+pub mod batch_invalidate;
 pub mod close;
+pub mod early_return;
 pub mod extend_expiration;
 pub mod init;
+pub mod init_extension_escrow;
 pub mod invalidate;
+pub mod quote_extension;
+pub mod release_escrow;
 pub mod reset_expiration;
+pub mod resize;
 pub mod update_max_expiration;
 
+pub use batch_invalidate::*;
 pub use close::*;
+pub use early_return::*;
 pub use extend_expiration::*;
 pub use init::*;
+pub use init_extension_escrow::*;
 pub use invalidate::*;
+pub use quote_extension::*;
+pub use release_escrow::*;
 pub use reset_expiration::*;
+pub use resize::*;
 pub use update_max_expiration::*;
@@ -0,0 +1,54 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::escrow_taker_fee;
+use anchor_lang::prelude::*;
+use solana_nft_programs_payment_manager::state::PaymentManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+use solana_program::program::set_return_data;
+
+#[derive(Accounts)]
+pub struct QuoteExtensionCtx<'info> {
+    #[account(constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(constraint = time_invalidator.token_manager == token_manager.key() @ ErrorCode::InvalidTimeInvalidator)]
+    time_invalidator: Box<Account<'info, TimeInvalidator>>,
+
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(constraint = payment_manager.key() == time_invalidator.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<QuoteExtensionCtx>, seconds_to_add: u64) -> Result<()> {
+    let token_manager = &ctx.accounts.token_manager;
+    let time_invalidator = &ctx.accounts.time_invalidator;
+    if time_invalidator.extension_payment_amount.is_none() || time_invalidator.extension_duration_seconds.is_none() || time_invalidator.extension_payment_mint.is_none() {
+        return Err(error!(ErrorCode::InvalidTimeInvalidator));
+    }
+    if time_invalidator.extension_price(seconds_to_add) == 0 && time_invalidator.extension_payment_amount.unwrap() > 0 {
+        return Err(error!(ErrorCode::InvalidExtensionAmount));
+    }
+
+    let extension_payment = time_invalidator.extension_payment(token_manager, seconds_to_add, Clock::get().unwrap().unix_timestamp);
+    // escrowed payments hold the taker fee they pay when released
+    let mut total_amount = extension_payment
+        .total()
+        .checked_add(escrow_taker_fee(&ctx.accounts.payment_manager, extension_payment.escrow_amount)?)
+        .expect("Addition error");
+    // the taker fee is charged on top of the amount handled by the payment manager
+    if ctx.accounts.payment_manager.owner.key() == solana_nft_programs_payment_manager::id() {
+        let payment_manager = Account::<PaymentManager>::try_from(&ctx.accounts.payment_manager)?;
+        let taker_fee = extension_payment
+            .payment_amount
+            .checked_mul(payment_manager.taker_fee_basis_points.into())
+            .expect("Multiplication error")
+            .checked_div(BASIS_POINTS_DIVISOR)
+            .expect("Division error");
+        total_amount = total_amount.checked_add(taker_fee).expect("Addition error");
+    }
+
+    msg!("Quoted {:?} seconds for {:?}", seconds_to_add, total_amount);
+    set_return_data(&total_amount.to_le_bytes());
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::release_from_escrow;
+use crate::utils::EscrowRelease;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+pub struct ReleaseEscrowCtx<'info> {
+    /// CHECK: This is not dangerous because we expect it to potentially be empty
+    token_manager: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because we expect it to potentially be closed
+    #[account(constraint = time_invalidator.key() == extension_escrow.time_invalidator @ ErrorCode::InvalidTimeInvalidator)]
+    time_invalidator: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    extension_escrow: Box<Account<'info, ExtensionEscrow>>,
+    #[account(mut, constraint =
+        escrow_token_account.owner == extension_escrow.key()
+        && escrow_token_account.mint == extension_escrow.payment_mint
+        @ ErrorCode::InvalidEscrowTokenAccount
+    )]
+    escrow_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = payment_token_account.key() == extension_escrow.payment_token_account @ ErrorCode::InvalidPaymentTokenAccount)]
+    payment_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This is not dangerous because it is checked against the escrow when released
+    #[account(mut)]
+    payment_manager: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ReleaseEscrowCtx<'info>>) -> Result<()> {
+    let time_invalidator_key = ctx.accounts.time_invalidator.key();
+    let extension_escrow_seeds = &[EXTENSION_ESCROW_SEED.as_bytes(), time_invalidator_key.as_ref(), &[ctx.accounts.extension_escrow.bump]];
+    let extension_escrow_signer = &[&extension_escrow_seeds[..]];
+
+    // while the rental is running the issuer can claim the time used so far, once it is over everything left belongs to the issuer
+    let mut release_seconds = ctx.accounts.extension_escrow.seconds;
+    if !ctx.accounts.time_invalidator.data_is_empty() {
+        let time_invalidator = Account::<TimeInvalidator>::try_from(&ctx.accounts.time_invalidator)?;
+        if ctx.accounts.token_manager.key() != time_invalidator.token_manager {
+            return Err(error!(ErrorCode::InvalidTokenManager));
+        }
+        if !ctx.accounts.token_manager.data_is_empty() {
+            let token_manager = Account::<TokenManager>::try_from(&ctx.accounts.token_manager)?;
+            // an escrow left over from an earlier rental is released in full
+            if token_manager.state == TokenManagerState::Claimed as u8 && ctx.accounts.extension_escrow.seconds > 0 && !ctx.accounts.extension_escrow.is_stale(&token_manager) {
+                let expiration = time_invalidator.current_expiration(&token_manager);
+                release_seconds = ctx.accounts.extension_escrow.consumed_seconds(expiration, Clock::get().unwrap().unix_timestamp);
+            }
+        }
+    }
+
+    let extension_escrow = &mut ctx.accounts.extension_escrow;
+    let release_amount = extension_escrow.amount_for_seconds(release_seconds);
+    extension_escrow.amount = extension_escrow.amount.checked_sub(release_amount).expect("Sub error");
+    extension_escrow.seconds = extension_escrow.seconds.checked_sub(release_seconds).expect("Sub error");
+
+    let release = EscrowRelease {
+        extension_escrow: &ctx.accounts.extension_escrow,
+        extension_escrow_info: &ctx.accounts.extension_escrow.to_account_info(),
+        escrow_token_account: &ctx.accounts.escrow_token_account.to_account_info(),
+        payment_token_account: &ctx.accounts.payment_token_account.to_account_info(),
+        payment_manager: &ctx.accounts.payment_manager.to_account_info(),
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        signer: extension_escrow_signer,
+    };
+    release_from_escrow(&release, &mut ctx.remaining_accounts.iter(), release_amount)
+}
@@ -0,0 +1,43 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use solana_program::program::invoke;
+use solana_program::system_instruction;
+
+#[derive(Accounts)]
+pub struct ResizeCtx<'info> {
+    // time invalidators created before the latest fields were added may not fit them
+    /// CHECK: This is not dangerous because the owner and discriminator are checked in the handler
+    #[account(mut, owner = crate::id() @ ErrorCode::InvalidTimeInvalidator)]
+    time_invalidator: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ResizeCtx>) -> Result<()> {
+    let time_invalidator_info = ctx.accounts.time_invalidator.to_account_info();
+    if time_invalidator_info.data_len() < 8 || time_invalidator_info.try_borrow_data()?[..8] != TimeInvalidator::DISCRIMINATOR {
+        return Err(error!(ErrorCode::InvalidTimeInvalidator));
+    }
+    if time_invalidator_info.data_len() >= TIME_INVALIDATOR_SIZE {
+        return Ok(());
+    }
+
+    let rent_lamports = Rent::get()?.minimum_balance(TIME_INVALIDATOR_SIZE).saturating_sub(time_invalidator_info.lamports());
+    if rent_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(&ctx.accounts.payer.key(), &time_invalidator_info.key(), rent_lamports),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                time_invalidator_info.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+    // new bytes are zeroed so the added fields read as none
+    time_invalidator_info.realloc(TIME_INVALIDATOR_SIZE, true)?;
+    Ok(())
+}
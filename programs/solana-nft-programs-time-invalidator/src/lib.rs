@@ -1,6 +1,7 @@
 pub mod errors;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
 use instructions::*;
@@ -19,6 +20,10 @@ pub mod solana_nft_programs_time_invalidator {
         extend_expiration::handler(ctx, seconds_to_add)
     }
 
+    pub fn quote_extension(ctx: Context<QuoteExtensionCtx>, seconds_to_add: u64) -> Result<()> {
+        quote_extension::handler(ctx, seconds_to_add)
+    }
+
     pub fn reset_expiration(ctx: Context<ResetExpirationCtx>) -> Result<()> {
         reset_expiration::handler(ctx)
     }
@@ -31,6 +36,26 @@ pub mod solana_nft_programs_time_invalidator {
         invalidate::handler(ctx)
     }
 
+    pub fn batch_invalidate<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, BatchInvalidateCtx<'info>>, ix: BatchInvalidateIx) -> Result<()> {
+        batch_invalidate::handler(ctx, ix)
+    }
+
+    pub fn init_extension_escrow(ctx: Context<InitExtensionEscrowCtx>) -> Result<()> {
+        init_extension_escrow::handler(ctx)
+    }
+
+    pub fn early_return<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, EarlyReturnCtx<'info>>) -> Result<()> {
+        early_return::handler(ctx)
+    }
+
+    pub fn release_escrow<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ReleaseEscrowCtx<'info>>) -> Result<()> {
+        release_escrow::handler(ctx)
+    }
+
+    pub fn resize(ctx: Context<ResizeCtx>) -> Result<()> {
+        resize::handler(ctx)
+    }
+
     pub fn close(ctx: Context<CloseCtx>) -> Result<()> {
         close::handler(ctx)
     }
@@ -1,7 +1,19 @@
 use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
 
 pub const TIME_INVALIDATOR_SEED: &str = "time-invalidator";
-pub const TIME_INVALIDATOR_SIZE: usize = 8 + std::mem::size_of::<TimeInvalidator>() + 8;
+pub const TIME_INVALIDATOR_SIZE: usize = 8 + std::mem::size_of::<TimeInvalidator>() + MAX_EXTENSION_PRICE_TIERS * std::mem::size_of::<ExtensionPriceTier>() + 8;
+pub const BASIS_POINTS_DIVISOR: u64 = 10_000;
+pub const MAX_EXTENSION_PRICE_TIERS: usize = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct ExtensionPriceTier {
+    pub threshold_seconds: u64,
+    pub payment_amount: u64,
+    pub duration_seconds: u64,
+}
+
 #[account]
 pub struct TimeInvalidator {
     pub bump: u8,
@@ -15,4 +27,206 @@ pub struct TimeInvalidator {
     pub extension_payment_mint: Option<Pubkey>,
     pub max_expiration: Option<i64>,
     pub disable_partial_extension: Option<bool>,
+    pub grace_period_seconds: Option<i64>,
+    pub grace_period_penalty_basis_points: Option<u16>,
+    pub overdue_penalty_amount: Option<u64>,
+    pub overdue_penalty_duration_seconds: Option<u64>,
+    pub overdue_penalty_collector_basis_points: Option<u16>,
+    pub refund_basis_points: Option<u16>,
+    pub escrow_extension_payments: Option<bool>,
+    pub extension_price_tiers: Option<Vec<ExtensionPriceTier>>,
+}
+
+pub struct ExtensionPayment {
+    // paid to the payment target, through the payment manager if one is set
+    pub payment_amount: u64,
+    pub escrow_amount: u64,
+    // collector share of the overdue penalty paid straight to the collector
+    pub collector_penalty: u64,
+    pub overdue_penalty: u64,
+}
+
+impl ExtensionPayment {
+    pub fn total(&self) -> u64 {
+        self.payment_amount
+            .checked_add(self.escrow_amount)
+            .expect("Addition error")
+            .checked_add(self.collector_penalty)
+            .expect("Addition error")
+    }
+}
+
+impl TimeInvalidator {
+    pub fn extension_payment(&self, token_manager: &TokenManager, seconds_to_add: u64, now: i64) -> ExtensionPayment {
+        let mut price = self.extension_price(seconds_to_add);
+        // extending a rental that has lapsed but is still within its grace period is charged the penalty rate
+        if self.is_in_grace_period(token_manager, now) && self.grace_period_penalty_basis_points.is_some() {
+            let penalty_basis_points = self.grace_period_penalty_basis_points.unwrap() as u64;
+            price = price
+                .checked_mul(BASIS_POINTS_DIVISOR.checked_add(penalty_basis_points).expect("Addition error"))
+                .expect("Multiplication error")
+                .checked_div(BASIS_POINTS_DIVISOR)
+                .expect("Division error");
+        }
+
+        // the escrowed share of the extension is held until the time it paid for is used
+        let escrow_amount = price
+            .checked_mul(self.escrow_basis_points())
+            .expect("Multiplication error")
+            .checked_div(BASIS_POINTS_DIVISOR)
+            .expect("Division error");
+
+        // penalty accrued since expiration is paid on top of the extension, the collector share goes straight to the collector
+        let overdue_penalty = self.overdue_penalty(token_manager, now);
+        let collector_penalty = overdue_penalty
+            .checked_mul(self.overdue_penalty_collector_basis_points.unwrap_or(0) as u64)
+            .expect("Multiplication error")
+            .checked_div(BASIS_POINTS_DIVISOR)
+            .expect("Division error");
+        let payment_amount = price
+            .checked_sub(escrow_amount)
+            .expect("Sub error")
+            .checked_add(overdue_penalty.checked_sub(collector_penalty).expect("Sub error"))
+            .expect("Addition error");
+
+        ExtensionPayment {
+            payment_amount,
+            escrow_amount,
+            collector_penalty,
+            overdue_penalty,
+        }
+    }
+
+    // each tier prices the seconds of an extension past its threshold, seconds below the first threshold use the base extension rate
+    pub fn extension_price(&self, seconds_to_add: u64) -> u64 {
+        let mut payment_amount = self.extension_payment_amount.expect("No extension amount");
+        let mut duration_seconds = self.extension_duration_seconds.expect("No extension duration");
+        let mut price: u64 = 0;
+        let mut priced_seconds: u64 = 0;
+        for tier in self.extension_price_tiers.iter().flatten() {
+            if seconds_to_add <= tier.threshold_seconds {
+                break;
+            }
+            let tier_price = tier
+                .threshold_seconds
+                .checked_sub(priced_seconds)
+                .expect("Sub error")
+                .checked_mul(payment_amount)
+                .expect("Multiplication error")
+                .checked_div(duration_seconds)
+                .expect("Division error");
+            price = price.checked_add(tier_price).expect("Addition error");
+            priced_seconds = tier.threshold_seconds;
+            payment_amount = tier.payment_amount;
+            duration_seconds = tier.duration_seconds;
+        }
+        let remaining_price = seconds_to_add
+            .checked_sub(priced_seconds)
+            .expect("Sub error")
+            .checked_mul(payment_amount)
+            .expect("Multiplication error")
+            .checked_div(duration_seconds)
+            .expect("Division error");
+        price.checked_add(remaining_price).expect("Addition error")
+    }
+
+    pub fn is_expired(&self, token_manager: &TokenManager, now: i64) -> bool {
+        if self.max_expiration.is_some() && now >= self.max_expiration.unwrap() {
+            return true;
+        }
+        if token_manager.state != TokenManagerState::Claimed as u8 {
+            return false;
+        }
+        // max expiration is a hard limit so only the rental expiration is extended by the grace period
+        let grace_period_end = self.current_expiration(token_manager).checked_add(self.grace_period_seconds.unwrap_or(0)).expect("Addition error");
+        now >= grace_period_end
+    }
+
+    pub fn is_in_grace_period(&self, token_manager: &TokenManager, now: i64) -> bool {
+        if self.grace_period_seconds.is_none() || token_manager.state != TokenManagerState::Claimed as u8 {
+            return false;
+        }
+        let expiration = self.current_expiration(token_manager);
+        now >= expiration && now < expiration.checked_add(self.grace_period_seconds.unwrap()).expect("Addition error")
+    }
+
+    pub fn overdue_penalty(&self, token_manager: &TokenManager, now: i64) -> u64 {
+        if self.overdue_penalty_amount.is_none() || self.overdue_penalty_duration_seconds.is_none() || token_manager.state != TokenManagerState::Claimed as u8 {
+            return 0;
+        }
+        let expiration = self.current_expiration(token_manager);
+        if now <= expiration {
+            return 0;
+        }
+        let overdue_seconds = now.checked_sub(expiration).expect("Sub error") as u64;
+        overdue_seconds
+            .checked_mul(self.overdue_penalty_amount.unwrap())
+            .expect("Multiplication error")
+            .checked_div(self.overdue_penalty_duration_seconds.unwrap())
+            .expect("Division error")
+    }
+
+    pub fn uses_escrow(&self) -> bool {
+        self.refund_basis_points.is_some() || self.escrow_extension_payments == Some(true)
+    }
+
+    // in escrow mode the whole extension payment is escrowed, otherwise only the refundable share
+    pub fn escrow_basis_points(&self) -> u64 {
+        if self.escrow_extension_payments == Some(true) {
+            return BASIS_POINTS_DIVISOR;
+        }
+        self.refund_basis_points.unwrap_or(0) as u64
+    }
+
+    pub fn current_expiration(&self, token_manager: &TokenManager) -> i64 {
+        match self.expiration {
+            Some(expiration) => expiration,
+            None => {
+                let duration_seconds = self.duration_seconds.expect("No extension duration");
+                token_manager.state_changed_at.checked_add(duration_seconds).expect("Addition error")
+            }
+        }
+    }
+}
+
+pub const EXTENSION_ESCROW_SEED: &str = "extension-escrow";
+pub const EXTENSION_ESCROW_SIZE: usize = 8 + std::mem::size_of::<ExtensionEscrow>() + 8;
+#[account]
+pub struct ExtensionEscrow {
+    pub bump: u8,
+    pub time_invalidator: Pubkey,
+    pub payment_mint: Pubkey,
+    pub payment_token_account: Pubkey,
+    pub amount: u64,
+    pub seconds: u64,
+    pub claimed_at: i64,
+    pub payment_manager: Pubkey,
+    pub mint: Pubkey,
+}
+
+impl ExtensionEscrow {
+    // escrowed seconds belong to the rental they were bought in, the token manager state changes again once it is reissued or transferred
+    pub fn is_stale(&self, token_manager: &TokenManager) -> bool {
+        (self.amount > 0 || self.seconds > 0) && self.claimed_at != token_manager.state_changed_at
+    }
+
+    // extensions are appended to the rental so the escrowed seconds are always the last ones before expiration
+    pub fn consumed_seconds(&self, expiration: i64, now: i64) -> u64 {
+        let start = expiration.checked_sub(self.seconds as i64).expect("Sub error");
+        if now <= start {
+            return 0;
+        }
+        std::cmp::min(now.checked_sub(start).expect("Sub error") as u64, self.seconds)
+    }
+
+    pub fn amount_for_seconds(&self, seconds: u64) -> u64 {
+        if seconds >= self.seconds {
+            return self.amount;
+        }
+        (self.amount as u128)
+            .checked_mul(seconds as u128)
+            .expect("Multiplication error")
+            .checked_div(self.seconds as u128)
+            .expect("Division error") as u64
+    }
 }
@@ -0,0 +1,147 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use solana_nft_programs_payment_manager::state::PaymentManager;
+use solana_nft_programs_token_manager::utils::royalty_creator_accounts_len;
+use spl_associated_token_account::get_associated_token_address;
+
+// escrow PDA paying out escrowed extension payments to the payment target fixed when the escrow was created
+pub struct EscrowRelease<'a, 'info> {
+    pub extension_escrow: &'a Account<'info, ExtensionEscrow>,
+    pub extension_escrow_info: &'a AccountInfo<'info>,
+    pub escrow_token_account: &'a AccountInfo<'info>,
+    pub payment_token_account: &'a AccountInfo<'info>,
+    pub payment_manager: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub signer: &'a [&'a [&'a [u8]]],
+}
+
+// the escrow pays the payment manager taker fee when it is released, it is rounded up here so fees on partial releases are always covered
+pub fn escrow_taker_fee(payment_manager_info: &AccountInfo, escrow_amount: u64) -> Result<u64> {
+    if payment_manager_info.owner.key() != solana_nft_programs_payment_manager::id() {
+        return Ok(0);
+    }
+    let payment_manager = Account::<PaymentManager>::try_from(payment_manager_info)?;
+    Ok(escrow_amount
+        .checked_mul(payment_manager.taker_fee_basis_points.into())
+        .expect("Multiplication error")
+        .checked_add(BASIS_POINTS_DIVISOR.checked_sub(1).expect("Sub error"))
+        .expect("Addition error")
+        .checked_div(BASIS_POINTS_DIVISOR)
+        .expect("Division error"))
+}
+
+// escrowed payments go through the payment manager like direct extensions so fees and royalties are paid on them
+// > With a payment manager remaining accounts are
+// fee collector token account
+// payment mint
+// rented mint
+// rented mint metadata
+// payment manager program
+// ...creator token accounts
+pub fn release_from_escrow<'info>(release: &EscrowRelease<'_, 'info>, remaining_accounts: &mut std::slice::Iter<AccountInfo<'info>>, amount: u64) -> Result<()> {
+    if release.payment_manager.key() != release.extension_escrow.payment_manager {
+        return Err(error!(ErrorCode::InvalidPaymentManager));
+    }
+    if release.payment_manager.owner.key() != solana_nft_programs_payment_manager::id() {
+        if amount > 0 {
+            let cpi_accounts = Transfer {
+                from: release.escrow_token_account.to_account_info(),
+                to: release.payment_token_account.to_account_info(),
+                authority: release.extension_escrow_info.to_account_info(),
+            };
+            let cpi_context = CpiContext::new(release.token_program.to_account_info(), cpi_accounts).with_signer(release.signer);
+            token::transfer(cpi_context, amount)?;
+        }
+        return Ok(());
+    }
+
+    let fee_collector_token_account_info = next_account_info(remaining_accounts)?;
+    let payment_mint_info = next_account_info(remaining_accounts)?;
+    if payment_mint_info.key() != release.extension_escrow.payment_mint {
+        return Err(error!(ErrorCode::InvalidPaymentMint));
+    }
+    // payment manager loads the rented mint as an spl token mint so token 2022 rentals cannot pay through it
+    let mint_info = next_account_info(remaining_accounts)?;
+    if mint_info.key() != release.extension_escrow.mint {
+        return Err(error!(ErrorCode::InvalidMint));
+    }
+    let mint_metadata_info = next_account_info(remaining_accounts)?;
+    let payment_manager_program_info = next_account_info(remaining_accounts)?;
+    if payment_manager_program_info.key() != solana_nft_programs_payment_manager::id() {
+        return Err(error!(ErrorCode::InvalidPaymentManagerProgram));
+    }
+    let mut creator_accounts = Vec::new();
+    for _ in 0..royalty_creator_accounts_len(mint_metadata_info) {
+        creator_accounts.push(next_account_info(remaining_accounts)?.to_account_info());
+    }
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandlePaymentWithRoyaltiesCtx {
+        payment_manager: release.payment_manager.to_account_info(),
+        payer_token_account: release.escrow_token_account.to_account_info(),
+        fee_collector_token_account: fee_collector_token_account_info.to_account_info(),
+        payment_token_account: release.payment_token_account.to_account_info(),
+        payment_mint: payment_mint_info.to_account_info(),
+        mint: mint_info.to_account_info(),
+        mint_metadata: mint_metadata_info.to_account_info(),
+        payer: release.extension_escrow_info.to_account_info(),
+        token_program: release.token_program.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(payment_manager_program_info.to_account_info(), cpi_accounts)
+        .with_remaining_accounts(creator_accounts)
+        .with_signer(release.signer);
+    solana_nft_programs_payment_manager::cpi::handle_payment_with_royalties(cpi_ctx, amount)
+}
+
+// once a rental is invalidated all of its escrowed time has been used so the escrow is paid out and reset before the next rental
+pub fn settle_extension_escrow<'info>(time_invalidator: &Account<'info, TimeInvalidator>, remaining_accounts: &mut std::slice::Iter<AccountInfo<'info>>) -> Result<()> {
+    if !time_invalidator.uses_escrow() {
+        return Ok(());
+    }
+    let extension_escrow_info = next_account_info(remaining_accounts)?;
+    let (extension_escrow_id, _) = Pubkey::find_program_address(&[EXTENSION_ESCROW_SEED.as_bytes(), time_invalidator.key().as_ref()], &crate::id());
+    if extension_escrow_info.key() != extension_escrow_id {
+        return Err(error!(ErrorCode::InvalidExtensionEscrow));
+    }
+    // no extension has been escrowed yet
+    if extension_escrow_info.data_is_empty() {
+        return Ok(());
+    }
+    let mut extension_escrow = Account::<ExtensionEscrow>::try_from(extension_escrow_info)?;
+
+    let escrow_token_account_info = next_account_info(remaining_accounts)?;
+    if escrow_token_account_info.key() != get_associated_token_address(&extension_escrow.key(), &extension_escrow.payment_mint) {
+        return Err(error!(ErrorCode::InvalidEscrowTokenAccount));
+    }
+    let payment_token_account_info = next_account_info(remaining_accounts)?;
+    if payment_token_account_info.key() != extension_escrow.payment_token_account {
+        return Err(error!(ErrorCode::InvalidPaymentTokenAccount));
+    }
+    let payment_token_program = Program::<Token>::try_from(next_account_info(remaining_accounts)?)?;
+    let payment_manager_info = next_account_info(remaining_accounts)?;
+
+    let settle_amount = extension_escrow.amount;
+    extension_escrow.amount = 0;
+    extension_escrow.seconds = 0;
+    extension_escrow.exit(&crate::id())?;
+
+    let time_invalidator_key = time_invalidator.key();
+    let extension_escrow_seeds = &[EXTENSION_ESCROW_SEED.as_bytes(), time_invalidator_key.as_ref(), &[extension_escrow.bump]];
+    let extension_escrow_signer = &[&extension_escrow_seeds[..]];
+    let release = EscrowRelease {
+        extension_escrow: &extension_escrow,
+        extension_escrow_info,
+        escrow_token_account: escrow_token_account_info,
+        payment_token_account: payment_token_account_info,
+        payment_manager: payment_manager_info,
+        token_program: &payment_token_program.to_account_info(),
+        signer: extension_escrow_signer,
+    };
+    release_from_escrow(&release, remaining_accounts, settle_amount)
+}
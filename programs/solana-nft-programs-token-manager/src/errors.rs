@@ -82,4 +82,16 @@ pub enum ErrorCode {
     CannotMigrateDelegatedToken,
     #[msg("Invalid return target")]
     InvalidReturnTarget,
+    #[msg("Non transferable mints cannot be managed")]
+    NonTransferableMint,
+    #[msg("Mints with transfer fees are not supported")]
+    TransferFeeNotSupported,
+    #[msg("Mints with transfer hooks are not supported")]
+    TransferHookNotSupported,
+    #[msg("Mint permanent delegate must be the mint manager")]
+    InvalidPermanentDelegate,
+    #[msg("Invalid token manager amount")]
+    InvalidAmount,
+    #[msg("Recipient token account must be empty for this token manager kind")]
+    RecipientTokenAccountNotEmpty,
 }
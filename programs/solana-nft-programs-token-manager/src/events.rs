@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when a token manager moves from initialized to issued
+#[event]
+pub struct TokenManagerIssued {
+    pub token_manager: Pubkey,
+    pub mint: Pubkey,
+    pub issuer: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub amount: u64,
+    pub kind: u8,
+    pub invalidation_type: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when an issued token manager is returned to the issuer before being claimed
+#[event]
+pub struct TokenManagerUnissued {
+    pub token_manager: Pubkey,
+    pub mint: Pubkey,
+    pub issuer: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub kind: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a token manager is claimed by a recipient
+#[event]
+pub struct TokenManagerClaimed {
+    pub token_manager: Pubkey,
+    pub mint: Pubkey,
+    pub issuer: Pubkey,
+    pub recipient: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub amount: u64,
+    pub kind: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a claimed token moves between holders through the transfer authority
+#[event]
+pub struct TokenManagerTransferred {
+    pub token_manager: Pubkey,
+    pub mint: Pubkey,
+    pub issuer: Pubkey,
+    pub from_token_account: Pubkey,
+    pub recipient: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub kind: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a token manager is invalidated, `state` is the resulting state and `closed` is set when the account was removed
+#[event]
+pub struct TokenManagerInvalidated {
+    pub token_manager: Pubkey,
+    pub mint: Pubkey,
+    pub issuer: Pubkey,
+    pub invalidator: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub kind: u8,
+    pub invalidation_type: u8,
+    pub state: u8,
+    pub closed: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when a permissioned token manager is migrated to a standard metaplex edition
+#[event]
+pub struct TokenManagerMigrated {
+    pub token_manager: Pubkey,
+    pub mint: Pubkey,
+    pub issuer: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub kind: u8,
+    pub timestamp: i64,
+}
@@ -9,19 +9,20 @@ use mpl_token_metadata::types::TokenStandard;
 use mpl_utils::assert_derivation;
 
 use crate::errors::ErrorCode;
+use crate::events::TokenManagerClaimed;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke;
 use anchor_lang::solana_program::program::invoke_signed;
 use anchor_lang::solana_program::system_instruction::transfer;
 use anchor_lang::AccountsClose;
-use anchor_spl::token::Approve;
-use anchor_spl::token::FreezeAccount;
-use anchor_spl::token::Mint;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
-use anchor_spl::token::Transfer;
-use anchor_spl::token::{self};
+use anchor_spl::token_interface::Approve;
+use anchor_spl::token_interface::FreezeAccount;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::TransferChecked;
+use anchor_spl::token_interface::{self};
 
 #[derive(Accounts)]
 pub struct ClaimCtx<'info> {
@@ -32,9 +33,9 @@ pub struct ClaimCtx<'info> {
         && token_manager_token_account.mint == token_manager.mint
         @ ErrorCode::InvalidTokenManagerTokenAccount
     )]
-    token_manager_token_account: Box<Account<'info, TokenAccount>>,
+    token_manager_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
-    mint: Box<Account<'info, Mint>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
 
     // recipient
     #[account(mut)]
@@ -44,8 +45,8 @@ pub struct ClaimCtx<'info> {
         && recipient_token_account.mint == token_manager.mint
         @ ErrorCode::InvalidRecipientTokenAccount
     )]
-    recipient_token_account: Box<Account<'info, TokenAccount>>,
-    token_program: Program<'info, Token>,
+    recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    token_program: Interface<'info, TokenInterface>,
     system_program: Program<'info, System>,
 }
 
@@ -58,7 +59,8 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
 
     // get PDA seeds to sign with
     let mint = token_manager.mint;
-    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), mint.as_ref(), &[token_manager.bump]];
+    let count_seed = token_manager.count_seed();
+    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), mint.as_ref(), count_seed.as_ref(), &[token_manager.bump]];
     let token_manager_signer = &[&token_manager_seeds[..]];
 
     if token_manager.kind != TokenManagerKind::Programmable as u8 {
@@ -78,30 +80,40 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         }
     }
 
+    // freeze and delegate apply to the whole account so it must not hold other tokens of this mint
+    if (token_manager.kind == TokenManagerKind::Managed as u8 || token_manager.kind == TokenManagerKind::Edition as u8 || token_manager.kind == TokenManagerKind::Permissioned as u8)
+        && ctx.accounts.recipient_token_account.amount != 0
+    {
+        return Err(error!(ErrorCode::RecipientTokenAccountNotEmpty));
+    }
+
     // if this is a managed token, this means we will revoke it at the end of life, so we need to delegate and freeze
     match token_manager.kind {
-        k if k == TokenManagerKind::Unmanaged as u8 => {
+        // permanent delegate tokens are reclaimed by the mint manager so no delegate or freeze is needed
+        k if k == TokenManagerKind::Unmanaged as u8 || k == TokenManagerKind::PermanentDelegate as u8 => {
             // transfer amount to recipient token account
-            let cpi_accounts = Transfer {
+            let cpi_accounts = TransferChecked {
                 from: ctx.accounts.token_manager_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.recipient_token_account.to_account_info(),
                 authority: token_manager.to_account_info(),
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::transfer(cpi_context, token_manager.amount)?;
+            token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
         }
 
         k if k == TokenManagerKind::Managed as u8 => {
             // transfer amount to recipient token account
-            let cpi_accounts = Transfer {
+            let cpi_accounts = TransferChecked {
                 from: ctx.accounts.token_manager_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.recipient_token_account.to_account_info(),
                 authority: token_manager.to_account_info(),
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::transfer(cpi_context, token_manager.amount)?;
+            token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
 
             // set account delegate of recipient token account to token manager PDA
             let cpi_accounts = Approve {
@@ -111,7 +123,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-            token::approve(cpi_context, token_manager.amount)?;
+            token_interface::approve(cpi_context, token_manager.amount)?;
 
             let mint_manager_info = next_account_info(remaining_accs)?;
             let mint = ctx.accounts.mint.key();
@@ -132,19 +144,20 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-            token::freeze_account(cpi_context)?;
+            token_interface::freeze_account(cpi_context)?;
         }
 
         k if k == TokenManagerKind::Edition as u8 => {
             // transfer amount to recipient token account
-            let cpi_accounts = Transfer {
+            let cpi_accounts = TransferChecked {
                 from: ctx.accounts.token_manager_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.recipient_token_account.to_account_info(),
                 authority: token_manager.to_account_info(),
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::transfer(cpi_context, token_manager.amount)?;
+            token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
 
             let edition_info = next_account_info(remaining_accs)?;
             let metadata_program = next_account_info(remaining_accs)?;
@@ -163,7 +176,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-            token::approve(cpi_context, token_manager.amount)?;
+            token_interface::approve(cpi_context, token_manager.amount)?;
 
             invoke_signed(
                 &mpl_token_metadata::instructions::FreezeDelegatedAccount {
@@ -186,14 +199,15 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
 
         k if k == TokenManagerKind::Permissioned as u8 => {
             // transfer amount to recipient token account
-            let cpi_accounts = Transfer {
+            let cpi_accounts = TransferChecked {
                 from: ctx.accounts.token_manager_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.recipient_token_account.to_account_info(),
                 authority: token_manager.to_account_info(),
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::transfer(cpi_context, token_manager.amount)?;
+            token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
 
             let mint_manager_info = next_account_info(remaining_accs)?;
             let mint = ctx.accounts.mint.key();
@@ -215,7 +229,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-            token::freeze_account(cpi_context)?;
+            token_interface::freeze_account(cpi_context)?;
         }
 
         k if k == TokenManagerKind::Programmable as u8 => {
@@ -377,5 +391,16 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         }
         claim_receipt.close(token_manager.to_account_info())?;
     }
+
+    emit!(TokenManagerClaimed {
+        token_manager: token_manager.key(),
+        mint: token_manager.mint,
+        issuer: token_manager.issuer,
+        recipient: ctx.accounts.recipient.key(),
+        recipient_token_account: token_manager.recipient_token_account,
+        amount: token_manager.amount,
+        kind: token_manager.kind,
+        timestamp: token_manager.state_changed_at,
+    });
     Ok(())
 }
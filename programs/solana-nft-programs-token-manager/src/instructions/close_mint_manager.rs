@@ -1,22 +1,22 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
-use anchor_spl::token::SetAuthority;
-use anchor_spl::token::Token;
-use anchor_spl::token::{self};
-use spl_token::instruction::AuthorityType;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::SetAuthority;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{self};
 
 #[derive(Accounts)]
 pub struct CloseMintManagerCtx<'info> {
     #[account(mut, constraint = mint_manager.token_managers == 0 @ ErrorCode::OutstandingTokens, close = freeze_authority)]
     pub mint_manager: Account<'info, MintManager>,
     #[account(mut, constraint = mint.freeze_authority.expect("No freeze authority") == mint_manager.key() @ ErrorCode::InvalidFreezeAuthority)]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     #[account(constraint = mint_manager.initializer == freeze_authority.key() @ ErrorCode::InvalidInitializer)]
     pub freeze_authority: Signer<'info>,
     pub payer: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn handler(ctx: Context<CloseMintManagerCtx>) -> Result<()> {
@@ -32,6 +32,6 @@ pub fn handler(ctx: Context<CloseMintManagerCtx>) -> Result<()> {
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-    token::set_authority(cpi_context, AuthorityType::FreezeAccount, Some(ctx.accounts.freeze_authority.key()))?;
+    token_interface::set_authority(cpi_context, AuthorityType::FreezeAccount, Some(ctx.accounts.freeze_authority.key()))?;
     Ok(())
 }
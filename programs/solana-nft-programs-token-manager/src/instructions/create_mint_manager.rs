@@ -1,11 +1,11 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
-use anchor_spl::token::SetAuthority;
-use anchor_spl::token::Token;
-use anchor_spl::token::{self};
-use spl_token::instruction::AuthorityType;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::SetAuthority;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{self};
 
 #[derive(Accounts)]
 pub struct CreateMintManagerCtx<'info> {
@@ -18,11 +18,11 @@ pub struct CreateMintManagerCtx<'info> {
     )]
     pub mint_manager: Account<'info, MintManager>,
     #[account(mut, constraint = mint.freeze_authority.expect("No freeze authority") == freeze_authority.key() @ ErrorCode::InvalidFreezeAuthority)]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     pub freeze_authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -40,7 +40,7 @@ pub fn handler(ctx: Context<CreateMintManagerCtx>) -> Result<()> {
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-    token::set_authority(cpi_context, AuthorityType::FreezeAccount, Some(ctx.accounts.mint_manager.key()))?;
+    token_interface::set_authority(cpi_context, AuthorityType::FreezeAccount, Some(ctx.accounts.mint_manager.key()))?;
 
     if ctx.accounts.mint.mint_authority.is_none() || ctx.accounts.mint.mint_authority.unwrap() != ctx.accounts.freeze_authority.key() {
         return Err(error!(ErrorCode::InvalidMintAuthority));
@@ -53,7 +53,7 @@ pub fn handler(ctx: Context<CreateMintManagerCtx>) -> Result<()> {
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-    token::set_authority(cpi_context, AuthorityType::MintTokens, Some(ctx.accounts.mint_manager.key()))?;
+    token_interface::set_authority(cpi_context, AuthorityType::MintTokens, Some(ctx.accounts.mint_manager.key()))?;
 
     Ok(())
 }
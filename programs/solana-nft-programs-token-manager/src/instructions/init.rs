@@ -1,8 +1,10 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::assert_mint_extensions;
+use crate::utils::assert_permanent_delegate;
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitIx {
@@ -13,13 +15,13 @@ pub struct InitIx {
 }
 
 #[derive(Accounts)]
-#[instruction(num_invalidators: u8)]
+#[instruction(ix: InitIx)]
 pub struct InitCtx<'info> {
     #[account(
         init_if_needed,
         payer = payer,
         seeds = [TOKEN_MANAGER_SEED.as_bytes(), mint.key().as_ref()], bump,
-        space = token_manager_size(num_invalidators as usize),
+        space = token_manager_size(ix.num_invalidators as usize),
     )]
     token_manager: Box<Account<'info, TokenManager>>,
 
@@ -30,7 +32,7 @@ pub struct InitCtx<'info> {
         space = MINT_COUNTER_SIZE,
     )]
     mint_counter: Box<Account<'info, MintCounter>>,
-    mint: Box<Account<'info, Mint>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(mut)]
     issuer: Signer<'info>,
@@ -42,14 +44,11 @@ pub struct InitCtx<'info> {
         && issuer_token_account.amount >= 1
         @ ErrorCode::InvalidIssuerTokenAccount
     )]
-    issuer_token_account: Box<Account<'info, TokenAccount>>,
+    issuer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
-    if ix.num_invalidators > MAX_INVALIDATORS {
-        return Err(error!(ErrorCode::MaximumInvalidatorsReached));
-    }
     let token_manager = &mut ctx.accounts.token_manager;
     if token_manager.state != TokenManagerState::Initialized as u8 {
         return Err(error!(ErrorCode::InvalidTokenManagerState));
@@ -57,14 +56,49 @@ pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
     if token_manager.num_invalidators != 0 && ix.num_invalidators >= token_manager.num_invalidators {
         return Err(error!(ErrorCode::InvalidNumInvalidators));
     }
-    if ctx.accounts.mint.supply > 1 {
+    assert_init(&ix, &ctx.accounts.mint, &ctx.accounts.issuer_token_account, ctx.program_id)?;
+
+    let mint_counter = &mut ctx.accounts.mint_counter;
+    mint_counter.bump = *ctx.bumps.get("mint_counter").unwrap();
+    mint_counter.count = mint_counter.count.checked_add(1).expect("Addition error");
+    mint_counter.mint = ctx.accounts.mint.key();
+
+    token_manager.bump = *ctx.bumps.get("token_manager").unwrap();
+    token_manager.count = mint_counter.count;
+    token_manager.num_invalidators = ix.num_invalidators;
+    token_manager.issuer = ctx.accounts.issuer.key();
+    token_manager.mint = ctx.accounts.mint.key();
+    token_manager.state = TokenManagerState::Initialized as u8;
+    token_manager.state_changed_at = Clock::get().unwrap().unix_timestamp;
+    token_manager.claim_approver = None;
+    token_manager.invalidators = Vec::new();
+    token_manager.amount = ix.amount;
+    token_manager.kind = ix.kind;
+    token_manager.invalidation_type = ix.invalidation_type;
+
+    // default to itself to avoid someone not setting it
+    token_manager.transfer_authority = Some(token_manager.key());
+    Ok(())
+}
+
+pub fn assert_init(ix: &InitIx, mint: &InterfaceAccount<Mint>, issuer_token_account: &InterfaceAccount<TokenAccount>, program_id: &Pubkey) -> Result<()> {
+    if ix.num_invalidators > MAX_INVALIDATORS {
+        return Err(error!(ErrorCode::MaximumInvalidatorsReached));
+    }
+    if ix.amount == 0 || issuer_token_account.amount < ix.amount {
+        return Err(error!(ErrorCode::InvalidAmount));
+    }
+    // fungible and semi fungible mints are only supported by kinds that do not rely on a metaplex edition
+    if (ix.kind == TokenManagerKind::Edition as u8 || ix.kind == TokenManagerKind::Programmable as u8) && mint.supply > 1 {
         return Err(error!(ErrorCode::InvalidMintSupply));
     }
+    assert_mint_extensions(&mint.to_account_info())?;
     if ix.kind != TokenManagerKind::Managed as u8
         && ix.kind != TokenManagerKind::Unmanaged as u8
         && ix.kind != TokenManagerKind::Edition as u8
         && ix.kind != TokenManagerKind::Permissioned as u8
         && ix.kind != TokenManagerKind::Programmable as u8
+        && ix.kind != TokenManagerKind::PermanentDelegate as u8
     {
         return Err(error!(ErrorCode::InvalidTokenManagerKind));
     }
@@ -78,10 +112,9 @@ pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
     }
 
     if ix.kind == TokenManagerKind::Permissioned as u8 {
-        let mint = &ctx.accounts.mint;
         let mint_key = mint.key();
         let path = &[MINT_MANAGER_SEED.as_bytes(), mint_key.as_ref()];
-        let (mint_manager_key, _bump) = Pubkey::find_program_address(path, ctx.program_id);
+        let (mint_manager_key, _bump) = Pubkey::find_program_address(path, program_id);
         if mint.mint_authority.is_none() || mint.mint_authority.unwrap() != mint_manager_key {
             return Err(error!(ErrorCode::InvalidMintAuthority));
         }
@@ -90,30 +123,16 @@ pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
         }
     }
 
+    if ix.kind == TokenManagerKind::PermanentDelegate as u8 {
+        let mint_key = mint.key();
+        let path = &[MINT_MANAGER_SEED.as_bytes(), mint_key.as_ref()];
+        let (mint_manager_key, _bump) = Pubkey::find_program_address(path, program_id);
+        assert_permanent_delegate(&mint.to_account_info(), &mint_manager_key)?;
+    }
+
     // Unamanged must use invalidate
     if ix.kind == TokenManagerKind::Unmanaged as u8 && ix.invalidation_type != InvalidationType::Invalidate as u8 {
         return Err(error!(ErrorCode::InvalidInvalidationType));
     }
-
-    let mint_counter = &mut ctx.accounts.mint_counter;
-    mint_counter.bump = *ctx.bumps.get("mint_counter").unwrap();
-    mint_counter.count = mint_counter.count.checked_add(1).expect("Addition error");
-    mint_counter.mint = ctx.accounts.mint.key();
-
-    token_manager.bump = *ctx.bumps.get("token_manager").unwrap();
-    token_manager.count = mint_counter.count;
-    token_manager.num_invalidators = ix.num_invalidators;
-    token_manager.issuer = ctx.accounts.issuer.key();
-    token_manager.mint = ctx.accounts.mint.key();
-    token_manager.state = TokenManagerState::Initialized as u8;
-    token_manager.state_changed_at = Clock::get().unwrap().unix_timestamp;
-    token_manager.claim_approver = None;
-    token_manager.invalidators = Vec::new();
-    token_manager.amount = ix.amount;
-    token_manager.kind = ix.kind;
-    token_manager.invalidation_type = ix.invalidation_type;
-
-    // default to itself to avoid someone not setting it
-    token_manager.transfer_authority = Some(token_manager.key());
     Ok(())
 }
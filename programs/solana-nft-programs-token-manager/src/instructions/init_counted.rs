@@ -0,0 +1,69 @@
+use crate::errors::ErrorCode;
+use crate::instructions::init::assert_init;
+use crate::instructions::init::InitIx;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+
+#[derive(Accounts)]
+#[instruction(ix: InitIx)]
+pub struct InitCountedCtx<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [MINT_COUNTER_SEED.as_bytes(), mint.key().as_ref()], bump,
+        space = MINT_COUNTER_SIZE,
+    )]
+    mint_counter: Box<Account<'info, MintCounter>>,
+
+    // derived from the next count so each issuance of the mint gets its own token manager
+    #[account(
+        init,
+        payer = payer,
+        seeds = [TOKEN_MANAGER_SEED.as_bytes(), mint.key().as_ref(), &mint_counter.count.checked_add(1).expect("Addition error").to_le_bytes()], bump,
+        space = token_manager_size(ix.num_invalidators as usize),
+    )]
+    token_manager: Box<Account<'info, TokenManager>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    issuer: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(mut, constraint =
+        issuer_token_account.owner == issuer.key()
+        && issuer_token_account.mint == mint.key()
+        @ ErrorCode::InvalidIssuerTokenAccount
+    )]
+    issuer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitCountedCtx>, ix: InitIx) -> Result<()> {
+    assert_init(&ix, &ctx.accounts.mint, &ctx.accounts.issuer_token_account, ctx.program_id)?;
+
+    let mint_counter = &mut ctx.accounts.mint_counter;
+    mint_counter.bump = *ctx.bumps.get("mint_counter").unwrap();
+    mint_counter.count = mint_counter.count.checked_add(1).expect("Addition error");
+    mint_counter.mint = ctx.accounts.mint.key();
+
+    let token_manager = &mut ctx.accounts.token_manager;
+    token_manager.version = COUNTED_TOKEN_MANAGER_VERSION;
+    token_manager.bump = *ctx.bumps.get("token_manager").unwrap();
+    token_manager.count = mint_counter.count;
+    token_manager.num_invalidators = ix.num_invalidators;
+    token_manager.issuer = ctx.accounts.issuer.key();
+    token_manager.mint = ctx.accounts.mint.key();
+    token_manager.state = TokenManagerState::Initialized as u8;
+    token_manager.state_changed_at = Clock::get().unwrap().unix_timestamp;
+    token_manager.claim_approver = None;
+    token_manager.invalidators = Vec::new();
+    token_manager.amount = ix.amount;
+    token_manager.kind = ix.kind;
+    token_manager.invalidation_type = ix.invalidation_type;
+
+    // default to itself to avoid someone not setting it
+    token_manager.transfer_authority = Some(token_manager.key());
+    Ok(())
+}
@@ -7,17 +7,18 @@ use mpl_token_metadata::types::TokenStandard;
 use mpl_utils::assert_derivation;
 
 use crate::errors::ErrorCode;
+use crate::events::TokenManagerInvalidated;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke_signed;
 use anchor_lang::AccountsClose;
-use anchor_spl::token::CloseAccount;
-use anchor_spl::token::Mint;
-use anchor_spl::token::ThawAccount;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
-use anchor_spl::token::Transfer;
-use anchor_spl::token::{self};
+use anchor_spl::token_interface::CloseAccount;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::ThawAccount;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::TransferChecked;
+use anchor_spl::token_interface::{self};
 
 #[derive(Accounts)]
 pub struct InvalidateCtx<'info> {
@@ -28,13 +29,13 @@ pub struct InvalidateCtx<'info> {
         && token_manager_token_account.mint == token_manager.mint
         @ ErrorCode::InvalidTokenManagerTokenAccount
     )]
-    token_manager_token_account: Box<Account<'info, TokenAccount>>,
+    token_manager_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(mut, constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
-    mint: Box<Account<'info, Mint>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
 
     // recipient
     #[account(mut, constraint = recipient_token_account.key() == token_manager.recipient_token_account @ ErrorCode::InvalidRecipientTokenAccount)]
-    recipient_token_account: Box<Account<'info, TokenAccount>>,
+    recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     // invalidator
     #[account(constraint =
@@ -48,7 +49,7 @@ pub struct InvalidateCtx<'info> {
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(mut)]
     collector: AccountInfo<'info>,
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     rent: Sysvar<'info, Rent>,
 }
 
@@ -58,7 +59,8 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
 
     // get PDA seeds to sign with
     let mint = token_manager.mint;
-    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), mint.as_ref(), &[token_manager.bump]];
+    let count_seed = token_manager.count_seed();
+    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), mint.as_ref(), count_seed.as_ref(), &[token_manager.bump]];
     let token_manager_signer = &[&token_manager_seeds[..]];
 
     if token_manager.kind != TokenManagerKind::Programmable as u8 {
@@ -101,7 +103,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
                 };
                 let cpi_program = ctx.accounts.token_program.to_account_info();
                 let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-                token::thaw_account(cpi_context)?;
+                token_interface::thaw_account(cpi_context)?;
             }
             k if k == TokenManagerKind::Edition as u8 => {
                 let edition_info = next_account_info(remaining_accs)?;
@@ -130,6 +132,41 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
                 )?;
             }
             k if k == TokenManagerKind::Programmable as u8 => {}
+            k if k == TokenManagerKind::PermanentDelegate as u8 => {
+                if token_manager.invalidation_type == InvalidationType::Return as u8 || token_manager.invalidation_type == InvalidationType::Reissue as u8 {
+                    let mint_manager_info = next_account_info(remaining_accs)?;
+                    let path = &[MINT_MANAGER_SEED.as_bytes(), mint.as_ref()];
+                    let bump_seed = assert_derivation(ctx.program_id, mint_manager_info, path, error!(ErrorCode::PublicKeyMismatch))?;
+                    let mint_manager_seeds = &[MINT_MANAGER_SEED.as_bytes(), mint.as_ref(), &[bump_seed]];
+                    let mint_manager_signer = &[&mint_manager_seeds[..]];
+
+                    // reclaim from the recorded recipient token account, tokens moved out of it can only be taken
+                    // back from another account when this token manager holds the whole supply of the mint
+                    let mut reclaim_source_info = ctx.accounts.recipient_token_account.to_account_info();
+                    if ctx.accounts.recipient_token_account.amount < token_manager.amount {
+                        if ctx.accounts.mint.supply != token_manager.amount {
+                            return Err(error!(ErrorCode::InvalidCurrentTokenAccount));
+                        }
+                        let holder_token_account_info = next_account_info(remaining_accs)?;
+                        let holder_token_account = InterfaceAccount::<TokenAccount>::try_from(holder_token_account_info)?;
+                        if holder_token_account.mint != mint || holder_token_account.amount < token_manager.amount {
+                            return Err(error!(ErrorCode::InvalidCurrentTokenAccount));
+                        }
+                        reclaim_source_info = holder_token_account_info.to_account_info();
+                    }
+
+                    // reclaim into token manager token account as permanent delegate
+                    let cpi_accounts = TransferChecked {
+                        from: reclaim_source_info,
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: ctx.accounts.token_manager_token_account.to_account_info(),
+                        authority: mint_manager_info.to_account_info(),
+                    };
+                    let cpi_program = ctx.accounts.token_program.to_account_info();
+                    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
+                    token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
+                }
+            }
             _ => return Err(error!(ErrorCode::InvalidTokenManagerKind)),
         }
     }
@@ -139,40 +176,43 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             if token_manager.state == TokenManagerState::Issued as u8 {
                 // find claim_approver token account
                 let claim_approver_token_account_info = next_account_info(remaining_accs)?;
-                let claim_approver_token_account = Account::<TokenAccount>::try_from(claim_approver_token_account_info)?;
+                let claim_approver_token_account = InterfaceAccount::<TokenAccount>::try_from(claim_approver_token_account_info)?;
                 if claim_approver_token_account.owner != token_manager.claim_approver.expect("No claim approver found") {
                     return Err(error!(ErrorCode::InvalidReceiptMintOwner));
                 }
 
                 // transfer to claim_approver
-                let cpi_accounts = Transfer {
+                let cpi_accounts = TransferChecked {
                     from: ctx.accounts.token_manager_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
                     to: claim_approver_token_account.to_account_info(),
                     authority: token_manager.to_account_info(),
                 };
                 let cpi_program = ctx.accounts.token_program.to_account_info();
                 let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-                token::transfer(cpi_context, token_manager.amount)?;
-            } else {
+                token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
+            } else if token_manager.kind != TokenManagerKind::PermanentDelegate as u8 {
                 // transfer to token_manager to clear the delegate
-                let cpi_accounts = Transfer {
+                let cpi_accounts = TransferChecked {
                     from: ctx.accounts.recipient_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
                     to: ctx.accounts.token_manager_token_account.to_account_info(),
                     authority: token_manager.to_account_info(),
                 };
                 let cpi_program = ctx.accounts.token_program.to_account_info();
                 let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-                token::transfer(cpi_context, token_manager.amount)?;
+                token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
 
                 // transfer back to receipient unlocked
-                let cpi_accounts = Transfer {
+                let cpi_accounts = TransferChecked {
                     from: ctx.accounts.token_manager_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
                     to: ctx.accounts.recipient_token_account.to_account_info(),
                     authority: token_manager.to_account_info(),
                 };
                 let cpi_program = ctx.accounts.token_program.to_account_info();
                 let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-                token::transfer(cpi_context, token_manager.amount)?;
+                token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
             }
 
             // close token_manager_token_account
@@ -183,7 +223,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::close_account(cpi_context)?;
+            token_interface::close_account(cpi_context)?;
 
             // close token_manager
             token_manager.state = TokenManagerState::Invalidated as u8;
@@ -195,7 +235,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
                 k if k == TokenManagerKind::Programmable as u8 => {
                     // find receipt holder
                     let return_token_account_info = next_account_info(remaining_accs)?;
-                    let return_token_account = Account::<TokenAccount>::try_from(return_token_account_info)?;
+                    let return_token_account = InterfaceAccount::<TokenAccount>::try_from(return_token_account_info)?;
                     let return_token_account_owner_info = next_account_info(remaining_accs)?;
                     if return_token_account.owner != return_token_account_owner_info.key() {
                         return Err(error!(ErrorCode::InvalidReturnTarget));
@@ -207,7 +247,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
                         }
                     } else {
                         let receipt_token_account_info = next_account_info(remaining_accs)?;
-                        let receipt_token_account = Account::<TokenAccount>::try_from(receipt_token_account_info)?;
+                        let receipt_token_account = InterfaceAccount::<TokenAccount>::try_from(receipt_token_account_info)?;
                         if !(receipt_token_account.mint == token_manager.receipt_mint.expect("No receipt mint") && receipt_token_account.amount > 0) {
                             return Err(error!(ErrorCode::InvalidReceiptMintAccount));
                         }
@@ -365,7 +405,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
                     };
                     let cpi_program = ctx.accounts.token_program.to_account_info();
                     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-                    token::close_account(cpi_context)?;
+                    token_interface::close_account(cpi_context)?;
 
                     // close token_manager
                     token_manager.state = TokenManagerState::Invalidated as u8;
@@ -375,14 +415,14 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
                 _ => {
                     // find receipt holder
                     let return_token_account_info = next_account_info(remaining_accs)?;
-                    let return_token_account = Account::<TokenAccount>::try_from(return_token_account_info)?;
+                    let return_token_account = InterfaceAccount::<TokenAccount>::try_from(return_token_account_info)?;
                     if token_manager.receipt_mint.is_none() {
                         if return_token_account.owner != token_manager.issuer {
                             return Err(error!(ErrorCode::InvalidIssuerTokenAccount));
                         }
                     } else {
                         let receipt_token_account_info = next_account_info(remaining_accs)?;
-                        let receipt_token_account = Account::<TokenAccount>::try_from(receipt_token_account_info)?;
+                        let receipt_token_account = InterfaceAccount::<TokenAccount>::try_from(receipt_token_account_info)?;
                         if !(receipt_token_account.mint == token_manager.receipt_mint.expect("No receipt mint") && receipt_token_account.amount > 0) {
                             return Err(error!(ErrorCode::InvalidReceiptMintAccount));
                         }
@@ -391,15 +431,23 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
                         }
                     }
 
+                    // permanent delegate tokens were already reclaimed into the token manager token account
+                    let return_source_info = if token_manager.kind == TokenManagerKind::PermanentDelegate as u8 {
+                        ctx.accounts.token_manager_token_account.to_account_info()
+                    } else {
+                        ctx.accounts.recipient_token_account.to_account_info()
+                    };
+
                     // transfer back to issuer or receipt holder
-                    let cpi_accounts = Transfer {
-                        from: ctx.accounts.recipient_token_account.to_account_info(),
+                    let cpi_accounts = TransferChecked {
+                        from: return_source_info,
+                        mint: ctx.accounts.mint.to_account_info(),
                         to: return_token_account_info.to_account_info(),
                         authority: token_manager.to_account_info(),
                     };
                     let cpi_program = ctx.accounts.token_program.to_account_info();
                     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-                    token::transfer(cpi_context, token_manager.amount)?;
+                    token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
 
                     // close token_manager_token_account
                     let cpi_accounts = CloseAccount {
@@ -409,7 +457,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
                     };
                     let cpi_program = ctx.accounts.token_program.to_account_info();
                     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-                    token::close_account(cpi_context)?;
+                    token_interface::close_account(cpi_context)?;
 
                     // close token_manager
                     token_manager.state = TokenManagerState::Invalidated as u8;
@@ -427,7 +475,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::close_account(cpi_context)?;
+            token_interface::close_account(cpi_context)?;
 
             // mark invalid
             token_manager.state = TokenManagerState::Invalidated as u8;
@@ -445,7 +493,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             match token_manager.kind {
                 k if k == TokenManagerKind::Programmable as u8 => {
                     // get PDA seeds to sign with
-                    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), token_manager.mint.as_ref(), &[token_manager.bump]];
+                    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), token_manager.mint.as_ref(), count_seed.as_ref(), &[token_manager.bump]];
                     let token_manager_signer = &[&token_manager_seeds[..]];
 
                     let recipient_token_account_owner_info = next_account_info(remaining_accs)?;
@@ -588,6 +636,8 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
                         token_manager_signer,
                     )?;
                 }
+                // no delegate was set on the holder account
+                k if k == TokenManagerKind::PermanentDelegate as u8 => {}
                 _ => {
                     // https://github.com/solana-labs/solana-program-library/pull/2872
                     // remove delegate
@@ -597,27 +647,29 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
                     // };
                     // let cpi_program = ctx.accounts.token_program.to_account_info();
                     // let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-                    // token::revoke(cpi_context)?;
+                    // token_interface::revoke(cpi_context)?;
 
                     // transfer to token_manager
-                    let cpi_accounts = Transfer {
+                    let cpi_accounts = TransferChecked {
                         from: ctx.accounts.recipient_token_account.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
                         to: ctx.accounts.token_manager_token_account.to_account_info(),
                         authority: token_manager.to_account_info(),
                     };
                     let cpi_program = ctx.accounts.token_program.to_account_info();
                     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-                    token::transfer(cpi_context, token_manager.amount)?;
+                    token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
 
                     // transfer back to receipient unlocked
-                    let cpi_accounts = Transfer {
+                    let cpi_accounts = TransferChecked {
                         from: ctx.accounts.token_manager_token_account.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
                         to: ctx.accounts.recipient_token_account.to_account_info(),
                         authority: token_manager.to_account_info(),
                     };
                     let cpi_program = ctx.accounts.token_program.to_account_info();
                     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-                    token::transfer(cpi_context, token_manager.amount)?;
+                    token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
                 }
             }
 
@@ -629,7 +681,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::close_account(cpi_context)?;
+            token_interface::close_account(cpi_context)?;
 
             // close token_manager
             token_manager.state = TokenManagerState::Invalidated as u8;
@@ -640,7 +692,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             match token_manager.kind {
                 k if k == TokenManagerKind::Programmable as u8 => {
                     // get PDA seeds to sign with
-                    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), token_manager.mint.as_ref(), &[token_manager.bump]];
+                    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), token_manager.mint.as_ref(), count_seed.as_ref(), &[token_manager.bump]];
                     let token_manager_signer = &[&token_manager_seeds[..]];
 
                     let recipient_token_account_owner_info = next_account_info(remaining_accs)?;
@@ -738,16 +790,19 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
                         token_manager_signer,
                     )?;
                 }
+                // already reclaimed into the token manager token account
+                k if k == TokenManagerKind::PermanentDelegate as u8 => {}
                 _ => {
                     // transfer back to token_manager
-                    let cpi_accounts = Transfer {
+                    let cpi_accounts = TransferChecked {
                         from: ctx.accounts.recipient_token_account.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
                         to: ctx.accounts.token_manager_token_account.to_account_info(),
                         authority: token_manager.to_account_info(),
                     };
                     let cpi_program = ctx.accounts.token_program.to_account_info();
                     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-                    token::transfer(cpi_context, token_manager.amount)?;
+                    token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
                 }
             }
 
@@ -766,5 +821,17 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         _ => return Err(error!(ErrorCode::InvalidInvalidationType)),
     }
 
+    emit!(TokenManagerInvalidated {
+        token_manager: token_manager.key(),
+        mint,
+        issuer: token_manager.issuer,
+        invalidator: ctx.accounts.invalidator.key(),
+        recipient_token_account: ctx.accounts.recipient_token_account.key(),
+        kind: token_manager.kind,
+        invalidation_type: token_manager.invalidation_type,
+        state: token_manager.state,
+        closed: token_manager.to_account_info().data_is_empty(),
+        timestamp: token_manager.state_changed_at,
+    });
     Ok(())
 }
@@ -2,12 +2,12 @@ use mpl_token_metadata::instructions::TransferV1;
 use mpl_token_metadata::instructions::TransferV1InstructionArgs;
 
 use crate::errors::ErrorCode;
+use crate::events::TokenManagerIssued;
 use crate::state::*;
+use crate::utils::transfer_token_manager_amount;
 use anchor_lang::prelude::*;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
-use anchor_spl::token::Transfer;
-use anchor_spl::token::{self};
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
 use solana_program::program::invoke;
 use solana_program::system_instruction;
 
@@ -16,18 +16,18 @@ pub struct IssueCtx<'info> {
     #[account(mut, constraint = token_manager.state == TokenManagerState::Initialized as u8 @ ErrorCode::InvalidTokenManagerState)]
     token_manager: Box<Account<'info, TokenManager>>,
     #[account(mut, constraint = token_manager_token_account.owner == token_manager.key() @ ErrorCode::InvalidTokenManagerTokenAccount)]
-    token_manager_token_account: Box<Account<'info, TokenAccount>>,
+    token_manager_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     // issuer
     #[account(constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
     issuer: Signer<'info>,
     #[account(mut, constraint = issuer_token_account.mint == token_manager.mint && issuer_token_account.owner == issuer.key() @ ErrorCode::InvalidIssuerTokenAccount)]
-    issuer_token_account: Box<Account<'info, TokenAccount>>,
+    issuer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     // other
     #[account(mut)]
     payer: Signer<'info>,
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     system_program: Program<'info, System>,
 }
 
@@ -123,16 +123,28 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         }
         _ => {
             // transfer token to token manager token account
-            let cpi_accounts = Transfer {
-                from: ctx.accounts.issuer_token_account.to_account_info(),
-                to: ctx.accounts.token_manager_token_account.to_account_info(),
-                authority: ctx.accounts.issuer.to_account_info(),
-            };
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-            let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-            token::transfer(cpi_context, token_manager.amount)?;
+            transfer_token_manager_amount(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.issuer_token_account.to_account_info(),
+                &ctx.accounts.token_manager_token_account.to_account_info(),
+                &ctx.accounts.issuer.to_account_info(),
+                &token_manager.mint,
+                token_manager.amount,
+                remaining_accs,
+                &[],
+            )?;
         }
     }
 
+    emit!(TokenManagerIssued {
+        token_manager: token_manager.key(),
+        mint: token_manager.mint,
+        issuer: token_manager.issuer,
+        recipient_token_account: token_manager.recipient_token_account,
+        amount: token_manager.amount,
+        kind: token_manager.kind,
+        invalidation_type: token_manager.invalidation_type,
+        timestamp: token_manager.state_changed_at,
+    });
     Ok(())
 }
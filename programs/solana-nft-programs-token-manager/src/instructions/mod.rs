@@ -5,6 +5,7 @@ pub mod close_mint_manager;
 pub mod create_claim_receipt;
 pub mod create_mint_manager;
 pub mod init;
+pub mod init_counted;
 pub mod init_mint_counter;
 pub mod invalidate;
 pub mod issue;
@@ -22,6 +23,7 @@ pub use close_mint_manager::*;
 pub use create_claim_receipt::*;
 pub use create_mint_manager::*;
 pub use init::*;
+pub use init_counted::*;
 pub use init_mint_counter::*;
 pub use invalidate::*;
 pub use issue::*;
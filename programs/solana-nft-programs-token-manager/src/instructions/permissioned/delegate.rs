@@ -1,10 +1,10 @@
-use anchor_spl::token::Approve;
-use anchor_spl::token::FreezeAccount;
-use anchor_spl::token::Mint;
-use anchor_spl::token::ThawAccount;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
-use anchor_spl::token::{self};
+use anchor_spl::token_interface::Approve;
+use anchor_spl::token_interface::FreezeAccount;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::ThawAccount;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{self};
 use mpl_utils::assert_derivation;
 
 use crate::errors::ErrorCode;
@@ -16,7 +16,7 @@ pub struct DelegateCtx<'info> {
     #[account(mut, constraint = token_manager.kind == TokenManagerKind::Permissioned as u8 && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState)]
     token_manager: Box<Account<'info, TokenManager>>,
     #[account(constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
-    mint: Box<Account<'info, Mint>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(mut, seeds = [MINT_MANAGER_SEED.as_bytes(), mint.key().as_ref()], bump)]
     mint_manager: Box<Account<'info, MintManager>>,
 
@@ -29,8 +29,8 @@ pub struct DelegateCtx<'info> {
         && recipient_token_account.delegate.is_none()
         @ ErrorCode::InvalidRecipientTokenAccount
     )]
-    recipient_token_account: Box<Account<'info, TokenAccount>>,
-    token_program: Program<'info, Token>,
+    recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn handler(ctx: Context<DelegateCtx>) -> Result<()> {
@@ -47,7 +47,7 @@ pub fn handler(ctx: Context<DelegateCtx>) -> Result<()> {
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-    token::thaw_account(cpi_context)?;
+    token_interface::thaw_account(cpi_context)?;
 
     let cpi_accounts = Approve {
         to: ctx.accounts.recipient_token_account.to_account_info(),
@@ -56,7 +56,7 @@ pub fn handler(ctx: Context<DelegateCtx>) -> Result<()> {
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-    token::approve(cpi_context, ctx.accounts.token_manager.amount)?;
+    token_interface::approve(cpi_context, ctx.accounts.token_manager.amount)?;
 
     let cpi_accounts = FreezeAccount {
         account: ctx.accounts.recipient_token_account.to_account_info(),
@@ -65,7 +65,7 @@ pub fn handler(ctx: Context<DelegateCtx>) -> Result<()> {
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-    token::freeze_account(cpi_context)?;
+    token_interface::freeze_account(cpi_context)?;
 
     Ok(())
 }
@@ -1,22 +1,26 @@
 use crate::errors::ErrorCode;
+use crate::events::TokenManagerMigrated;
 use crate::state::MintManager;
 use crate::state::TokenManager;
 use crate::state::MINT_MANAGER_SEED;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::CloseAccount;
-use anchor_spl::token::Mint;
-use anchor_spl::token::ThawAccount;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
-use anchor_spl::token::{self};
+use anchor_spl::token_interface::CloseAccount;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::ThawAccount;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{self};
 use mpl_token_metadata::instructions::CreateMasterEditionV3;
 use mpl_token_metadata::instructions::CreateMasterEditionV3InstructionArgs;
 use solana_program::program::invoke_signed;
 
 #[derive(Accounts)]
 pub struct MigrateCtx<'info> {
-    #[account(mut, close = collector, constraint = token_manager.kind == TokenManagerKind::Permissioned as u8 && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState)]
+    #[account(mut, close = collector,
+        constraint = token_manager.kind == TokenManagerKind::Permissioned as u8 && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState,
+        constraint = mint_manager.token_managers <= 1 @ ErrorCode::OutstandingTokens,
+    )]
     mint_manager: Box<Account<'info, MintManager>>,
     #[account(mut, close = collector)]
     token_manager: Box<Account<'info, TokenManager>>,
@@ -25,9 +29,9 @@ pub struct MigrateCtx<'info> {
         && token_manager_token_account.mint == token_manager.mint
         @ ErrorCode::InvalidTokenManagerTokenAccount
     )]
-    token_manager_token_account: Box<Account<'info, TokenAccount>>,
+    token_manager_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(mut, constraint = token_manager.mint == mint.key() @ ErrorCode::InvalidMint )]
-    mint: Box<Account<'info, Mint>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
     /// CHECK: no checks required
     #[account(mut)]
     mint_metadata: UncheckedAccount<'info>,
@@ -36,7 +40,7 @@ pub struct MigrateCtx<'info> {
     mint_edition: UncheckedAccount<'info>,
 
     #[account(mut)]
-    holder_token_account: Box<Account<'info, TokenAccount>>,
+    holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// CHECK: no checks required
     #[account(constraint = token_manager.invalidators.contains(&invalidator.key()) @ ErrorCode::InvalidInvalidator)]
@@ -47,7 +51,7 @@ pub struct MigrateCtx<'info> {
     /// CHECK: no checks required
     #[account(mut)]
     collector: UncheckedAccount<'info>,
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     system_program: Program<'info, System>,
     /// CHECK: This is not dangerous because the ID is checked with instructions sysvar
     #[account(address = mpl_token_metadata::ID)]
@@ -55,6 +59,7 @@ pub struct MigrateCtx<'info> {
 }
 
 pub fn handler(ctx: Context<MigrateCtx>) -> Result<()> {
+    ctx.accounts.token_manager.state_changed_at = Clock::get().unwrap().unix_timestamp;
     let mint_manager_key = ctx.accounts.mint.key();
     let mint_manager_seeds = &[MINT_MANAGER_SEED.as_bytes(), mint_manager_key.as_ref(), &[ctx.accounts.mint_manager.bump]];
     let mint_manager_signer = &[&mint_manager_seeds[..]];
@@ -67,7 +72,7 @@ pub fn handler(ctx: Context<MigrateCtx>) -> Result<()> {
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-    token::thaw_account(cpi_context)?;
+    token_interface::thaw_account(cpi_context)?;
 
     invoke_signed(
         &CreateMasterEditionV3 {
@@ -96,7 +101,8 @@ pub fn handler(ctx: Context<MigrateCtx>) -> Result<()> {
     )?;
 
     let mint = ctx.accounts.token_manager.mint;
-    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), mint.as_ref(), &[ctx.accounts.token_manager.bump]];
+    let count_seed = ctx.accounts.token_manager.count_seed();
+    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), mint.as_ref(), count_seed.as_ref(), &[ctx.accounts.token_manager.bump]];
     let token_manager_signer = &[&token_manager_seeds[..]];
 
     // close token_manager_token_account
@@ -107,7 +113,15 @@ pub fn handler(ctx: Context<MigrateCtx>) -> Result<()> {
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-    token::close_account(cpi_context)?;
+    token_interface::close_account(cpi_context)?;
 
+    emit!(TokenManagerMigrated {
+        token_manager: ctx.accounts.token_manager.key(),
+        mint,
+        issuer: ctx.accounts.token_manager.issuer,
+        recipient_token_account: ctx.accounts.holder_token_account.key(),
+        kind: ctx.accounts.token_manager.kind,
+        timestamp: ctx.accounts.token_manager.state_changed_at,
+    });
     Ok(())
 }
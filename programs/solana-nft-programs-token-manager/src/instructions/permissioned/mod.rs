@@ -4,6 +4,9 @@ pub use delegate::*;
 pub mod undelegate;
 pub use undelegate::*;
 
+pub mod revoke_delegate;
+pub use revoke_delegate::*;
+
 pub mod send;
 pub use send::*;
 
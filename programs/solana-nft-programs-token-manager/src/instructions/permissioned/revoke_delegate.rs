@@ -0,0 +1,96 @@
+use anchor_spl::token_interface::FreezeAccount;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::ThawAccount;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::TransferChecked;
+use anchor_spl::token_interface::{self};
+use mpl_utils::assert_derivation;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevokeDelegateCtx<'info> {
+    #[account(mut, constraint = token_manager.kind == TokenManagerKind::Permissioned as u8 && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState)]
+    token_manager: Box<Account<'info, TokenManager>>,
+    #[account(mut, constraint =
+        token_manager_token_account.owner == token_manager.key()
+        && token_manager_token_account.mint == token_manager.mint
+        @ ErrorCode::InvalidTokenManagerTokenAccount
+    )]
+    token_manager_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
+    mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [MINT_MANAGER_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    mint_manager: Account<'info, MintManager>,
+
+    #[account(mut, constraint =
+        recipient_token_account.mint == token_manager.mint
+        && recipient_token_account.key() == token_manager.recipient_token_account.key()
+        && recipient_token_account.delegate.is_some()
+        && recipient_token_account.delegate.expect("Invalid delegate") == token_manager.key()
+        @ ErrorCode::InvalidRecipientTokenAccount
+    )]
+    recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(constraint = token_manager.transfer_authority == Some(transfer_authority.key()) @ ErrorCode::InvalidTransferAuthority)]
+    transfer_authority: Signer<'info>,
+    token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<RevokeDelegateCtx>) -> Result<()> {
+    let mint = ctx.accounts.mint.key();
+    let path = &[MINT_MANAGER_SEED.as_bytes(), mint.as_ref()];
+    let bump_seed = assert_derivation(ctx.program_id, &ctx.accounts.mint_manager.to_account_info(), path, error!(ErrorCode::PublicKeyMismatch))?;
+    let mint_manager_seeds = &[MINT_MANAGER_SEED.as_bytes(), mint.as_ref(), &[bump_seed]];
+    let mint_manager_signer = &[&mint_manager_seeds[..]];
+
+    let token_manager = &ctx.accounts.token_manager;
+    let count_seed = token_manager.count_seed();
+    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), token_manager.mint.as_ref(), count_seed.as_ref(), &[token_manager.bump]];
+    let token_manager_signer = &[&token_manager_seeds[..]];
+
+    let cpi_accounts = ThawAccount {
+        account: ctx.accounts.recipient_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: ctx.accounts.mint_manager.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
+    token_interface::thaw_account(cpi_context)?;
+
+    // only the owner can revoke, spending the whole delegated amount clears the delegate so the token manager
+    // moves it into its own token account as the delegate and sends it straight back
+    let delegated_amount = ctx.accounts.recipient_token_account.delegated_amount;
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.recipient_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.token_manager_token_account.to_account_info(),
+        authority: ctx.accounts.token_manager.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
+    token_interface::transfer_checked(cpi_context, delegated_amount, ctx.accounts.mint.decimals)?;
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.token_manager_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.token_manager.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
+    token_interface::transfer_checked(cpi_context, delegated_amount, ctx.accounts.mint.decimals)?;
+
+    let cpi_accounts = FreezeAccount {
+        account: ctx.accounts.recipient_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: ctx.accounts.mint_manager.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
+    token_interface::freeze_account(cpi_context)?;
+
+    Ok(())
+}
@@ -1,12 +1,12 @@
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::associated_token::{self};
-use anchor_spl::token::FreezeAccount;
-use anchor_spl::token::Mint;
-use anchor_spl::token::ThawAccount;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
-use anchor_spl::token::Transfer;
-use anchor_spl::token::{self};
+use anchor_spl::token_interface::FreezeAccount;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::ThawAccount;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::TransferChecked;
+use anchor_spl::token_interface::{self};
 use mpl_utils::assert_derivation;
 
 use crate::errors::ErrorCode;
@@ -15,14 +15,14 @@ use anchor_lang::prelude::*;
 use solana_program::sysvar::instructions::get_instruction_relative;
 use solana_program::sysvar::instructions::load_current_index_checked;
 use solana_program::sysvar::{self};
-use spl_associated_token_account::get_associated_token_address;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 
 #[derive(Accounts)]
 pub struct SendCtx<'info> {
     #[account(mut, constraint = token_manager.kind == TokenManagerKind::Permissioned as u8 && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState)]
     token_manager: Box<Account<'info, TokenManager>>,
     #[account(mut, constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
-    mint: Box<Account<'info, Mint>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(mut, seeds = [MINT_MANAGER_SEED.as_bytes(), mint.key().as_ref()], bump)]
     mint_manager: Account<'info, MintManager>,
 
@@ -34,7 +34,7 @@ pub struct SendCtx<'info> {
         && recipient_token_account.key() == token_manager.recipient_token_account.key()
         @ ErrorCode::InvalidRecipientTokenAccount
     )]
-    recipient_token_account: Box<Account<'info, TokenAccount>>,
+    recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// CHECK: This is not dangerous because the account is checked in the instruction handler
     target: UncheckedAccount<'info>,
@@ -45,7 +45,7 @@ pub struct SendCtx<'info> {
     payer: Signer<'info>,
 
     associated_token_program: Program<'info, AssociatedToken>,
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     system_program: Program<'info, System>,
     rent: Sysvar<'info, Rent>,
     /// CHECK: This is not dangerous because the ID is checked with instructions sysvar
@@ -75,7 +75,7 @@ pub fn handler(ctx: Context<SendCtx>) -> Result<()> {
     ctx.accounts.token_manager.recipient_token_account = ctx.accounts.target_token_account.key();
 
     // Check ATA
-    let associated_token_account = get_associated_token_address(&ctx.accounts.target.key(), &ctx.accounts.mint.key());
+    let associated_token_account = get_associated_token_address_with_program_id(&ctx.accounts.target.key(), &ctx.accounts.mint.key(), &ctx.accounts.token_program.key());
     if associated_token_account != ctx.accounts.target_token_account.key() {
         return Err(error!(ErrorCode::InvalidTargetTokenAccount));
     }
@@ -91,6 +91,13 @@ pub fn handler(ctx: Context<SendCtx>) -> Result<()> {
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
         associated_token::create(cpi_context)?;
+    } else {
+        // target account is frozen after the send so it must not hold other tokens of this mint
+        let target_token_account_data = ctx.accounts.target_token_account.try_borrow_data()?;
+        let target_token_account = TokenAccount::try_deserialize(&mut target_token_account_data.as_ref())?;
+        if target_token_account.amount != 0 {
+            return Err(error!(ErrorCode::InvalidTargetTokenAccount));
+        }
     }
 
     let mint = ctx.accounts.mint.key();
@@ -106,16 +113,17 @@ pub fn handler(ctx: Context<SendCtx>) -> Result<()> {
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-    token::thaw_account(cpi_context)?;
+    token_interface::thaw_account(cpi_context)?;
 
-    let cpi_accounts = Transfer {
+    let cpi_accounts = TransferChecked {
         from: ctx.accounts.recipient_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
         to: ctx.accounts.target_token_account.to_account_info(),
         authority: ctx.accounts.recipient.to_account_info(),
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_context, ctx.accounts.token_manager.amount)?;
+    token_interface::transfer_checked(cpi_context, ctx.accounts.token_manager.amount, ctx.accounts.mint.decimals)?;
 
     let cpi_accounts = FreezeAccount {
         account: ctx.accounts.target_token_account.to_account_info(),
@@ -124,7 +132,7 @@ pub fn handler(ctx: Context<SendCtx>) -> Result<()> {
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-    token::freeze_account(cpi_context)?;
+    token_interface::freeze_account(cpi_context)?;
 
     Ok(())
 }
@@ -1,16 +1,17 @@
 use crate::errors::ErrorCode;
+use crate::events::TokenManagerTransferred;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke_signed;
 use anchor_lang::AccountsClose;
-use anchor_spl::token::Approve;
-use anchor_spl::token::FreezeAccount;
-use anchor_spl::token::Mint;
-use anchor_spl::token::ThawAccount;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
-use anchor_spl::token::Transfer;
-use anchor_spl::token::{self};
+use anchor_spl::token_interface::Approve;
+use anchor_spl::token_interface::FreezeAccount;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::ThawAccount;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::TransferChecked;
+use anchor_spl::token_interface::{self};
 use mpl_utils::assert_derivation;
 
 #[derive(Accounts)]
@@ -20,14 +21,14 @@ pub struct TransferCtx<'info> {
     )]
     token_manager: Box<Account<'info, TokenManager>>,
     #[account(constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
-    mint: Box<Account<'info, Mint>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
 
     // current
     #[account(mut, constraint =
         current_holder_token_account.key() == token_manager.recipient_token_account
         @ ErrorCode::InvalidCurrentTokenAccount
     )]
-    current_holder_token_account: Box<Account<'info, TokenAccount>>,
+    current_holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     // new recipient
     #[account(mut)]
@@ -37,9 +38,9 @@ pub struct TransferCtx<'info> {
         && recipient_token_account.mint == token_manager.mint
         @ ErrorCode::InvalidRecipientTokenAccount
     )]
-    recipient_token_account: Box<Account<'info, TokenAccount>>,
+    recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, TransferCtx<'info>>) -> Result<()> {
@@ -50,9 +51,17 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
     let remaining_accs = &mut ctx.remaining_accounts.iter();
 
     // get PDA seeds to sign with
-    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), token_manager.mint.as_ref(), &[token_manager.bump]];
+    let count_seed = token_manager.count_seed();
+    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), token_manager.mint.as_ref(), count_seed.as_ref(), &[token_manager.bump]];
     let token_manager_signer = &[&token_manager_seeds[..]];
 
+    // freeze and delegate apply to the whole account so it must not hold other tokens of this mint
+    if (token_manager.kind == TokenManagerKind::Managed as u8 || token_manager.kind == TokenManagerKind::Edition as u8 || token_manager.kind == TokenManagerKind::Permissioned as u8)
+        && ctx.accounts.recipient_token_account.amount != 0
+    {
+        return Err(error!(ErrorCode::RecipientTokenAccountNotEmpty));
+    }
+
     // if this is a managed token, this means we will revoke it at the end of life, so we need to delegate and freeze
     match token_manager.kind {
         k if k == TokenManagerKind::Unmanaged as u8 => {}
@@ -73,17 +82,18 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-            token::thaw_account(cpi_context)?;
+            token_interface::thaw_account(cpi_context)?;
 
             // transfer amount to recipient token account
-            let cpi_accounts = Transfer {
+            let cpi_accounts = TransferChecked {
                 from: ctx.accounts.current_holder_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.recipient_token_account.to_account_info(),
                 authority: token_manager.to_account_info(),
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::transfer(cpi_context, token_manager.amount)?;
+            token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
 
             // set account delegate of recipient token account to token manager PDA
             let cpi_accounts = Approve {
@@ -93,7 +103,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-            token::approve(cpi_context, token_manager.amount)?;
+            token_interface::approve(cpi_context, token_manager.amount)?;
 
             // freeze recipient token account
             let cpi_accounts = FreezeAccount {
@@ -103,7 +113,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-            token::freeze_account(cpi_context)?;
+            token_interface::freeze_account(cpi_context)?;
         }
         k if k == TokenManagerKind::Edition as u8 => {
             let edition_info = next_account_info(remaining_accs)?;
@@ -133,14 +143,15 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             )?;
 
             // transfer amount to recipient token account
-            let cpi_accounts = Transfer {
+            let cpi_accounts = TransferChecked {
                 from: ctx.accounts.current_holder_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.recipient_token_account.to_account_info(),
                 authority: token_manager.to_account_info(),
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::transfer(cpi_context, token_manager.amount)?;
+            token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
 
             // set account delegate of recipient token account to token manager PDA
             let cpi_accounts = Approve {
@@ -150,7 +161,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-            token::approve(cpi_context, token_manager.amount)?;
+            token_interface::approve(cpi_context, token_manager.amount)?;
 
             invoke_signed(
                 &mpl_token_metadata::instructions::FreezeDelegatedAccount {
@@ -187,17 +198,18 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-            token::thaw_account(cpi_context)?;
+            token_interface::thaw_account(cpi_context)?;
 
             // transfer amount to recipient token account
-            let cpi_accounts = Transfer {
+            let cpi_accounts = TransferChecked {
                 from: ctx.accounts.current_holder_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.recipient_token_account.to_account_info(),
                 authority: token_manager.to_account_info(),
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::transfer(cpi_context, token_manager.amount)?;
+            token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
 
             // freeze recipient token account
             let cpi_accounts = FreezeAccount {
@@ -207,7 +219,27 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
-            token::freeze_account(cpi_context)?;
+            token_interface::freeze_account(cpi_context)?;
+        }
+
+        k if k == TokenManagerKind::PermanentDelegate as u8 => {
+            let mint_manager_info = next_account_info(remaining_accs)?;
+            let mint = ctx.accounts.mint.key();
+            let path = &[MINT_MANAGER_SEED.as_bytes(), mint.as_ref()];
+            let bump_seed = assert_derivation(ctx.program_id, mint_manager_info, path, error!(ErrorCode::PublicKeyMismatch))?;
+            let mint_manager_seeds = &[MINT_MANAGER_SEED.as_bytes(), mint.as_ref(), &[bump_seed]];
+            let mint_manager_signer = &[&mint_manager_seeds[..]];
+
+            // holder never approved the token manager so move it as permanent delegate
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.current_holder_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: mint_manager_info.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
+            token_interface::transfer_checked(cpi_context, token_manager.amount, ctx.accounts.mint.decimals)?;
         }
 
         _ => return Err(error!(ErrorCode::InvalidTokenManagerKind)),
@@ -228,5 +260,16 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         }
         transfer_receipt.close(ctx.accounts.recipient.to_account_info())?;
     }
+
+    emit!(TokenManagerTransferred {
+        token_manager: token_manager.key(),
+        mint: token_manager.mint,
+        issuer: token_manager.issuer,
+        from_token_account: ctx.accounts.current_holder_token_account.key(),
+        recipient: ctx.accounts.recipient.key(),
+        recipient_token_account: token_manager.recipient_token_account,
+        kind: token_manager.kind,
+        timestamp: token_manager.state_changed_at,
+    });
     Ok(())
 }
@@ -1,7 +1,7 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
 
 #[derive(Accounts)]
 pub struct UninitCtx<'info> {
@@ -16,7 +16,7 @@ pub struct UninitCtx<'info> {
         && issuer_token_account.amount >= 1
         @ ErrorCode::InvalidIssuerTokenAccount
     )]
-    issuer_token_account: Box<Account<'info, TokenAccount>>,
+    issuer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     system_program: Program<'info, System>,
 }
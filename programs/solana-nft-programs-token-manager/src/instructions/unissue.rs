@@ -1,12 +1,13 @@
 use crate::errors::ErrorCode;
+use crate::events::TokenManagerUnissued;
 use crate::state::*;
+use crate::utils::transfer_token_manager_amount;
 use anchor_lang::prelude::*;
 use anchor_lang::AccountsClose;
-use anchor_spl::token::CloseAccount;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
-use anchor_spl::token::Transfer;
-use anchor_spl::token::{self};
+use anchor_spl::token_interface::CloseAccount;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{self};
 use mpl_token_metadata::accounts::Metadata;
 use mpl_token_metadata::instructions::TransferV1;
 use mpl_token_metadata::instructions::TransferV1InstructionArgs;
@@ -18,24 +19,26 @@ pub struct UnissueCtx<'info> {
     #[account(mut, constraint = token_manager.state == TokenManagerState::Issued as u8)]
     token_manager: Box<Account<'info, TokenManager>>,
     #[account(mut, constraint = token_manager_token_account.owner == token_manager.key() @ ErrorCode::InvalidTokenManagerTokenAccount)]
-    token_manager_token_account: Box<Account<'info, TokenAccount>>,
+    token_manager_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     // issuer
     #[account(mut, constraint = token_manager.issuer == issuer.key() @ ErrorCode::InvalidIssuer)]
     issuer: Signer<'info>,
     #[account(mut, constraint = issuer_token_account.owner == issuer.key() @ ErrorCode::InvalidIssuerTokenAccount)]
-    issuer_token_account: Box<Account<'info, TokenAccount>>,
+    issuer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, UnissueCtx<'info>>) -> Result<()> {
     let remaining_accs = &mut ctx.remaining_accounts.iter().peekable();
     let token_manager = &mut ctx.accounts.token_manager;
+    token_manager.state_changed_at = Clock::get().unwrap().unix_timestamp;
 
     // get PDA seeds to sign with
     let mint = token_manager.mint;
-    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), mint.as_ref(), &[token_manager.bump]];
+    let count_seed = token_manager.count_seed();
+    let token_manager_seeds = &[TOKEN_MANAGER_SEED.as_bytes(), mint.as_ref(), count_seed.as_ref(), &[token_manager.bump]];
     let token_manager_signer = &[&token_manager_seeds[..]];
 
     if token_manager.kind != TokenManagerKind::Programmable as u8 {
@@ -121,21 +124,23 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::close_account(cpi_context)?;
+            token_interface::close_account(cpi_context)?;
 
             // close token manager account
             token_manager.close(ctx.accounts.issuer.to_account_info())?;
         }
         _ => {
             // transfer amount to destination token account
-            let cpi_accounts = Transfer {
-                from: ctx.accounts.token_manager_token_account.to_account_info(),
-                to: ctx.accounts.issuer_token_account.to_account_info(),
-                authority: token_manager.to_account_info(),
-            };
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-            let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::transfer(cpi_context, token_manager.amount)?;
+            transfer_token_manager_amount(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.token_manager_token_account.to_account_info(),
+                &ctx.accounts.issuer_token_account.to_account_info(),
+                &token_manager.to_account_info(),
+                &token_manager.mint,
+                token_manager.amount,
+                remaining_accs,
+                token_manager_signer,
+            )?;
 
             // close token account
             let cpi_accounts = CloseAccount {
@@ -145,12 +150,20 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(token_manager_signer);
-            token::close_account(cpi_context)?;
+            token_interface::close_account(cpi_context)?;
 
             // close token manager account
             token_manager.close(ctx.accounts.issuer.to_account_info())?;
         }
     }
 
+    emit!(TokenManagerUnissued {
+        token_manager: token_manager.key(),
+        mint: token_manager.mint,
+        issuer: token_manager.issuer,
+        recipient_token_account: token_manager.recipient_token_account,
+        kind: token_manager.kind,
+        timestamp: token_manager.state_changed_at,
+    });
     Ok(())
 }
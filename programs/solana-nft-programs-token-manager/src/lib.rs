@@ -1,4 +1,5 @@
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
@@ -16,6 +17,10 @@ pub mod solana_nft_programs_token_manager {
         init::handler(ctx, ix)
     }
 
+    pub fn init_counted(ctx: Context<InitCountedCtx>, ix: InitIx) -> Result<()> {
+        init_counted::handler(ctx, ix)
+    }
+
     pub fn uninit(ctx: Context<UninitCtx>) -> Result<()> {
         uninit::handler(ctx)
     }
@@ -106,6 +111,10 @@ pub mod solana_nft_programs_token_manager {
         permissioned::undelegate::handler(ctx)
     }
 
+    pub fn revoke_delegate(ctx: Context<RevokeDelegateCtx>) -> Result<()> {
+        permissioned::revoke_delegate::handler(ctx)
+    }
+
     pub fn migrate(ctx: Context<MigrateCtx>) -> Result<()> {
         permissioned::migrate::handler(ctx)
     }
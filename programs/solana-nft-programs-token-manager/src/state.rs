@@ -26,6 +26,8 @@ pub enum TokenManagerKind {
     Permissioned = 4,
     /// Token is a metaplex programmable nft
     Programmable = 5,
+    /// Token is a token-2022 mint whose permanent delegate is the mint manager so it can be reclaimed without freezing
+    PermanentDelegate = 6,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
@@ -53,6 +55,8 @@ pub fn token_manager_size(num_invalidators: usize) -> usize {
 
 pub const MAX_INVALIDATORS: u8 = 5;
 pub const TOKEN_MANAGER_SEED: &str = "token-manager";
+// Token managers with this version are derived from [TOKEN_MANAGER_SEED, mint, count] instead of [TOKEN_MANAGER_SEED, mint]
+pub const COUNTED_TOKEN_MANAGER_VERSION: u8 = 1;
 #[account]
 pub struct TokenManager {
     // Version of this token manager
@@ -89,6 +93,17 @@ pub struct TokenManager {
     pub invalidators: Vec<Pubkey>,
 }
 
+impl TokenManager {
+    // Seed between the mint and bump, empty for mint derived token managers since an empty seed does not change the address
+    pub fn count_seed(&self) -> Vec<u8> {
+        if self.version == COUNTED_TOKEN_MANAGER_VERSION {
+            self.count.to_le_bytes().to_vec()
+        } else {
+            Vec::new()
+        }
+    }
+}
+
 pub const MINT_MANAGER_SEED: &str = "mint-manager";
 pub const MINT_MANAGER_SIZE: usize = 8 + std::mem::size_of::<MintManager>() + 8;
 #[account]
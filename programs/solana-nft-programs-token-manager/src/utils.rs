@@ -2,10 +2,28 @@ use crate::errors::ErrorCode;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use anchor_spl::token_2022::spl_token_2022::extension::non_transferable::NonTransferable;
+use anchor_spl::token_2022::spl_token_2022::extension::permanent_delegate::get_permanent_delegate;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_hook;
+use anchor_spl::token_2022::spl_token_2022::extension::BaseStateWithExtensions;
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+use anchor_spl::token_2022::spl_token_2022::state::Mint;
+use anchor_spl::token_2022::spl_token_2022::{self};
+use anchor_spl::token_interface::TransferChecked;
+use anchor_spl::token_interface::{self};
+use mpl_token_metadata::accounts::Metadata;
 
 pub fn assert_payment_token_account(token_account: &Account<TokenAccount>, token_manager: &Account<TokenManager>, remaining_accounts: &mut std::slice::Iter<AccountInfo>) -> Result<()> {
+    assert_payment_target(&token_account.owner, token_manager, remaining_accounts)
+}
+
+// native payments are sent straight to the issuer or receipt holder wallet
+pub fn assert_payment_target(payment_target: &Pubkey, token_manager: &Account<TokenManager>, remaining_accounts: &mut std::slice::Iter<AccountInfo>) -> Result<()> {
     if token_manager.receipt_mint.is_none() {
-        if token_account.owner != token_manager.issuer {
+        if *payment_target != token_manager.issuer {
             return Err(error!(ErrorCode::InvalidIssuer));
         }
     } else {
@@ -14,9 +32,137 @@ pub fn assert_payment_token_account(token_account: &Account<TokenAccount>, token
         if !(receipt_token_account.mint == token_manager.receipt_mint.expect("No receipt mint") && receipt_token_account.amount > 0) {
             return Err(error!(ErrorCode::InvalidReceiptMint));
         }
-        if receipt_token_account.owner != token_account.owner {
+        if receipt_token_account.owner != *payment_target {
             return Err(error!(ErrorCode::InvalidReceiptMintOwner));
         }
     }
     Ok(())
 }
+
+pub fn assert_mint_extensions(mint_info: &AccountInfo) -> Result<()> {
+    // legacy spl token mints carry no extensions
+    if mint_info.owner != &spl_token_2022::ID {
+        return Ok(());
+    }
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+
+    // token program rejects every transfer out of a non transferable account
+    if mint.get_extension::<NonTransferable>().is_ok() {
+        return Err(error!(ErrorCode::NonTransferableMint));
+    }
+    // fees are withheld on the receiving account so amounts would drift from token_manager.amount
+    if let Ok(transfer_fee_config) = mint.get_extension::<TransferFeeConfig>() {
+        if Option::<Pubkey>::from(transfer_fee_config.transfer_fee_config_authority).is_some()
+            || u16::from(transfer_fee_config.older_transfer_fee.transfer_fee_basis_points) != 0
+            || u16::from(transfer_fee_config.newer_transfer_fee.transfer_fee_basis_points) != 0
+        {
+            return Err(error!(ErrorCode::TransferFeeNotSupported));
+        }
+    }
+    // hook accounts are not forwarded through the token manager cpis
+    if transfer_hook::get_program_id(&mint).is_some() {
+        return Err(error!(ErrorCode::TransferHookNotSupported));
+    }
+    Ok(())
+}
+
+// legacy spl token transfers need no mint so issue and unissue keep their original accounts,
+// token 2022 mints are read from the next remaining account for transfer_checked
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_token_manager_amount<'a, 'info: 'a, I: Iterator<Item = &'a AccountInfo<'info>>>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    mint: &Pubkey,
+    amount: u64,
+    remaining_accounts: &mut I,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    if token_program.key() == token::ID {
+        let cpi_accounts = Transfer {
+            from: from.clone(),
+            to: to.clone(),
+            authority: authority.clone(),
+        };
+        let cpi_context = CpiContext::new(token_program.clone(), cpi_accounts).with_signer(signer);
+        return token::transfer(cpi_context, amount);
+    }
+
+    let mint_info = next_account_info(remaining_accounts)?;
+    if mint_info.key() != *mint {
+        return Err(error!(ErrorCode::InvalidMint));
+    }
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_info.try_borrow_data()?)?.base.decimals;
+    let cpi_accounts = TransferChecked {
+        from: from.clone(),
+        mint: mint_info.clone(),
+        to: to.clone(),
+        authority: authority.clone(),
+    };
+    let cpi_context = CpiContext::new(token_program.clone(), cpi_accounts).with_signer(signer);
+    token_interface::transfer_checked(cpi_context, amount, decimals)
+}
+
+pub fn assert_permanent_delegate(mint_info: &AccountInfo, delegate: &Pubkey) -> Result<()> {
+    if mint_info.owner != &spl_token_2022::ID {
+        return Err(error!(ErrorCode::InvalidPermanentDelegate));
+    }
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    if get_permanent_delegate(&mint) != Some(*delegate) {
+        return Err(error!(ErrorCode::InvalidPermanentDelegate));
+    }
+    Ok(())
+}
+
+// the payment manager reads one token account per creator with a share, callers forwarding more accounts after them need to know where they end
+pub fn royalty_creator_accounts_len(mint_metadata_info: &AccountInfo) -> usize {
+    if mint_metadata_info.data_is_empty() || mint_metadata_info.owner != &mpl_token_metadata::ID {
+        return 0;
+    }
+    let mint_metadata_data = mint_metadata_info.try_borrow_data().expect("Failed to borrow data");
+    match Metadata::deserialize(&mut mint_metadata_data.as_ref()) {
+        Ok(metadata) => metadata.creators.map(|creators| creators.iter().filter(|creator| creator.share != 0).count()).unwrap_or(0),
+        Err(_) => 0,
+    }
+}
+
+// one entry of an invalidator batch_invalidate crank
+pub struct BatchInvalidateEntry<'info> {
+    pub token_manager: AccountInfo<'info>,
+    pub invalidator: AccountInfo<'info>,
+    pub token_manager_token_account: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub recipient_token_account: AccountInfo<'info>,
+    // additional accounts forwarded to the token manager invalidate
+    pub remaining_accounts: Vec<AccountInfo<'info>>,
+}
+
+impl<'info> BatchInvalidateEntry<'info> {
+    // already invalidated by another crank
+    pub fn is_closed(&self) -> bool {
+        self.token_manager.data_is_empty() || self.invalidator.data_is_empty()
+    }
+}
+
+pub fn next_batch_invalidate_entry<'info>(remaining_accounts: &mut std::slice::Iter<AccountInfo<'info>>, remaining_accounts_length: u8) -> Result<BatchInvalidateEntry<'info>> {
+    let token_manager = next_account_info(remaining_accounts)?.to_account_info();
+    let invalidator = next_account_info(remaining_accounts)?.to_account_info();
+    let token_manager_token_account = next_account_info(remaining_accounts)?.to_account_info();
+    let mint = next_account_info(remaining_accounts)?.to_account_info();
+    let recipient_token_account = next_account_info(remaining_accounts)?.to_account_info();
+    let mut entry_remaining_accounts = Vec::new();
+    for _ in 0..remaining_accounts_length {
+        entry_remaining_accounts.push(next_account_info(remaining_accounts)?.to_account_info());
+    }
+    Ok(BatchInvalidateEntry {
+        token_manager,
+        invalidator,
+        token_manager_token_account,
+        mint,
+        recipient_token_account,
+        remaining_accounts: entry_remaining_accounts,
+    })
+}
@@ -56,4 +56,64 @@ pub enum ErrorCode {
     InvalidRemainingAccountsSize,
     #[msg("Invalid payer payment token account")]
     InvalidPayerPaymentTokenAccount,
+    #[msg("Invalid offer")]
+    InvalidOffer,
+    #[msg("Offer payment amount has changed")]
+    OfferChanged,
+    #[msg("Invalid holder payment token account")]
+    InvalidHolderPaymentTokenAccount,
+    #[msg("Invalid collection bid")]
+    InvalidCollectionBid,
+    #[msg("Invalid collection bid token account")]
+    InvalidCollectionBidTokenAccount,
+    #[msg("Collection bid payment amount has changed")]
+    CollectionBidChanged,
+    #[msg("Invalid mint metadata")]
+    InvalidMintMetadata,
+    #[msg("Mint is not a verified member of the collection")]
+    MintNotInCollection,
+    #[msg("Invalid listing start or expiration")]
+    InvalidListingSchedule,
+    #[msg("Listing has not started yet")]
+    ListingNotStarted,
+    #[msg("Listing has expired")]
+    ListingExpired,
+    #[msg("Listing has not expired")]
+    ListingNotExpired,
+    #[msg("Invalid dutch auction")]
+    InvalidDutchAuction,
+    #[msg("Invalid english auction")]
+    InvalidEnglishAuction,
+    #[msg("Auction has not started yet")]
+    AuctionNotStarted,
+    #[msg("Auction has ended")]
+    AuctionEnded,
+    #[msg("Auction has not ended")]
+    AuctionNotEnded,
+    #[msg("Auction price is above the max payment amount")]
+    AuctionPriceExceeded,
+    #[msg("Bid is below the minimum bid")]
+    BidTooLow,
+    #[msg("Auction already has bids")]
+    AuctionHasBids,
+    #[msg("Invalid bidder")]
+    InvalidBidder,
+    #[msg("Invalid bidder payment token account")]
+    InvalidBidderPaymentTokenAccount,
+    #[msg("Invalid auction token account")]
+    InvalidAuctionTokenAccount,
+    #[msg("Only the lister or the highest bidder can settle the auction")]
+    InvalidSettler,
+    #[msg("Auction has been settled")]
+    AuctionSettled,
+    #[msg("Auction has not been settled")]
+    AuctionNotSettled,
+    #[msg("Auction can still be settled")]
+    AuctionSettleable,
+    #[msg("Token is in an english auction")]
+    TokenInEnglishAuction,
+    #[msg("Buyer is not allowed to accept this listing")]
+    BuyerNotAllowed,
+    #[msg("Token is listed")]
+    TokenListed,
 }
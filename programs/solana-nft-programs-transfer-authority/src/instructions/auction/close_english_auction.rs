@@ -0,0 +1,87 @@
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use spl_associated_token_account::get_associated_token_address;
+
+#[derive(Accounts)]
+pub struct CloseEnglishAuctionCtx<'info> {
+    /// CHECK: This is not dangerous because we expect it to potentially be empty
+    #[account(constraint = token_manager.key() == english_auction.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked against the token manager in the handler
+    lister_mint_token_account: UncheckedAccount<'info>,
+
+    #[account(mut, close = lister)]
+    english_auction: Box<Account<'info, EnglishAuction>>,
+    /// CHECK: This is not dangerous because it is a system account PDA that only holds the escrowed bid
+    #[account(mut, seeds = [ENGLISH_AUCTION_ESCROW_SEED.as_bytes(), english_auction.key().as_ref()], bump = english_auction.escrow_bump)]
+    english_auction_escrow: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    english_auction_payment_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because of the english_auction.lister check
+    #[account(mut, constraint = lister.key() == english_auction.lister @ ErrorCode::InvalidLister)]
+    lister: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because of the english_auction.highest_bidder check
+    #[account(mut, constraint = english_auction.highest_bidder.is_none() || english_auction.highest_bidder == Some(bidder.key()) @ ErrorCode::InvalidBidder)]
+    bidder: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    bidder_payment_token_account: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CloseEnglishAuctionCtx>) -> Result<()> {
+    // settlement needs the lister to still hold the token under a claimed token manager, and a claim needs the escrow to still hold it
+    if !ctx.accounts.token_manager.data_is_empty() {
+        let token_manager = Account::<TokenManager>::try_from(&ctx.accounts.token_manager)?;
+        if ctx.accounts.english_auction.settled {
+            if token_manager.state == TokenManagerState::Claimed as u8
+                && token_manager.recipient_token_account == get_associated_token_address(&ctx.accounts.english_auction_escrow.key(), &token_manager.mint)
+            {
+                return Err(error!(ErrorCode::AuctionSettled));
+            }
+        } else if token_manager.state == TokenManagerState::Claimed as u8
+            && ctx.accounts.lister_mint_token_account.key() == token_manager.recipient_token_account
+            && !ctx.accounts.lister_mint_token_account.data_is_empty()
+        {
+            let lister_mint_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.lister_mint_token_account)?;
+            if lister_mint_token_account.owner == ctx.accounts.english_auction.lister && lister_mint_token_account.amount >= token_manager.amount {
+                return Err(error!(ErrorCode::AuctionSettleable));
+            }
+        }
+    }
+
+    if ctx.accounts.english_auction.highest_bidder.is_none() {
+        return Ok(());
+    }
+    let english_auction_key = ctx.accounts.english_auction.key();
+    let english_auction_escrow_seeds = &[ENGLISH_AUCTION_ESCROW_SEED.as_bytes(), english_auction_key.as_ref(), &[ctx.accounts.english_auction.escrow_bump]];
+    let english_auction_escrow_signer = &[&english_auction_escrow_seeds[..]];
+    let escrow = Escrow {
+        escrow: &ctx.accounts.english_auction_escrow.to_account_info(),
+        escrow_payment_token_account: &ctx.accounts.english_auction_payment_token_account.to_account_info(),
+        payment_mint: ctx.accounts.english_auction.payment_mint,
+        signer: english_auction_escrow_signer,
+        invalid_token_account_error: ErrorCode::InvalidAuctionTokenAccount,
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    };
+
+    // the highest bid goes back to the bidder
+    refund_escrow(
+        &escrow,
+        &ctx.accounts.bidder.to_account_info(),
+        &ctx.accounts.bidder_payment_token_account.to_account_info(),
+        ErrorCode::InvalidBidderPaymentTokenAccount,
+    )
+}
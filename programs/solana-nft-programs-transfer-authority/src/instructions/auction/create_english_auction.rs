@@ -0,0 +1,115 @@
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_program::sysvar::{self};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateEnglishAuctionIx {
+    pub payment_mint: Pubkey,
+    pub reserve_amount: u64,
+    pub min_increment: u64,
+    pub end_at: i64,
+    pub anti_snipe_seconds: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: CreateEnglishAuctionIx)]
+pub struct CreateEnglishAuctionCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = ENGLISH_AUCTION_SIZE,
+        seeds = [ENGLISH_AUCTION_SEED.as_bytes(), token_manager.key().as_ref()], bump,
+    )]
+    english_auction: Box<Account<'info, EnglishAuction>>,
+    /// CHECK: This is not dangerous because it is a system account PDA that only holds the escrowed bid
+    #[account(seeds = [ENGLISH_AUCTION_ESCROW_SEED.as_bytes(), english_auction.key().as_ref()], bump)]
+    english_auction_escrow: UncheckedAccount<'info>,
+
+    #[account(constraint = transfer_authority.key() == token_manager.transfer_authority.expect("No transfer authority for token manager") @ ErrorCode::InvalidTransferAuthority)]
+    transfer_authority: Box<Account<'info, TransferAuthority>>,
+    marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(mut, constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    /// CHECK: This is not dangerous because this account is not read in this instruction
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because this account is not read in this instruction
+    #[account(mut)]
+    mint_manager: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = lister_token_account.key() == token_manager.recipient_token_account @ ErrorCode::InvalidListerMintTokenAccount)]
+    lister_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = lister.key() == lister_token_account.owner @ ErrorCode::InvalidLister)]
+    lister: Signer<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    /// CHECK: This is not dangerous because the ID is checked with instructions sysvar
+    #[account(address = sysvar::instructions::id())]
+    instructions: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is only checked to be empty
+    #[account(seeds = [LISTING_SEED.as_bytes(), token_manager.key().as_ref()], bump, constraint = listing.data_is_empty() @ ErrorCode::TokenListed)]
+    listing: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CreateEnglishAuctionCtx>, ix: CreateEnglishAuctionIx) -> Result<()> {
+    if ix.min_increment == 0 || ix.anti_snipe_seconds < 0 || ix.end_at <= Clock::get().unwrap().unix_timestamp {
+        return Err(error!(ErrorCode::InvalidEnglishAuction));
+    }
+
+    let english_auction = &mut ctx.accounts.english_auction;
+    english_auction.bump = *ctx.bumps.get("english_auction").unwrap();
+    english_auction.escrow_bump = *ctx.bumps.get("english_auction_escrow").unwrap();
+    english_auction.lister = ctx.accounts.lister.key();
+    english_auction.token_manager = ctx.accounts.token_manager.key();
+    english_auction.marketplace = ctx.accounts.marketplace.key();
+    // payment
+    english_auction.payment_mint = ix.payment_mint;
+    english_auction.reserve_amount = ix.reserve_amount;
+    english_auction.min_increment = ix.min_increment;
+    english_auction.end_at = ix.end_at;
+    english_auction.anti_snipe_seconds = ix.anti_snipe_seconds;
+    english_auction.highest_bidder = None;
+    english_auction.highest_bid = 0;
+    english_auction.highest_bid_escrow_amount = 0;
+    english_auction.settled = false;
+
+    if ctx.accounts.marketplace.payment_mints.is_some() && !ctx.accounts.marketplace.payment_mints.as_ref().unwrap().contains(&ix.payment_mint) {
+        return Err(error!(ErrorCode::InvalidPaymentMint));
+    }
+
+    if ctx.accounts.transfer_authority.allowed_marketplaces.is_some() && !ctx.accounts.transfer_authority.allowed_marketplaces.as_ref().unwrap().contains(&ctx.accounts.marketplace.key()) {
+        return Err(error!(ErrorCode::MarketplaceNotAllowed));
+    }
+
+    if ctx.accounts.lister_token_account.delegate.is_none() {
+        let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::DelegateCtx {
+            token_manager: ctx.accounts.token_manager.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            mint_manager: ctx.accounts.mint_manager.to_account_info(),
+            recipient: ctx.accounts.lister.to_account_info(),
+            recipient_token_account: ctx.accounts.lister_token_account.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts);
+        solana_nft_programs_token_manager::cpi::delegate(cpi_ctx)?;
+    } else if ctx.accounts.lister_token_account.delegate.expect("Invalid delegate") != ctx.accounts.token_manager.key()
+        || ctx.accounts.lister_token_account.delegated_amount != ctx.accounts.token_manager.amount
+    {
+        return Err(error!(ErrorCode::TokenNotDelegated));
+    }
+
+    Ok(())
+}
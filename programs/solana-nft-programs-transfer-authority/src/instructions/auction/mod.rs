@@ -0,0 +1,19 @@
+pub mod accept_dutch_auction;
+pub mod cancel_english_auction;
+pub mod claim_english_auction;
+pub mod close_english_auction;
+pub mod create_dutch_auction;
+pub mod create_english_auction;
+pub mod place_bid;
+pub mod remove_dutch_auction;
+pub mod settle_english_auction;
+
+pub use accept_dutch_auction::*;
+pub use cancel_english_auction::*;
+pub use claim_english_auction::*;
+pub use close_english_auction::*;
+pub use create_dutch_auction::*;
+pub use create_english_auction::*;
+pub use place_bid::*;
+pub use remove_dutch_auction::*;
+pub use settle_english_auction::*;
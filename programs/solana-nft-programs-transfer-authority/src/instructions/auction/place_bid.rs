@@ -0,0 +1,156 @@
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_payment_manager::state::PaymentManager;
+
+use solana_program::program::invoke;
+use solana_program::program::invoke_signed;
+use solana_program::system_instruction;
+use spl_associated_token_account::get_associated_token_address;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PlaceBidIx {
+    pub payment_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct PlaceBidCtx<'info> {
+    #[account(mut)]
+    english_auction: Box<Account<'info, EnglishAuction>>,
+    /// CHECK: This is not dangerous because it is a system account PDA that only holds the escrowed bid
+    #[account(mut, seeds = [ENGLISH_AUCTION_ESCROW_SEED.as_bytes(), english_auction.key().as_ref()], bump = english_auction.escrow_bump)]
+    english_auction_escrow: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    english_auction_payment_token_account: UncheckedAccount<'info>,
+
+    #[account(constraint = marketplace.key() == english_auction.marketplace @ ErrorCode::InvalidMarketplace)]
+    marketplace: Box<Account<'info, Marketplace>>,
+    /// CHECK: This is not dangerous because of the marketplace.payment_manager check
+    #[account(constraint = payment_manager.key() == marketplace.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    bidder: Signer<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    bidder_payment_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked against english_auction.highest_bidder in the handler
+    #[account(mut)]
+    previous_bidder: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    previous_bidder_payment_token_account: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<PlaceBidCtx>, ix: PlaceBidIx) -> Result<()> {
+    let now = Clock::get().unwrap().unix_timestamp;
+    if now >= ctx.accounts.english_auction.end_at {
+        return Err(error!(ErrorCode::AuctionEnded));
+    }
+    if ix.payment_amount < ctx.accounts.english_auction.min_bid() {
+        return Err(error!(ErrorCode::BidTooLow));
+    }
+
+    let english_auction_key = ctx.accounts.english_auction.key();
+    let english_auction_escrow_seeds = &[ENGLISH_AUCTION_ESCROW_SEED.as_bytes(), english_auction_key.as_ref(), &[ctx.accounts.english_auction.escrow_bump]];
+    let english_auction_escrow_signer = &[&english_auction_escrow_seeds[..]];
+    let payment_mint = ctx.accounts.english_auction.payment_mint;
+
+    // the escrow pays the taker fee on top of the bid when the auction is settled
+    let mut escrow_amount = ix.payment_amount;
+    if ctx.accounts.payment_manager.owner.key() == solana_nft_programs_payment_manager::id() {
+        let payment_manager = Account::<PaymentManager>::try_from(&ctx.accounts.payment_manager)?;
+        let taker_fee = ix
+            .payment_amount
+            .checked_mul(payment_manager.taker_fee_basis_points.into())
+            .expect("Multiplication error")
+            .checked_div(BASIS_POINTS_DIVISOR)
+            .expect("Division error");
+        escrow_amount = escrow_amount.checked_add(taker_fee).expect("Add error");
+    }
+
+    // native SOL
+    if payment_mint == Pubkey::default() {
+        invoke(
+            &system_instruction::transfer(&ctx.accounts.bidder.key(), &ctx.accounts.english_auction_escrow.key(), escrow_amount),
+            &[
+                ctx.accounts.bidder.to_account_info(),
+                ctx.accounts.english_auction_escrow.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    } else {
+        // any SPL token
+        let bidder_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.bidder_payment_token_account)?;
+        if bidder_payment_token_account.mint != payment_mint || bidder_payment_token_account.owner != ctx.accounts.bidder.key() {
+            return Err(error!(ErrorCode::InvalidBidderPaymentTokenAccount));
+        }
+        if ctx.accounts.english_auction_payment_token_account.key() != get_associated_token_address(&ctx.accounts.english_auction_escrow.key(), &payment_mint) {
+            return Err(error!(ErrorCode::InvalidAuctionTokenAccount));
+        }
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.bidder_payment_token_account.to_account_info(),
+            to: ctx.accounts.english_auction_payment_token_account.to_account_info(),
+            authority: ctx.accounts.bidder.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_context, escrow_amount)?;
+    }
+
+    // refund the bid that was outbid
+    if let Some(previous_bidder) = ctx.accounts.english_auction.highest_bidder {
+        if ctx.accounts.previous_bidder.key() != previous_bidder {
+            return Err(error!(ErrorCode::InvalidBidder));
+        }
+        let refund_amount = ctx.accounts.english_auction.highest_bid_escrow_amount;
+
+        if payment_mint == Pubkey::default() {
+            invoke_signed(
+                &system_instruction::transfer(&ctx.accounts.english_auction_escrow.key(), &previous_bidder, refund_amount),
+                &[
+                    ctx.accounts.english_auction_escrow.to_account_info(),
+                    ctx.accounts.previous_bidder.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+                english_auction_escrow_signer,
+            )?;
+        } else {
+            let previous_bidder_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.previous_bidder_payment_token_account)?;
+            if previous_bidder_payment_token_account.mint != payment_mint || previous_bidder_payment_token_account.owner != previous_bidder {
+                return Err(error!(ErrorCode::InvalidBidderPaymentTokenAccount));
+            }
+
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.english_auction_payment_token_account.to_account_info(),
+                to: ctx.accounts.previous_bidder_payment_token_account.to_account_info(),
+                authority: ctx.accounts.english_auction_escrow.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(english_auction_escrow_signer);
+            token::transfer(cpi_context, refund_amount)?;
+        }
+    }
+
+    let english_auction = &mut ctx.accounts.english_auction;
+    english_auction.highest_bidder = Some(ctx.accounts.bidder.key());
+    english_auction.highest_bid = ix.payment_amount;
+    english_auction.highest_bid_escrow_amount = escrow_amount;
+
+    // anti-snipe, a late bid keeps the auction open for at least anti_snipe_seconds
+    let min_end_at = now.checked_add(english_auction.anti_snipe_seconds).expect("Add error");
+    if english_auction.end_at < min_end_at {
+        english_auction.end_at = min_end_at;
+    }
+    Ok(())
+}
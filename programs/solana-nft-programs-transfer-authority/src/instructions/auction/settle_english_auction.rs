@@ -0,0 +1,180 @@
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+use solana_nft_programs_payment_manager::program::SolanaNftProgramsPaymentManager;
+
+use solana_program::sysvar::{self};
+
+#[derive(Accounts)]
+pub struct SettleEnglishAuctionCtx<'info> {
+    #[account(mut, constraint = transfer_authority.key() == token_manager.transfer_authority.expect("No transfer authority for token manager") @ ErrorCode::InvalidTransferAuthority)]
+    transfer_authority: Box<Account<'info, TransferAuthority>>,
+    /// CHECK: This is not dangerous because this is the receipt getting initialized
+    #[account(mut)]
+    transfer_receipt: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = !english_auction.settled @ ErrorCode::AuctionSettled)]
+    english_auction: Box<Account<'info, EnglishAuction>>,
+    /// CHECK: This is not dangerous because it is a system account PDA that only holds the escrowed bid
+    #[account(mut, seeds = [ENGLISH_AUCTION_ESCROW_SEED.as_bytes(), english_auction.key().as_ref()], bump = english_auction.escrow_bump)]
+    english_auction_escrow: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    english_auction_payment_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    english_auction_mint_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because account is checked below
+    #[account(mut)]
+    lister_payment_token_account: UncheckedAccount<'info>,
+    #[account(mut, constraint =
+        lister_mint_token_account.amount >= token_manager.amount &&
+        lister_mint_token_account.mint == token_manager.mint &&
+        lister_mint_token_account.owner == lister.key() @ ErrorCode::InvalidListerMintTokenAccount)]
+    lister_mint_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because of the english_auction.lister check
+    #[account(mut, constraint = lister.key() == english_auction.lister @ ErrorCode::InvalidLister)]
+    lister: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because of the english_auction.highest_bidder check
+    #[account(mut, constraint = english_auction.highest_bidder == Some(bidder.key()) @ ErrorCode::InvalidBidder)]
+    bidder: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    bidder_mint_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    bidder_payment_token_account: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = settler.key() == english_auction.lister || settler.key() == bidder.key() @ ErrorCode::InvalidSettler)]
+    settler: Signer<'info>,
+
+    #[account(mut, constraint = marketplace.key() == english_auction.marketplace @ ErrorCode::InvalidMarketplace)]
+    marketplace: Box<Account<'info, Marketplace>>,
+    #[account(mut, constraint = token_manager.key() == english_auction.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+    /// CHECK: This is not dangerous because of the token_manager.mint check
+    #[account(constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked in the payment manager handle payment with royalties instruction
+    mint_metadata_info: UncheckedAccount<'info>,
+
+    // payment accounts
+    /// CHECK: This is not dangerous because of the marketplace.payment_manager check
+    #[account(mut, constraint = payment_manager.key() == marketplace.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because of the english_auction.payment_mint check
+    #[account(constraint = payment_mint.key() == english_auction.payment_mint @ ErrorCode::InvalidPaymentMint)]
+    payment_mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked in the payment manager handle payment with royalties instruction
+    #[account(mut)]
+    fee_collector_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked in the payment manager handle payment with royalties instruction
+    #[account(mut)]
+    fee_collector: UncheckedAccount<'info>,
+
+    solana_nft_programs_payment_manager: Program<'info, SolanaNftProgramsPaymentManager>,
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    /// CHECK: This is not dangerous because the ID is checked with instructions sysvar
+    #[account(address = sysvar::instructions::id())]
+    instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, SettleEnglishAuctionCtx<'info>>) -> Result<()> {
+    if Clock::get().unwrap().unix_timestamp < ctx.accounts.english_auction.end_at {
+        return Err(error!(ErrorCode::AuctionNotEnded));
+    }
+
+    let (payment_remaining_accounts, transfer_accounts) = split_transfer_accounts(ctx.accounts.token_manager.kind, ctx.remaining_accounts)?;
+
+    let english_auction_key = ctx.accounts.english_auction.key();
+    let english_auction_escrow_seeds = &[ENGLISH_AUCTION_ESCROW_SEED.as_bytes(), english_auction_key.as_ref(), &[ctx.accounts.english_auction.escrow_bump]];
+    let english_auction_escrow_signer = &[&english_auction_escrow_seeds[..]];
+    let escrow = Escrow {
+        escrow: &ctx.accounts.english_auction_escrow.to_account_info(),
+        escrow_payment_token_account: &ctx.accounts.english_auction_payment_token_account.to_account_info(),
+        payment_mint: ctx.accounts.english_auction.payment_mint,
+        signer: english_auction_escrow_signer,
+        invalid_token_account_error: ErrorCode::InvalidAuctionTokenAccount,
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    };
+
+    let transfer = ReceiptTransfer {
+        transfer_authority: &ctx.accounts.transfer_authority,
+        transfer_receipt: &ctx.accounts.transfer_receipt.to_account_info(),
+        token_manager: &ctx.accounts.token_manager,
+        mint: &ctx.accounts.mint.to_account_info(),
+        transfer_accounts,
+        solana_nft_programs_token_manager: &ctx.accounts.solana_nft_programs_token_manager.to_account_info(),
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    };
+    // the lister cannot sign for the bidder, so the escrow holds the token and the bid until the bidder claims both
+    if ctx.accounts.settler.key() != ctx.accounts.bidder.key() {
+        take_custody_in_escrow(
+            &escrow,
+            &transfer,
+            &ctx.accounts.lister.to_account_info(),
+            &ctx.accounts.lister_mint_token_account,
+            &ctx.accounts.english_auction_mint_token_account.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+        )?;
+        ctx.accounts.english_auction.settled = true;
+        return Ok(());
+    }
+
+    if ctx.accounts.payment_mint.key() != Pubkey::default() {
+        // check on lister token account
+        let lister_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.lister_payment_token_account)?;
+        if lister_payment_token_account.mint != ctx.accounts.english_auction.payment_mint || lister_payment_token_account.owner != ctx.accounts.lister.key() {
+            return Err(error!(ErrorCode::InvalidListerPaymentTokenAccount));
+        }
+    }
+    let payment = EscrowPayment {
+        payment_manager: &ctx.accounts.payment_manager.to_account_info(),
+        payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+        payment_target: &ctx.accounts.lister.to_account_info(),
+        payment_target_token_account: &ctx.accounts.lister_payment_token_account.to_account_info(),
+        fee_collector: &ctx.accounts.fee_collector.to_account_info(),
+        fee_collector_token_account: &ctx.accounts.fee_collector_token_account.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        mint_metadata: &ctx.accounts.mint_metadata_info.to_account_info(),
+        solana_nft_programs_payment_manager: &ctx.accounts.solana_nft_programs_payment_manager.to_account_info(),
+    };
+    pay_from_escrow(&escrow, &payment, ctx.accounts.english_auction.highest_bid, payment_remaining_accounts)?;
+
+    let bidder_mint_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.bidder_mint_token_account)?;
+    if bidder_mint_token_account.mint != ctx.accounts.token_manager.mint || bidder_mint_token_account.owner != ctx.accounts.bidder.key() {
+        return Err(error!(ErrorCode::InvalidBuyerMintTokenAccount));
+    }
+    transfer_with_receipt(
+        &transfer,
+        &ctx.accounts.lister_mint_token_account.to_account_info(),
+        &ctx.accounts.bidder.to_account_info(),
+        &ctx.accounts.bidder_mint_token_account.to_account_info(),
+        &ctx.accounts.bidder.to_account_info(),
+        &[],
+    )?;
+
+    // anything left in the escrow goes back to the bidder
+    refund_escrow(
+        &escrow,
+        &ctx.accounts.bidder.to_account_info(),
+        &ctx.accounts.bidder_payment_token_account.to_account_info(),
+        ErrorCode::InvalidBidderPaymentTokenAccount,
+    )?;
+    ctx.accounts.english_auction.close(ctx.accounts.lister.to_account_info())
+}
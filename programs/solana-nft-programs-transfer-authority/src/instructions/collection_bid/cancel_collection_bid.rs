@@ -0,0 +1,50 @@
+use anchor_spl::token::Token;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelCollectionBidCtx<'info> {
+    #[account(mut, close = buyer)]
+    collection_bid: Box<Account<'info, CollectionBid>>,
+    /// CHECK: This is not dangerous because it is a system account PDA that only holds the escrowed funds
+    #[account(mut, seeds = [COLLECTION_BID_ESCROW_SEED.as_bytes(), collection_bid.key().as_ref()], bump = collection_bid.escrow_bump)]
+    collection_bid_escrow: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    collection_bid_payment_token_account: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = buyer.key() == collection_bid.buyer @ ErrorCode::InvalidCollectionBid)]
+    buyer: Signer<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    buyer_payment_token_account: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CancelCollectionBidCtx>) -> Result<()> {
+    let collection_bid_key = ctx.accounts.collection_bid.key();
+    let collection_bid_escrow_seeds = &[COLLECTION_BID_ESCROW_SEED.as_bytes(), collection_bid_key.as_ref(), &[ctx.accounts.collection_bid.escrow_bump]];
+    let collection_bid_escrow_signer = &[&collection_bid_escrow_seeds[..]];
+    let escrow = Escrow {
+        escrow: &ctx.accounts.collection_bid_escrow.to_account_info(),
+        escrow_payment_token_account: &ctx.accounts.collection_bid_payment_token_account.to_account_info(),
+        payment_mint: ctx.accounts.collection_bid.payment_mint,
+        signer: collection_bid_escrow_signer,
+        invalid_token_account_error: ErrorCode::InvalidCollectionBidTokenAccount,
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    };
+
+    // escrowed funds go back to the buyer
+    refund_escrow(
+        &escrow,
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.buyer_payment_token_account.to_account_info(),
+        ErrorCode::InvalidBuyerPaymentTokenAccount,
+    )
+}
@@ -0,0 +1,7 @@
+pub mod accept_collection_bid;
+pub mod cancel_collection_bid;
+pub mod make_collection_bid;
+
+pub use accept_collection_bid::*;
+pub use cancel_collection_bid::*;
+pub use make_collection_bid::*;
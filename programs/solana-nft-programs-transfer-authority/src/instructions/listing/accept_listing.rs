@@ -34,7 +34,7 @@ pub struct AcceptListingCtx<'info> {
     #[account(mut)]
     lister_payment_token_account: UncheckedAccount<'info>,
     #[account(mut, constraint =
-        lister_mint_token_account.amount == 1 &&
+        lister_mint_token_account.amount >= token_manager.amount &&
         lister_mint_token_account.mint == token_manager.mint &&
         lister_mint_token_account.owner == lister.key() @ ErrorCode::InvalidListerMintTokenAccount)]
     lister_mint_token_account: Box<Account<'info, TokenAccount>>,
@@ -46,7 +46,7 @@ pub struct AcceptListingCtx<'info> {
         buyer_mint_token_account.mint == token_manager.mint &&
         buyer_mint_token_account.owner == buyer.key() @ ErrorCode::InvalidBuyerMintTokenAccount)]
     buyer_mint_token_account: Box<Account<'info, TokenAccount>>,
-    #[account(mut)]
+    #[account(mut, constraint = listing.allowed_buyer.is_none() || listing.allowed_buyer == Some(buyer.key()) @ ErrorCode::BuyerNotAllowed)]
     buyer: Signer<'info>,
 
     #[account(mut)]
@@ -95,14 +95,19 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         return Err(error!(ErrorCode::ListingChanged));
     }
 
-    if remaining_accs.is_empty() {
+    let now = Clock::get().unwrap().unix_timestamp;
+    if !ctx.accounts.listing.is_started(now) {
+        return Err(error!(ErrorCode::ListingNotStarted));
+    }
+    if ctx.accounts.listing.is_expired(now) {
+        return Err(error!(ErrorCode::ListingExpired));
+    }
+
+    let transfer_accounts_length = transfer_remaining_accounts_length(ctx.accounts.token_manager.kind);
+    if remaining_accs.len() < transfer_accounts_length {
         return Err(error!(ErrorCode::InvalidRemainingAccountsSize));
     }
-    let payment_remaining_accounts = if ctx.accounts.token_manager.kind == TokenManagerKind::Permissioned as u8 {
-        &remaining_accs[..remaining_accs.len() - 1]
-    } else {
-        &remaining_accs[..remaining_accs.len() - 2]
-    };
+    let payment_remaining_accounts = &remaining_accs[..remaining_accs.len() - transfer_accounts_length];
 
     // native SOL
     if ctx.accounts.payment_mint.key() == Pubkey::default() {
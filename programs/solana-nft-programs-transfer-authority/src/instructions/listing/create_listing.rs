@@ -14,6 +14,9 @@ use solana_program::sysvar::{self};
 pub struct CreateListingIx {
     pub payment_amount: u64,
     pub payment_mint: Pubkey,
+    pub start_at: Option<i64>,
+    pub expires_at: Option<i64>,
+    pub allowed_buyer: Option<Pubkey>,
 }
 
 #[derive(Accounts)]
@@ -53,6 +56,9 @@ pub struct CreateListingCtx<'info> {
     /// CHECK: This is not dangerous because the ID is checked with instructions sysvar
     #[account(address = sysvar::instructions::id())]
     instructions: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is only checked to be empty
+    #[account(seeds = [ENGLISH_AUCTION_SEED.as_bytes(), token_manager.key().as_ref()], bump, constraint = english_auction.data_is_empty() @ ErrorCode::TokenInEnglishAuction)]
+    english_auction: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<CreateListingCtx>, ix: CreateListingIx) -> Result<()> {
@@ -64,6 +70,11 @@ pub fn handler(ctx: Context<CreateListingCtx>, ix: CreateListingIx) -> Result<()
     // payment
     listing.payment_amount = ix.payment_amount;
     listing.payment_mint = ix.payment_mint;
+    // schedule
+    assert_listing_schedule(ix.start_at, ix.expires_at, Clock::get().unwrap().unix_timestamp)?;
+    listing.start_at = ix.start_at;
+    listing.expires_at = ix.expires_at;
+    listing.allowed_buyer = ix.allowed_buyer;
 
     if ctx.accounts.marketplace.payment_mints.is_some() && !ctx.accounts.marketplace.payment_mints.as_ref().unwrap().contains(&ix.payment_mint) {
         return Err(error!(ErrorCode::InvalidPaymentMint));
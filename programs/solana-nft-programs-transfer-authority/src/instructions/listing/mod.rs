@@ -1,9 +1,11 @@
 pub mod accept_listing;
 pub mod create_listing;
+pub mod remove_expired_listing;
 pub mod remove_listing;
 pub mod update_listing;
 
 pub use accept_listing::*;
 pub use create_listing::*;
+pub use remove_expired_listing::*;
 pub use remove_listing::*;
 pub use update_listing::*;
@@ -0,0 +1,86 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerKind;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+pub struct RemoveExpiredListingCtx<'info> {
+    #[account(mut, close = lister)]
+    listing: Box<Account<'info, Listing>>,
+    /// CHECK: This is not dangerous because of the listing.lister check
+    #[account(mut, constraint = lister.key() == listing.lister @ ErrorCode::InvalidLister)]
+    lister: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because it is checked in the handler
+    transfer_authority: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked in the handler and may already be closed
+    #[account(mut, constraint = listing.token_manager == token_manager.key() @ ErrorCode::InvalidTokenManager)]
+    token_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked in the token manager revoke delegate instruction
+    #[account(mut)]
+    token_manager_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked in the handler
+    #[account(mut)]
+    lister_mint_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is only checked to be empty
+    #[account(seeds = [ENGLISH_AUCTION_SEED.as_bytes(), token_manager.key().as_ref()], bump)]
+    english_auction: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because this account is not read in this instruction
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because this account is not read in this instruction
+    #[account(mut)]
+    mint_manager: UncheckedAccount<'info>,
+
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<RemoveExpiredListingCtx>) -> Result<()> {
+    if !ctx.accounts.listing.is_expired(Clock::get().unwrap().unix_timestamp) {
+        return Err(error!(ErrorCode::ListingNotExpired));
+    }
+
+    // the token manager may have been invalidated since the listing was created, and a running english auction still needs the delegate
+    if ctx.accounts.token_manager.data_is_empty() || !ctx.accounts.english_auction.data_is_empty() {
+        return Ok(());
+    }
+    let token_manager = Account::<TokenManager>::try_from(&ctx.accounts.token_manager)?;
+    if token_manager.kind != TokenManagerKind::Permissioned as u8 || token_manager.state != TokenManagerState::Claimed as u8 {
+        return Ok(());
+    }
+    let transfer_authority = Account::<TransferAuthority>::try_from(&ctx.accounts.transfer_authority)?;
+    if token_manager.transfer_authority != Some(transfer_authority.key()) {
+        return Err(error!(ErrorCode::InvalidTransferAuthority));
+    }
+    if ctx.accounts.lister_mint_token_account.key() != token_manager.recipient_token_account {
+        return Err(error!(ErrorCode::InvalidListerMintTokenAccount));
+    }
+    let lister_mint_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.lister_mint_token_account)?;
+    // once the token has left the lister there is no delegate of theirs left to revoke
+    if lister_mint_token_account.owner != ctx.accounts.lister.key()
+        || lister_mint_token_account.delegate.is_none()
+        || lister_mint_token_account.delegate.expect("Invalid delegate") != token_manager.key()
+    {
+        return Ok(());
+    }
+
+    // the lister does not sign this crank, so the transfer authority revokes the delegate it was given when listing
+    let transfer_authority_seeds = &[TRANSFER_AUTHORITY_SEED.as_bytes(), transfer_authority.name.as_bytes(), &[transfer_authority.bump]];
+    let transfer_authority_signer = &[&transfer_authority_seeds[..]];
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::RevokeDelegateCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        token_manager_token_account: ctx.accounts.token_manager_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        mint_manager: ctx.accounts.mint_manager.to_account_info(),
+        recipient_token_account: ctx.accounts.lister_mint_token_account.to_account_info(),
+        transfer_authority: ctx.accounts.transfer_authority.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts).with_signer(transfer_authority_signer);
+    solana_nft_programs_token_manager::cpi::revoke_delegate(cpi_ctx)
+}
@@ -15,7 +15,7 @@ pub struct RemoveListingCtx<'info> {
     #[account(mut, close = lister)]
     listing: Box<Account<'info, Listing>>,
     #[account(mut, constraint =
-        lister_mint_token_account.amount == 1 &&
+        lister_mint_token_account.amount >= token_manager.amount &&
         lister_mint_token_account.mint == token_manager.mint &&
         lister_mint_token_account.owner == lister.key() @ ErrorCode::InvalidListerMintTokenAccount)]
     lister_mint_token_account: Box<Account<'info, TokenAccount>>,
@@ -10,6 +10,9 @@ pub struct UpdateListingIx {
     pub marketplace: Pubkey,
     pub payment_amount: u64,
     pub payment_mint: Pubkey,
+    pub start_at: Option<i64>,
+    pub expires_at: Option<i64>,
+    pub allowed_buyer: Option<Pubkey>,
 }
 
 #[derive(Accounts)]
@@ -21,12 +24,15 @@ pub struct UpdateListingCtx<'info> {
     #[account(mut)]
     listing: Box<Account<'info, Listing>>,
     #[account(mut, constraint =
-        lister_mint_token_account.amount == 1 &&
+        lister_mint_token_account.amount >= token_manager.amount &&
         lister_mint_token_account.mint == token_manager.mint &&
         lister_mint_token_account.owner == lister.key() @ ErrorCode::InvalidListerMintTokenAccount)]
     lister_mint_token_account: Box<Account<'info, TokenAccount>>,
     #[account(mut)]
     lister: Signer<'info>,
+    /// CHECK: This is not dangerous because it is only checked to be empty
+    #[account(seeds = [ENGLISH_AUCTION_SEED.as_bytes(), token_manager.key().as_ref()], bump, constraint = english_auction.data_is_empty() @ ErrorCode::TokenInEnglishAuction)]
+    english_auction: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<UpdateListingCtx>, ix: UpdateListingIx) -> Result<()> {
@@ -35,6 +41,10 @@ pub fn handler(ctx: Context<UpdateListingCtx>, ix: UpdateListingIx) -> Result<()
     listing.marketplace = ix.marketplace.key();
     listing.payment_amount = ix.payment_amount;
     listing.payment_mint = ix.payment_mint;
+    assert_listing_schedule(ix.start_at, ix.expires_at, Clock::get().unwrap().unix_timestamp)?;
+    listing.start_at = ix.start_at;
+    listing.expires_at = ix.expires_at;
+    listing.allowed_buyer = ix.allowed_buyer;
 
     msg!("Mint: {}", ctx.accounts.token_manager.mint.key());
 
@@ -1,6 +1,7 @@
 pub mod listing;
 pub use listing::accept_listing::*;
 pub use listing::create_listing::*;
+pub use listing::remove_expired_listing::*;
 pub use listing::remove_listing::*;
 pub use listing::update_listing::*;
 
@@ -18,3 +19,22 @@ pub mod transfer;
 pub use transfer::accept_transfer::*;
 pub use transfer::cancel_transfer::*;
 pub use transfer::init_transfer::*;
+
+pub mod offer;
+pub use offer::accept_offer::*;
+pub use offer::cancel_offer::*;
+pub use offer::make_offer::*;
+
+pub mod collection_bid;
+pub use collection_bid::accept_collection_bid::*;
+pub use collection_bid::cancel_collection_bid::*;
+pub use collection_bid::make_collection_bid::*;
+
+pub mod auction;
+pub use auction::accept_dutch_auction::*;
+pub use auction::cancel_english_auction::*;
+pub use auction::create_dutch_auction::*;
+pub use auction::create_english_auction::*;
+pub use auction::place_bid::*;
+pub use auction::remove_dutch_auction::*;
+pub use auction::settle_english_auction::*;
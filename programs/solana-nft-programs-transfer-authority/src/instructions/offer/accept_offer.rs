@@ -0,0 +1,160 @@
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_payment_manager::program::SolanaNftProgramsPaymentManager;
+
+use solana_program::sysvar::{self};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AcceptOfferIx {
+    pub payment_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct AcceptOfferCtx<'info> {
+    #[account(mut, constraint = transfer_authority.key() == token_manager.transfer_authority.expect("No transfer authority for token manager") @ ErrorCode::InvalidTransferAuthority)]
+    transfer_authority: Box<Account<'info, TransferAuthority>>,
+    /// CHECK: This is not dangerous because this is the receipt getting initialized
+    #[account(mut)]
+    transfer_receipt: UncheckedAccount<'info>,
+
+    #[account(mut, close = buyer)]
+    offer: Box<Account<'info, Offer>>,
+    /// CHECK: This is not dangerous because it is a system account PDA that only holds the escrowed funds
+    #[account(mut, seeds = [OFFER_ESCROW_SEED.as_bytes(), offer.key().as_ref()], bump = offer.escrow_bump)]
+    offer_escrow: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    offer_payment_token_account: UncheckedAccount<'info>,
+
+    #[account(mut, constraint =
+        holder_mint_token_account.amount >= token_manager.amount &&
+        holder_mint_token_account.key() == token_manager.recipient_token_account &&
+        holder_mint_token_account.owner == holder.key() @ ErrorCode::InvalidHolderMintTokenAccount)]
+    holder_mint_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    holder_payment_token_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    holder: Signer<'info>,
+
+    #[account(mut, constraint = buyer.key() == offer.buyer @ ErrorCode::InvalidOffer)]
+    buyer: Signer<'info>,
+    #[account(mut, constraint =
+        buyer_mint_token_account.mint == token_manager.mint &&
+        buyer_mint_token_account.owner == buyer.key() @ ErrorCode::InvalidBuyerMintTokenAccount)]
+    buyer_mint_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    buyer_payment_token_account: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = marketplace.key() == offer.marketplace @ ErrorCode::InvalidMarketplace)]
+    marketplace: Box<Account<'info, Marketplace>>,
+    #[account(mut, constraint = token_manager.key() == offer.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+    /// CHECK: This is not dangerous because of the token_manager.mint check
+    #[account(constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is check in the handler
+    mint_metadata_info: UncheckedAccount<'info>,
+
+    // payment accounts
+    /// CHECK: This is not dangerous because of the marketplace.payment_manager check
+    #[account(mut, constraint = payment_manager.key() == marketplace.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because of the offer.payment_mint check
+    #[account(constraint = payment_mint.key() == offer.payment_mint @ ErrorCode::InvalidPaymentMint)]
+    payment_mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked in the payment manager handle payment with royalties instruction
+    #[account(mut)]
+    fee_collector_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked in the payment manager handle payment with royalties instruction
+    #[account(mut)]
+    fee_collector: UncheckedAccount<'info>,
+
+    solana_nft_programs_payment_manager: Program<'info, SolanaNftProgramsPaymentManager>,
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    /// CHECK: This is not dangerous because the ID is checked with instructions sysvar
+    #[account(address = sysvar::instructions::id())]
+    instructions: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is only checked to be empty
+    #[account(seeds = [ENGLISH_AUCTION_SEED.as_bytes(), token_manager.key().as_ref()], bump, constraint = english_auction.data_is_empty() @ ErrorCode::TokenInEnglishAuction)]
+    english_auction: UncheckedAccount<'info>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, AcceptOfferCtx<'info>>, ix: AcceptOfferIx) -> Result<()> {
+    if ix.payment_amount != ctx.accounts.offer.payment_amount {
+        return Err(error!(ErrorCode::OfferChanged));
+    }
+    let (payment_remaining_accounts, transfer_accounts) = split_transfer_accounts(ctx.accounts.token_manager.kind, ctx.remaining_accounts)?;
+
+    let offer_key = ctx.accounts.offer.key();
+    let offer_escrow_seeds = &[OFFER_ESCROW_SEED.as_bytes(), offer_key.as_ref(), &[ctx.accounts.offer.escrow_bump]];
+    let offer_escrow_signer = &[&offer_escrow_seeds[..]];
+    let escrow = Escrow {
+        escrow: &ctx.accounts.offer_escrow.to_account_info(),
+        escrow_payment_token_account: &ctx.accounts.offer_payment_token_account.to_account_info(),
+        payment_mint: ctx.accounts.offer.payment_mint,
+        signer: offer_escrow_signer,
+        invalid_token_account_error: ErrorCode::InvalidOfferTokenAccount,
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    };
+
+    if ctx.accounts.payment_mint.key() != Pubkey::default() {
+        // check on holder token account
+        let holder_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.holder_payment_token_account)?;
+        if holder_payment_token_account.mint != ctx.accounts.offer.payment_mint || holder_payment_token_account.owner != ctx.accounts.holder.key() {
+            return Err(error!(ErrorCode::InvalidHolderPaymentTokenAccount));
+        }
+    }
+    let payment = EscrowPayment {
+        payment_manager: &ctx.accounts.payment_manager.to_account_info(),
+        payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+        payment_target: &ctx.accounts.holder.to_account_info(),
+        payment_target_token_account: &ctx.accounts.holder_payment_token_account.to_account_info(),
+        fee_collector: &ctx.accounts.fee_collector.to_account_info(),
+        fee_collector_token_account: &ctx.accounts.fee_collector_token_account.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        mint_metadata: &ctx.accounts.mint_metadata_info.to_account_info(),
+        solana_nft_programs_payment_manager: &ctx.accounts.solana_nft_programs_payment_manager.to_account_info(),
+    };
+    pay_from_escrow(&escrow, &payment, ctx.accounts.offer.payment_amount, payment_remaining_accounts)?;
+
+    let transfer = ReceiptTransfer {
+        transfer_authority: &ctx.accounts.transfer_authority,
+        transfer_receipt: &ctx.accounts.transfer_receipt.to_account_info(),
+        token_manager: &ctx.accounts.token_manager,
+        mint: &ctx.accounts.mint.to_account_info(),
+        transfer_accounts,
+        solana_nft_programs_token_manager: &ctx.accounts.solana_nft_programs_token_manager.to_account_info(),
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    };
+    // the buyer co-signs so the token moves in the same instruction the holder is paid in
+    delegate_permissioned(&transfer, &ctx.accounts.holder.to_account_info(), &ctx.accounts.holder_mint_token_account, &[])?;
+    transfer_with_receipt(
+        &transfer,
+        &ctx.accounts.holder_mint_token_account.to_account_info(),
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.buyer_mint_token_account.to_account_info(),
+        &ctx.accounts.buyer.to_account_info(),
+        &[],
+    )?;
+
+    // anything left in the escrow goes back to the buyer
+    refund_escrow(
+        &escrow,
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.buyer_payment_token_account.to_account_info(),
+        ErrorCode::InvalidBuyerPaymentTokenAccount,
+    )
+}
@@ -0,0 +1,48 @@
+use anchor_spl::token::Token;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelOfferCtx<'info> {
+    #[account(mut, close = buyer)]
+    offer: Box<Account<'info, Offer>>,
+    /// CHECK: This is not dangerous because it is a system account PDA that only holds the escrowed funds
+    #[account(mut, seeds = [OFFER_ESCROW_SEED.as_bytes(), offer.key().as_ref()], bump = offer.escrow_bump)]
+    offer_escrow: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    offer_payment_token_account: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = buyer.key() == offer.buyer @ ErrorCode::InvalidOffer)]
+    buyer: Signer<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    buyer_payment_token_account: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CancelOfferCtx>) -> Result<()> {
+    let offer_key = ctx.accounts.offer.key();
+    let offer_escrow_seeds = &[OFFER_ESCROW_SEED.as_bytes(), offer_key.as_ref(), &[ctx.accounts.offer.escrow_bump]];
+    let offer_escrow_signer = &[&offer_escrow_seeds[..]];
+    let escrow = Escrow {
+        escrow: &ctx.accounts.offer_escrow.to_account_info(),
+        escrow_payment_token_account: &ctx.accounts.offer_payment_token_account.to_account_info(),
+        payment_mint: ctx.accounts.offer.payment_mint,
+        signer: offer_escrow_signer,
+        invalid_token_account_error: ErrorCode::InvalidOfferTokenAccount,
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    };
+    refund_escrow(
+        &escrow,
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.buyer_payment_token_account.to_account_info(),
+        ErrorCode::InvalidBuyerPaymentTokenAccount,
+    )
+}
@@ -0,0 +1,123 @@
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_payment_manager::state::PaymentManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+use solana_program::program::invoke;
+use solana_program::system_instruction;
+use spl_associated_token_account::get_associated_token_address;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MakeOfferIx {
+    pub payment_amount: u64,
+    pub payment_mint: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: MakeOfferIx)]
+pub struct MakeOfferCtx<'info> {
+    #[account(
+        init,
+        payer = buyer,
+        space = OFFER_SIZE,
+        seeds = [OFFER_SEED.as_bytes(), token_manager.key().as_ref(), buyer.key().as_ref()], bump,
+    )]
+    offer: Box<Account<'info, Offer>>,
+    /// CHECK: This is not dangerous because it is a system account PDA that only holds the escrowed funds
+    #[account(mut, seeds = [OFFER_ESCROW_SEED.as_bytes(), offer.key().as_ref()], bump)]
+    offer_escrow: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    offer_payment_token_account: UncheckedAccount<'info>,
+
+    #[account(constraint = transfer_authority.key() == token_manager.transfer_authority.expect("No transfer authority for token manager") @ ErrorCode::InvalidTransferAuthority)]
+    transfer_authority: Box<Account<'info, TransferAuthority>>,
+    marketplace: Box<Account<'info, Marketplace>>,
+    /// CHECK: This is not dangerous because of the marketplace.payment_manager check
+    #[account(constraint = payment_manager.key() == marketplace.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: UncheckedAccount<'info>,
+
+    #[account(constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut)]
+    buyer: Signer<'info>,
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    buyer_payment_token_account: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MakeOfferCtx>, ix: MakeOfferIx) -> Result<()> {
+    let offer = &mut ctx.accounts.offer;
+    offer.bump = *ctx.bumps.get("offer").unwrap();
+    offer.escrow_bump = *ctx.bumps.get("offer_escrow").unwrap();
+    offer.buyer = ctx.accounts.buyer.key();
+    offer.token_manager = ctx.accounts.token_manager.key();
+    offer.marketplace = ctx.accounts.marketplace.key();
+    // payment
+    offer.payment_amount = ix.payment_amount;
+    offer.payment_mint = ix.payment_mint;
+
+    if ctx.accounts.marketplace.payment_mints.is_some() && !ctx.accounts.marketplace.payment_mints.as_ref().unwrap().contains(&ix.payment_mint) {
+        return Err(error!(ErrorCode::InvalidPaymentMint));
+    }
+
+    if ctx.accounts.transfer_authority.allowed_marketplaces.is_some() && !ctx.accounts.transfer_authority.allowed_marketplaces.as_ref().unwrap().contains(&ctx.accounts.marketplace.key()) {
+        return Err(error!(ErrorCode::MarketplaceNotAllowed));
+    }
+
+    // the escrow pays the taker fee on top of the offer when it is accepted
+    let mut escrow_amount = ix.payment_amount;
+    if ctx.accounts.payment_manager.owner.key() == solana_nft_programs_payment_manager::id() {
+        let payment_manager = Account::<PaymentManager>::try_from(&ctx.accounts.payment_manager)?;
+        let taker_fee = ix
+            .payment_amount
+            .checked_mul(payment_manager.taker_fee_basis_points.into())
+            .expect("Multiplication error")
+            .checked_div(BASIS_POINTS_DIVISOR)
+            .expect("Division error");
+        escrow_amount = escrow_amount.checked_add(taker_fee).expect("Add error");
+    }
+
+    // native SOL
+    if ix.payment_mint == Pubkey::default() {
+        invoke(
+            &system_instruction::transfer(&ctx.accounts.buyer.key(), &ctx.accounts.offer_escrow.key(), escrow_amount),
+            &[
+                ctx.accounts.buyer.to_account_info(),
+                ctx.accounts.offer_escrow.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    } else {
+        // any SPL token
+        let buyer_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.buyer_payment_token_account)?;
+        if buyer_payment_token_account.mint != ix.payment_mint || buyer_payment_token_account.owner != ctx.accounts.buyer.key() {
+            return Err(error!(ErrorCode::InvalidBuyerPaymentTokenAccount));
+        }
+        if ctx.accounts.offer_payment_token_account.key() != get_associated_token_address(&ctx.accounts.offer_escrow.key(), &ix.payment_mint) {
+            return Err(error!(ErrorCode::InvalidOfferTokenAccount));
+        }
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.buyer_payment_token_account.to_account_info(),
+            to: ctx.accounts.offer_payment_token_account.to_account_info(),
+            authority: ctx.accounts.buyer.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_context, escrow_amount)?;
+    }
+
+    Ok(())
+}
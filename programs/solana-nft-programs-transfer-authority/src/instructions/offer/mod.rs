@@ -0,0 +1,7 @@
+pub mod accept_offer;
+pub mod cancel_offer;
+pub mod make_offer;
+
+pub use accept_offer::*;
+pub use cancel_offer::*;
+pub use make_offer::*;
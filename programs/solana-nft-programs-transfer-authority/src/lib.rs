@@ -1,6 +1,7 @@
 pub mod errors;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
 use instructions::*;
@@ -46,6 +47,73 @@ pub mod solana_nft_programs_transfer_authority {
         listing::remove_listing::handler(ctx)
     }
 
+    pub fn remove_expired_listing(ctx: Context<RemoveExpiredListingCtx>) -> Result<()> {
+        listing::remove_expired_listing::handler(ctx)
+    }
+
+    // offer
+    pub fn make_offer(ctx: Context<MakeOfferCtx>, ix: MakeOfferIx) -> Result<()> {
+        offer::make_offer::handler(ctx, ix)
+    }
+
+    pub fn cancel_offer(ctx: Context<CancelOfferCtx>) -> Result<()> {
+        offer::cancel_offer::handler(ctx)
+    }
+
+    pub fn accept_offer<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, AcceptOfferCtx<'info>>, ix: AcceptOfferIx) -> Result<()> {
+        offer::accept_offer::handler(ctx, ix)
+    }
+
+    // collection bid
+    pub fn make_collection_bid(ctx: Context<MakeCollectionBidCtx>, ix: MakeCollectionBidIx) -> Result<()> {
+        collection_bid::make_collection_bid::handler(ctx, ix)
+    }
+
+    pub fn cancel_collection_bid(ctx: Context<CancelCollectionBidCtx>) -> Result<()> {
+        collection_bid::cancel_collection_bid::handler(ctx)
+    }
+
+    pub fn accept_collection_bid<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, AcceptCollectionBidCtx<'info>>, ix: AcceptCollectionBidIx) -> Result<()> {
+        collection_bid::accept_collection_bid::handler(ctx, ix)
+    }
+
+    // auction
+    pub fn create_dutch_auction(ctx: Context<CreateDutchAuctionCtx>, ix: CreateDutchAuctionIx) -> Result<()> {
+        auction::create_dutch_auction::handler(ctx, ix)
+    }
+
+    pub fn accept_dutch_auction<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, AcceptDutchAuctionCtx<'info>>, ix: AcceptDutchAuctionIx) -> Result<()> {
+        auction::accept_dutch_auction::handler(ctx, ix)
+    }
+
+    pub fn remove_dutch_auction(ctx: Context<RemoveDutchAuctionCtx>) -> Result<()> {
+        auction::remove_dutch_auction::handler(ctx)
+    }
+
+    pub fn create_english_auction(ctx: Context<CreateEnglishAuctionCtx>, ix: CreateEnglishAuctionIx) -> Result<()> {
+        auction::create_english_auction::handler(ctx, ix)
+    }
+
+    pub fn place_bid(ctx: Context<PlaceBidCtx>, ix: PlaceBidIx) -> Result<()> {
+        auction::place_bid::handler(ctx, ix)
+    }
+
+    pub fn settle_english_auction<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, SettleEnglishAuctionCtx<'info>>) -> Result<()> {
+        auction::settle_english_auction::handler(ctx)
+    }
+
+    pub fn cancel_english_auction(ctx: Context<CancelEnglishAuctionCtx>) -> Result<()> {
+        auction::cancel_english_auction::handler(ctx)
+    }
+
+    pub fn claim_english_auction<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ClaimEnglishAuctionCtx<'info>>) -> Result<()> {
+        auction::claim_english_auction::handler(ctx)
+    }
+
+    pub fn close_english_auction(ctx: Context<CloseEnglishAuctionCtx>) -> Result<()> {
+        auction::close_english_auction::handler(ctx)
+    }
+
     // marketplace
     pub fn init_marketplace(ctx: Context<InitMarketplaceCtx>, ix: InitMarketplaceIx) -> Result<()> {
         marketplace::init_marketplace::handler(ctx, ix)
@@ -1,5 +1,8 @@
 use crate::errors::ErrorCode;
 use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManagerKind;
+
+pub const BASIS_POINTS_DIVISOR: u64 = 10_000;
 
 pub const TRANSFER_AUTHORITY_SEED: &str = "transfer-authority";
 pub const TRANSFER_AUTHORITY_SIZE: usize = 8 + 1 + 24 + 32 + 32 * 5 + 64;
@@ -33,6 +36,30 @@ pub struct Listing {
     // payment
     pub payment_amount: u64,
     pub payment_mint: Pubkey,
+    // schedule
+    pub start_at: Option<i64>,
+    pub expires_at: Option<i64>,
+    // only this buyer can accept the listing when set
+    pub allowed_buyer: Option<Pubkey>,
+}
+
+impl Listing {
+    pub fn is_started(&self, now: i64) -> bool {
+        self.start_at.is_none() || now >= self.start_at.unwrap()
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at.is_some() && now >= self.expires_at.unwrap()
+    }
+}
+
+pub fn assert_listing_schedule(start_at: Option<i64>, expires_at: Option<i64>, now: i64) -> Result<()> {
+    if let Some(expires_at) = expires_at {
+        if expires_at <= now || (start_at.is_some() && expires_at <= start_at.unwrap()) {
+            return Err(error!(ErrorCode::InvalidListingSchedule));
+        }
+    }
+    Ok(())
 }
 
 pub const TRANSFER_SEED: &str = "transfer";
@@ -45,6 +72,116 @@ pub struct Transfer {
     pub to: Pubkey,
 }
 
+pub const OFFER_SEED: &str = "offer";
+pub const OFFER_ESCROW_SEED: &str = "offer-escrow";
+pub const OFFER_SIZE: usize = 8 + std::mem::size_of::<Offer>() + 64;
+#[account]
+pub struct Offer {
+    pub bump: u8,
+    pub escrow_bump: u8,
+    pub buyer: Pubkey,
+    pub token_manager: Pubkey,
+    pub marketplace: Pubkey,
+    // payment
+    pub payment_amount: u64,
+    pub payment_mint: Pubkey,
+}
+
+pub const COLLECTION_BID_SEED: &str = "collection-bid";
+pub const COLLECTION_BID_ESCROW_SEED: &str = "collection-bid-escrow";
+pub const COLLECTION_BID_SIZE: usize = 8 + std::mem::size_of::<CollectionBid>() + 64;
+#[account]
+pub struct CollectionBid {
+    pub bump: u8,
+    pub escrow_bump: u8,
+    pub buyer: Pubkey,
+    pub collection: Pubkey,
+    pub transfer_authority: Pubkey,
+    pub marketplace: Pubkey,
+    // payment
+    pub payment_amount: u64,
+    pub payment_mint: Pubkey,
+}
+
+pub const DUTCH_AUCTION_SEED: &str = "dutch-auction";
+pub const DUTCH_AUCTION_SIZE: usize = 8 + std::mem::size_of::<DutchAuction>() + 64;
+#[account]
+pub struct DutchAuction {
+    pub bump: u8,
+    pub lister: Pubkey,
+    pub token_manager: Pubkey,
+    pub marketplace: Pubkey,
+    // payment
+    pub payment_mint: Pubkey,
+    pub start_amount: u64,
+    pub floor_amount: u64,
+    // price decays linearly from start_amount to floor_amount between start_at and end_at
+    pub start_at: i64,
+    pub end_at: i64,
+}
+
+impl DutchAuction {
+    pub fn current_price(&self, now: i64) -> u64 {
+        if now <= self.start_at {
+            return self.start_amount;
+        }
+        if now >= self.end_at {
+            return self.floor_amount;
+        }
+        let elapsed = now.checked_sub(self.start_at).expect("Sub error") as u128;
+        let duration = self.end_at.checked_sub(self.start_at).expect("Sub error") as u128;
+        let decay = (self.start_amount.checked_sub(self.floor_amount).expect("Sub error") as u128)
+            .checked_mul(elapsed)
+            .expect("Multiplication error")
+            .checked_div(duration)
+            .expect("Division error");
+        self.start_amount.checked_sub(decay as u64).expect("Sub error")
+    }
+}
+
+pub const ENGLISH_AUCTION_SEED: &str = "english-auction";
+pub const ENGLISH_AUCTION_ESCROW_SEED: &str = "english-auction-escrow";
+pub const ENGLISH_AUCTION_SIZE: usize = 8 + std::mem::size_of::<EnglishAuction>() + 64;
+#[account]
+pub struct EnglishAuction {
+    pub bump: u8,
+    pub escrow_bump: u8,
+    pub lister: Pubkey,
+    pub token_manager: Pubkey,
+    pub marketplace: Pubkey,
+    // payment
+    pub payment_mint: Pubkey,
+    pub reserve_amount: u64,
+    pub min_increment: u64,
+    // bids in the last anti_snipe_seconds push end_at back
+    pub end_at: i64,
+    pub anti_snipe_seconds: i64,
+    // highest bid is held by the auction escrow together with its taker fee
+    pub highest_bidder: Option<Pubkey>,
+    pub highest_bid: u64,
+    pub highest_bid_escrow_amount: u64,
+    // settled by the lister, the auction escrow holds the token and the bid until the highest bidder claims them
+    pub settled: bool,
+}
+
+impl EnglishAuction {
+    pub fn min_bid(&self) -> u64 {
+        if self.highest_bidder.is_none() {
+            return self.reserve_amount;
+        }
+        self.highest_bid.checked_add(self.min_increment).expect("Add error")
+    }
+}
+
+// permissioned tokens end the remaining accounts with the mint manager, other kinds with the two accounts the token manager transfer needs
+pub fn transfer_remaining_accounts_length(kind: u8) -> usize {
+    if kind == TokenManagerKind::Permissioned as u8 {
+        1
+    } else {
+        2
+    }
+}
+
 pub fn assert_derivation(program_id: &Pubkey, account: &AccountInfo, path: &[&[u8]]) -> Result<u8> {
     let (key, bump) = Pubkey::find_program_address(path, program_id);
     if key != *account.key {
@@ -0,0 +1,246 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{self};
+use anchor_spl::token::CloseAccount;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerKind;
+use solana_program::program::invoke_signed;
+use solana_program::system_instruction;
+use spl_associated_token_account::get_associated_token_address;
+
+// system account PDA holding a buyer's escrowed payment and, between an english auction settlement and its claim, the bought token
+pub struct Escrow<'a, 'info> {
+    pub escrow: &'a AccountInfo<'info>,
+    pub escrow_payment_token_account: &'a AccountInfo<'info>,
+    pub payment_mint: Pubkey,
+    pub signer: &'a [&'a [&'a [u8]]],
+    pub invalid_token_account_error: ErrorCode,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+pub struct EscrowPayment<'a, 'info> {
+    pub payment_manager: &'a AccountInfo<'info>,
+    pub payment_mint: &'a AccountInfo<'info>,
+    pub payment_target: &'a AccountInfo<'info>,
+    pub payment_target_token_account: &'a AccountInfo<'info>,
+    pub fee_collector: &'a AccountInfo<'info>,
+    pub fee_collector_token_account: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub mint_metadata: &'a AccountInfo<'info>,
+    pub solana_nft_programs_payment_manager: &'a AccountInfo<'info>,
+}
+
+pub struct ReceiptTransfer<'a, 'info> {
+    pub transfer_authority: &'a Account<'info, TransferAuthority>,
+    pub transfer_receipt: &'a AccountInfo<'info>,
+    pub token_manager: &'a Account<'info, TokenManager>,
+    pub mint: &'a AccountInfo<'info>,
+    // remaining accounts for the token manager kind, the receipt is appended here
+    pub transfer_accounts: &'a [AccountInfo<'info>],
+    pub solana_nft_programs_token_manager: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+// pays the escrowed amount to the payment target with fees and royalties through the payment manager
+pub fn pay_from_escrow<'info>(escrow: &Escrow<'_, 'info>, payment: &EscrowPayment<'_, 'info>, amount: u64, payment_remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+    // native SOL
+    if escrow.payment_mint == Pubkey::default() {
+        let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandleNativePaymentWithRoyaltiesCtx {
+            payment_manager: payment.payment_manager.to_account_info(),
+            fee_collector: payment.fee_collector.to_account_info(),
+            payment_target: payment.payment_target.to_account_info(),
+            payer: escrow.escrow.to_account_info(),
+            mint: payment.mint.to_account_info(),
+            mint_metadata: payment.mint_metadata.to_account_info(),
+            system_program: escrow.system_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(payment.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts)
+            .with_remaining_accounts(payment_remaining_accounts.to_vec())
+            .with_signer(escrow.signer);
+        solana_nft_programs_payment_manager::cpi::handle_native_payment_with_royalties(cpi_ctx, amount)?;
+    } else {
+        // any SPL token
+        if escrow.escrow_payment_token_account.key() != get_associated_token_address(&escrow.escrow.key(), &escrow.payment_mint) {
+            return Err(error!(escrow.invalid_token_account_error));
+        }
+
+        let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandlePaymentWithRoyaltiesCtx {
+            payment_manager: payment.payment_manager.to_account_info(),
+            payer_token_account: escrow.escrow_payment_token_account.to_account_info(),
+            fee_collector_token_account: payment.fee_collector_token_account.to_account_info(),
+            payment_token_account: payment.payment_target_token_account.to_account_info(),
+            payment_mint: payment.payment_mint.to_account_info(),
+            mint: payment.mint.to_account_info(),
+            mint_metadata: payment.mint_metadata.to_account_info(),
+            payer: escrow.escrow.to_account_info(),
+            token_program: escrow.token_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(payment.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts)
+            .with_remaining_accounts(payment_remaining_accounts.to_vec())
+            .with_signer(escrow.signer);
+        solana_nft_programs_payment_manager::cpi::handle_payment_with_royalties(cpi_ctx, amount)?;
+    }
+    Ok(())
+}
+
+// approves the transfer with a receipt and moves the token, recipient_signer signs for PDA recipients
+pub fn transfer_with_receipt<'info>(
+    transfer: &ReceiptTransfer<'_, 'info>,
+    current_holder_token_account: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    recipient_token_account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    recipient_signer: &[&[&[u8]]],
+) -> Result<()> {
+    let transfer_authority_seeds = &[TRANSFER_AUTHORITY_SEED.as_bytes(), transfer.transfer_authority.name.as_bytes(), &[transfer.transfer_authority.bump]];
+    let transfer_authority_signer = &[&transfer_authority_seeds[..]];
+
+    // approve
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::CreateTransferReceiptCtx {
+        token_manager: transfer.token_manager.to_account_info(),
+        transfer_authority: transfer.transfer_authority.to_account_info(),
+        transfer_receipt: transfer.transfer_receipt.to_account_info(),
+        payer: payer.to_account_info(),
+        system_program: transfer.system_program.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(transfer.solana_nft_programs_token_manager.to_account_info(), cpi_accounts).with_signer(transfer_authority_signer);
+    solana_nft_programs_token_manager::cpi::create_transfer_receipt(cpi_ctx, recipient.key())?;
+
+    let mut transfer_remaining_accounts = transfer.transfer_accounts.to_vec();
+    transfer_remaining_accounts.push(transfer.transfer_receipt.to_account_info());
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::TransferCtx {
+        token_manager: transfer.token_manager.to_account_info(),
+        mint: transfer.mint.to_account_info(),
+        current_holder_token_account: current_holder_token_account.to_account_info(),
+        recipient: recipient.to_account_info(),
+        recipient_token_account: recipient_token_account.to_account_info(),
+        token_program: transfer.token_program.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(transfer.solana_nft_programs_token_manager.to_account_info(), cpi_accounts)
+        .with_remaining_accounts(transfer_remaining_accounts)
+        .with_signer(recipient_signer);
+    solana_nft_programs_token_manager::cpi::transfer(cpi_ctx)
+}
+
+// permissioned tokens are only delegated to the token manager while listed, the token manager transfer needs the delegation
+pub fn delegate_permissioned<'info>(transfer: &ReceiptTransfer<'_, 'info>, owner: &AccountInfo<'info>, owner_token_account: &Account<'info, TokenAccount>, owner_signer: &[&[&[u8]]]) -> Result<()> {
+    if transfer.token_manager.kind != TokenManagerKind::Permissioned as u8 || owner_token_account.delegate.is_some() {
+        return Ok(());
+    }
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::DelegateCtx {
+        token_manager: transfer.token_manager.to_account_info(),
+        mint: transfer.mint.to_account_info(),
+        mint_manager: transfer.transfer_accounts[transfer.transfer_accounts.len() - 1].to_account_info(),
+        recipient: owner.to_account_info(),
+        recipient_token_account: owner_token_account.to_account_info(),
+        token_program: transfer.token_program.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(transfer.solana_nft_programs_token_manager.to_account_info(), cpi_accounts).with_signer(owner_signer);
+    solana_nft_programs_token_manager::cpi::delegate(cpi_ctx)
+}
+
+// the escrow takes custody of the token until the buyer claims it, token manager transfers need the new holder to sign
+pub fn take_custody_in_escrow<'info>(
+    escrow: &Escrow<'_, 'info>,
+    transfer: &ReceiptTransfer<'_, 'info>,
+    holder: &AccountInfo<'info>,
+    holder_mint_token_account: &Account<'info, TokenAccount>,
+    escrow_mint_token_account: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if escrow_mint_token_account.key() != get_associated_token_address(&escrow.escrow.key(), &transfer.mint.key()) {
+        return Err(error!(escrow.invalid_token_account_error));
+    }
+    if escrow_mint_token_account.data_is_empty() {
+        let cpi_accounts = associated_token::Create {
+            payer: holder.to_account_info(),
+            associated_token: escrow_mint_token_account.to_account_info(),
+            authority: escrow.escrow.to_account_info(),
+            mint: transfer.mint.to_account_info(),
+            system_program: escrow.system_program.to_account_info(),
+            token_program: escrow.token_program.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(associated_token_program.to_account_info(), cpi_accounts);
+        associated_token::create(cpi_context)?;
+    }
+
+    delegate_permissioned(transfer, holder, holder_mint_token_account, &[])?;
+    transfer_with_receipt(transfer, &holder_mint_token_account.to_account_info(), escrow.escrow, escrow_mint_token_account, holder, escrow.signer)
+}
+
+// moves the token held in custody to the buyer and closes the emptied escrow token account
+pub fn claim_from_escrow<'info>(
+    escrow: &Escrow<'_, 'info>,
+    transfer: &ReceiptTransfer<'_, 'info>,
+    escrow_mint_token_account: &Account<'info, TokenAccount>,
+    buyer: &AccountInfo<'info>,
+    buyer_mint_token_account: &AccountInfo<'info>,
+) -> Result<()> {
+    // permissioned tokens are not delegated to the token manager when they are received
+    delegate_permissioned(transfer, escrow.escrow, escrow_mint_token_account, escrow.signer)?;
+    transfer_with_receipt(transfer, &escrow_mint_token_account.to_account_info(), buyer, buyer_mint_token_account, buyer, &[])?;
+
+    let cpi_accounts = CloseAccount {
+        account: escrow_mint_token_account.to_account_info(),
+        destination: buyer.to_account_info(),
+        authority: escrow.escrow.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(escrow.token_program.to_account_info(), cpi_accounts).with_signer(escrow.signer);
+    token::close_account(cpi_context)
+}
+
+// returns everything left in the escrow to its owner and closes the escrow payment token account
+pub fn refund_escrow<'info>(escrow: &Escrow<'_, 'info>, owner: &AccountInfo<'info>, owner_payment_token_account: &AccountInfo<'info>, invalid_owner_token_account_error: ErrorCode) -> Result<()> {
+    if escrow.payment_mint != Pubkey::default() {
+        let escrow_payment_token_account = Account::<TokenAccount>::try_from(escrow.escrow_payment_token_account)?;
+        if escrow_payment_token_account.owner != escrow.escrow.key() || escrow_payment_token_account.mint != escrow.payment_mint {
+            return Err(error!(escrow.invalid_token_account_error));
+        }
+        if escrow_payment_token_account.amount > 0 {
+            let owner_payment_token_account_data = Account::<TokenAccount>::try_from(owner_payment_token_account)?;
+            if owner_payment_token_account_data.owner != owner.key() || owner_payment_token_account_data.mint != escrow.payment_mint {
+                return Err(error!(invalid_owner_token_account_error));
+            }
+            let cpi_accounts = Transfer {
+                from: escrow.escrow_payment_token_account.to_account_info(),
+                to: owner_payment_token_account.to_account_info(),
+                authority: escrow.escrow.to_account_info(),
+            };
+            let cpi_context = CpiContext::new(escrow.token_program.to_account_info(), cpi_accounts).with_signer(escrow.signer);
+            token::transfer(cpi_context, escrow_payment_token_account.amount)?;
+        }
+
+        let cpi_accounts = CloseAccount {
+            account: escrow.escrow_payment_token_account.to_account_info(),
+            destination: owner.to_account_info(),
+            authority: escrow.escrow.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(escrow.token_program.to_account_info(), cpi_accounts).with_signer(escrow.signer);
+        token::close_account(cpi_context)?;
+    }
+
+    let escrow_lamports = escrow.escrow.lamports();
+    if escrow_lamports > 0 {
+        invoke_signed(
+            &system_instruction::transfer(&escrow.escrow.key(), &owner.key(), escrow_lamports),
+            &[escrow.escrow.to_account_info(), owner.to_account_info(), escrow.system_program.to_account_info()],
+            escrow.signer,
+        )?;
+    }
+    Ok(())
+}
+
+// splits the remaining accounts into the payment accounts and the trailing accounts the token manager transfer needs
+pub fn split_transfer_accounts<'a, 'info>(kind: u8, remaining_accounts: &'a [AccountInfo<'info>]) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>])> {
+    let transfer_accounts_length = transfer_remaining_accounts_length(kind);
+    if remaining_accounts.len() < transfer_accounts_length {
+        return Err(error!(ErrorCode::InvalidRemainingAccountsSize));
+    }
+    Ok(remaining_accounts.split_at(remaining_accounts.len() - transfer_accounts_length))
+}
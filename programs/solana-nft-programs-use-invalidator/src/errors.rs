@@ -34,4 +34,18 @@ pub enum ErrorCode {
     InvalidMint,
     #[msg("Invalid issuer")]
     InvalidIssuer,
+    #[msg("Invalid usage escrow")]
+    InvalidUsageEscrow,
+    #[msg("Invalid escrow token account")]
+    InvalidEscrowTokenAccount,
+    #[msg("Invalid usage price tiers")]
+    InvalidUsagePriceTiers,
+    #[msg("Usages for this period exceeded")]
+    UsagePeriodLimitReached,
+    #[msg("Invalid usage log")]
+    InvalidUsageLog,
+    #[msg("Invalid use authorities")]
+    InvalidUseAuthorities,
+    #[msg("Use authority quota exceeded")]
+    UseAuthorityQuotaExceeded,
 }
@@ -0,0 +1,73 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::utils::next_batch_invalidate_entry;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BatchInvalidateIx {
+    // number of additional accounts forwarded to the token manager invalidate for each entry
+    pub remaining_accounts_lengths: Vec<u8>,
+}
+
+#[derive(Accounts)]
+pub struct BatchInvalidateCtx<'info> {
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    invalidator: AccountInfo<'info>,
+
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    token_program: UncheckedAccount<'info>,
+    rent: Sysvar<'info, Rent>,
+    // > For each entry in remaining accounts
+    // token_manager
+    // use_invalidator
+    // token_manager_token_account
+    // mint
+    // recipient_token_account
+    // ...remaining accounts for the token manager invalidate
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, BatchInvalidateCtx<'info>>, ix: BatchInvalidateIx) -> Result<()> {
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    for remaining_accounts_length in ix.remaining_accounts_lengths {
+        let entry = next_batch_invalidate_entry(remaining_accs, remaining_accounts_length)?;
+        if entry.is_closed() {
+            msg!("Skipping closed token manager {:?}", entry.token_manager.key());
+            continue;
+        }
+        let token_manager = Account::<TokenManager>::try_from(&entry.token_manager)?;
+        let use_invalidator = Account::<UseInvalidator>::try_from(&entry.invalidator)?;
+        if use_invalidator.token_manager != token_manager.key() {
+            return Err(error!(ErrorCode::InvalidUseInvalidator));
+        }
+        if !use_invalidator.is_exhausted() {
+            msg!("Skipping token manager {:?} with remaining usages", token_manager.key());
+            continue;
+        }
+
+        let token_manager_key = token_manager.key();
+        let use_invalidator_seeds = &[USE_INVALIDATOR_SEED.as_bytes(), token_manager_key.as_ref(), &[use_invalidator.bump]];
+        let use_invalidator_signer = &[&use_invalidator_seeds[..]];
+
+        // invalidate
+        let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::InvalidateCtx {
+            token_manager: entry.token_manager.to_account_info(),
+            token_manager_token_account: entry.token_manager_token_account.to_account_info(),
+            mint: entry.mint.to_account_info(),
+            recipient_token_account: entry.recipient_token_account.to_account_info(),
+            invalidator: entry.invalidator.to_account_info(),
+            collector: ctx.accounts.invalidator.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts)
+            .with_remaining_accounts(entry.remaining_accounts)
+            .with_signer(use_invalidator_signer);
+        solana_nft_programs_token_manager::cpi::invalidate(cpi_ctx)?;
+    }
+
+    Ok(())
+}
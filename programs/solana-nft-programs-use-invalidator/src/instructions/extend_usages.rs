@@ -1,5 +1,6 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::escrow_taker_fee;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 use anchor_spl::token::Token;
@@ -9,7 +10,11 @@ use anchor_spl::token::{self};
 use solana_nft_programs_payment_manager::program::SolanaNftProgramsPaymentManager;
 use solana_nft_programs_token_manager::state::TokenManager;
 use solana_nft_programs_token_manager::state::TokenManagerState;
+use solana_nft_programs_token_manager::utils::assert_payment_target;
 use solana_nft_programs_token_manager::utils::assert_payment_token_account;
+use solana_program::program::invoke;
+use solana_program::system_instruction;
+use spl_associated_token_account::get_associated_token_address;
 
 #[derive(Accounts)]
 pub struct ExtendUsagesCtx<'info> {
@@ -23,52 +28,123 @@ pub struct ExtendUsagesCtx<'info> {
     #[account(mut, constraint = payment_manager.key() == use_invalidator.payment_manager @ ErrorCode::InvalidPaymentManager)]
     payment_manager: UncheckedAccount<'info>,
 
-    #[account(mut, constraint = payment_token_account.mint == use_invalidator.extension_payment_mint.expect("No extension mint") @ ErrorCode::InvalidPaymentTokenAccount)]
-    payment_token_account: Box<Account<'info, TokenAccount>>,
-    #[account(mut, constraint = fee_collector_token_account.mint == use_invalidator.extension_payment_mint.unwrap() @ ErrorCode::InvalidPaymentMint)]
-    fee_collector_token_account: Box<Account<'info, TokenAccount>>,
+    // payment target wallet for native payments
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    payment_token_account: UncheckedAccount<'info>,
+    // fee collector wallet for native payments
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    fee_collector_token_account: UncheckedAccount<'info>,
 
     #[account(mut)]
     payer: Signer<'info>,
-    #[account(mut, constraint =
-        payer_token_account.owner == payer.key()
-        && payer_token_account.mint == use_invalidator.extension_payment_mint.expect("No extension mint")
-        @ ErrorCode::InvalidPayerTokenAccount
-    )]
-    payer_token_account: Box<Account<'info, TokenAccount>>,
+    // unused for native payments
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut)]
+    payer_token_account: UncheckedAccount<'info>,
 
     token_program: Program<'info, Token>,
     solana_nft_programs_payment_manager: Program<'info, SolanaNftProgramsPaymentManager>,
+    system_program: Program<'info, System>,
 }
 
 pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ExtendUsagesCtx<'info>>, usages_to_add: u64) -> Result<()> {
     let remaining_accs = &mut ctx.remaining_accounts.iter();
-    assert_payment_token_account(&ctx.accounts.payment_token_account, &ctx.accounts.token_manager, remaining_accs)?;
-
     let token_manager = &mut ctx.accounts.token_manager;
     let use_invalidator = &mut ctx.accounts.use_invalidator;
     if use_invalidator.extension_payment_amount.is_none() || use_invalidator.extension_usages.is_none() || use_invalidator.extension_payment_mint.is_none() || use_invalidator.total_usages.is_none() {
         return Err(error!(ErrorCode::InvalidUseInvalidator));
     }
 
-    let price_to_pay = usages_to_add
-        .checked_mul(use_invalidator.extension_payment_amount.expect("No extension amount"))
-        .expect("Multiplication error")
-        .checked_div(use_invalidator.extension_usages.expect("No extension duration"))
-        .expect("Division error");
+    let extension_payment_mint = use_invalidator.extension_payment_mint.unwrap();
+    // native SOL
+    let is_native = extension_payment_mint == Pubkey::default();
+    if is_native {
+        assert_payment_target(&ctx.accounts.payment_token_account.key(), token_manager, remaining_accs)?;
+    } else {
+        let payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.payment_token_account)?;
+        if payment_token_account.mint != extension_payment_mint {
+            return Err(error!(ErrorCode::InvalidPaymentTokenAccount));
+        }
+        let fee_collector_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.fee_collector_token_account)?;
+        if fee_collector_token_account.mint != extension_payment_mint {
+            return Err(error!(ErrorCode::InvalidPaymentMint));
+        }
+        let payer_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.payer_token_account)?;
+        if payer_token_account.owner != ctx.accounts.payer.key() || payer_token_account.mint != extension_payment_mint {
+            return Err(error!(ErrorCode::InvalidPayerTokenAccount));
+        }
+        assert_payment_token_account(&payment_token_account, token_manager, remaining_accs)?;
+    }
+
+    let price_to_pay = use_invalidator.extension_price(usages_to_add);
 
     let new_total_usages = Some(use_invalidator.total_usages.unwrap().checked_add(usages_to_add).expect("Add error"));
     if new_total_usages > use_invalidator.max_usages {
         return Err(error!(ErrorCode::MaxUsagesReached));
     }
 
-    if ctx.accounts.payment_manager.owner.key() == ctx.accounts.solana_nft_programs_payment_manager.key() {
+    if use_invalidator.escrow_extension_payments == Some(true) {
+        // payment is held in escrow and released to the issuer as the purchased usages are consumed
+        let usage_escrow_info = next_account_info(remaining_accs)?;
+        let mut usage_escrow = Account::<UsageEscrow>::try_from(usage_escrow_info)?;
+        if usage_escrow.use_invalidator != use_invalidator.key() {
+            return Err(error!(ErrorCode::InvalidUsageEscrow));
+        }
+        let escrow_token_account_info = next_account_info(remaining_accs)?;
+        if escrow_token_account_info.key() != get_associated_token_address(&usage_escrow.key(), &usage_escrow.payment_mint) {
+            return Err(error!(ErrorCode::InvalidEscrowTokenAccount));
+        }
+        let taker_fee = escrow_taker_fee(&ctx.accounts.payment_manager, price_to_pay)?;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.payer_token_account.to_account_info(),
+            to: escrow_token_account_info.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_context, price_to_pay.checked_add(taker_fee).expect("Add error"))?;
+
+        usage_escrow.amount = usage_escrow.amount.checked_add(price_to_pay).expect("Add error");
+        usage_escrow.usages = usage_escrow.usages.checked_add(usages_to_add).expect("Add error");
+        usage_escrow.exit(ctx.program_id)?;
+    } else if is_native && ctx.accounts.payment_manager.owner.key() == ctx.accounts.solana_nft_programs_payment_manager.key() {
+        let mint_info = next_account_info(remaining_accs)?;
+        if token_manager.mint != mint_info.key() {
+            return Err(error!(ErrorCode::InvalidMint));
+        }
+        let mint_metadata_info = next_account_info(remaining_accs)?;
+
+        let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandleNativePaymentWithRoyaltiesCtx {
+            payment_manager: ctx.accounts.payment_manager.to_account_info(),
+            fee_collector: ctx.accounts.fee_collector_token_account.to_account_info(),
+            payment_target: ctx.accounts.payment_token_account.to_account_info(),
+            payer: ctx.accounts.payer.to_account_info(),
+            mint: mint_info.to_account_info(),
+            mint_metadata: mint_metadata_info.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+        let cpi_ctx =
+            CpiContext::new(ctx.accounts.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts).with_remaining_accounts(remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>());
+        solana_nft_programs_payment_manager::cpi::handle_native_payment_with_royalties(cpi_ctx, price_to_pay)?;
+    } else if is_native {
+        invoke(
+            &system_instruction::transfer(&ctx.accounts.payer.key(), &ctx.accounts.payment_token_account.key(), price_to_pay),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.payment_token_account.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    } else if ctx.accounts.payment_manager.owner.key() == ctx.accounts.solana_nft_programs_payment_manager.key() {
         let payment_mint_info = next_account_info(remaining_accs)?;
         let payment_mint = Account::<Mint>::try_from(payment_mint_info)?;
-        if use_invalidator.extension_payment_mint.unwrap() != payment_mint.key() {
+        if extension_payment_mint != payment_mint.key() {
             return Err(error!(ErrorCode::InvalidPaymentMint));
         }
 
+        // payment manager loads the rented mint as an spl token mint so token 2022 rentals cannot pay through it
         let mint_info = next_account_info(remaining_accs)?;
         let mint = Account::<Mint>::try_from(mint_info)?;
         if token_manager.mint != mint.key() {
@@ -1,30 +1,81 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
 use solana_nft_programs_token_manager::state::TokenManager;
 
 #[derive(Accounts)]
-#[instruction(num_usages: u64)]
 pub struct IncrementUsagesCtx<'info> {
     #[account(constraint = token_manager.key() == use_invalidator.token_manager @ ErrorCode::InvalidUseInvalidator)]
     token_manager: Box<Account<'info, TokenManager>>,
 
-    #[account(mut, constraint = use_invalidator.total_usages.is_none() || use_invalidator.usages.checked_add(num_usages).expect("Add error") <= use_invalidator.total_usages.expect("No usage limit") @ ErrorCode::InsufficientUsages)]
+    #[account(mut)]
     use_invalidator: Box<Account<'info, UseInvalidator>>,
 
     #[account(constraint = token_manager.recipient_token_account == recipient_token_account.key() @ ErrorCode::InvalidTokenAccount)]
-    recipient_token_account: Box<Account<'info, TokenAccount>>,
+    recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
-        constraint = (use_invalidator.use_authority.is_some() && user.key() == use_invalidator.use_authority.unwrap())
-        || (use_invalidator.use_authority.is_none() && user.key() == recipient_token_account.owner)
+        constraint = use_invalidator.is_use_authority(&user.key())
+        || (use_invalidator.use_authority.is_none() && use_invalidator.use_authorities.is_none() && user.key() == recipient_token_account.owner)
         @ ErrorCode::InvalidUser
     )]
     user: Signer<'info>,
+    // > Required once the use invalidator has a usage log
+    // usage_log
 }
 
-pub fn handler(ctx: Context<IncrementUsagesCtx>, num_usages: u64) -> Result<()> {
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, IncrementUsagesCtx<'info>>, num_usages: u64) -> Result<()> {
+    increment(ctx, num_usages, None)
+}
+
+pub fn increment<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, IncrementUsagesCtx<'info>>, num_usages: u64, memo: Option<[u8; 32]>) -> Result<()> {
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    let now = Clock::get().unwrap().unix_timestamp;
     let use_invalidator = &mut ctx.accounts.use_invalidator;
+    use_invalidator.reset_usages(now);
+    if use_invalidator.total_usages.is_some() && use_invalidator.usages.checked_add(num_usages).expect("Add error") > use_invalidator.total_usages.unwrap() {
+        return Err(error!(ErrorCode::InsufficientUsages));
+    }
     use_invalidator.usages = use_invalidator.usages.checked_add(num_usages).expect("Add error");
+
+    let user_key = ctx.accounts.user.key();
+    if let Some(use_authority) = use_invalidator.use_authorities.iter_mut().flatten().find(|use_authority| use_authority.authority == user_key) {
+        use_authority.usages = use_authority.usages.checked_add(num_usages).expect("Add error");
+        if use_authority.quota.is_some() && use_authority.usages > use_authority.quota.unwrap() {
+            return Err(error!(ErrorCode::UseAuthorityQuotaExceeded));
+        }
+    }
+
+    if let (Some(max_usages_per_period), Some(period_seconds)) = (use_invalidator.max_usages_per_period, use_invalidator.period_seconds) {
+        // a new period starts with the first use after the previous one has ended
+        if use_invalidator.period_start.is_none() || now >= use_invalidator.period_start.unwrap().checked_add(period_seconds).expect("Add error") {
+            use_invalidator.period_start = Some(now);
+            use_invalidator.period_usages = 0;
+        }
+        use_invalidator.period_usages = use_invalidator.period_usages.checked_add(num_usages).expect("Add error");
+        if use_invalidator.period_usages > max_usages_per_period {
+            return Err(error!(ErrorCode::UsagePeriodLimitReached));
+        }
+    }
+
+    match use_invalidator.usage_log {
+        Some(usage_log_id) => {
+            let usage_log_info = next_account_info(remaining_accs).map_err(|_| error!(ErrorCode::InvalidUsageLog))?;
+            if usage_log_info.key() != usage_log_id {
+                return Err(error!(ErrorCode::InvalidUsageLog));
+            }
+            let mut usage_log = Account::<UsageLog>::try_from(usage_log_info)?;
+            usage_log.record(UsageLogEntry {
+                user: ctx.accounts.user.key(),
+                count: num_usages,
+                timestamp: now,
+                memo,
+            });
+            usage_log.exit(ctx.program_id)?;
+        }
+        // a memo is only kept in the usage log
+        None if memo.is_some() => return Err(error!(ErrorCode::InvalidUsageLog)),
+        None => {}
+    }
     Ok(())
 }
@@ -0,0 +1,6 @@
+use crate::instructions::increment_usages::*;
+use anchor_lang::prelude::*;
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, IncrementUsagesCtx<'info>>, num_usages: u64, memo: [u8; 32]) -> Result<()> {
+    increment(ctx, num_usages, Some(memo))
+}
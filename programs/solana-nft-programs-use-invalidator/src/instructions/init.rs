@@ -14,6 +14,13 @@ pub struct InitIx {
     pub extension_payment_amount: Option<u64>,
     pub extension_payment_mint: Option<Pubkey>,
     pub extension_usages: Option<u64>,
+    pub escrow_extension_payments: Option<bool>,
+    pub usage_price_tiers: Option<Vec<UsagePriceTier>>,
+    pub min_price_per_usage: Option<u64>,
+    pub max_usages_per_period: Option<u64>,
+    pub period_seconds: Option<i64>,
+    pub use_authorities: Option<Vec<UseAuthorityQuota>>,
+    pub usages_reset_seconds: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -37,6 +44,33 @@ pub struct InitCtx<'info> {
 }
 
 pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+    // escrowed extensions are held in a token account so they cannot be paid in native SOL
+    if ix.escrow_extension_payments == Some(true) && (ix.extension_payment_amount.is_none() || ix.extension_payment_mint.is_none() || ix.extension_payment_mint == Some(Pubkey::default())) {
+        return Err(error!(ErrorCode::InvalidUseInvalidator));
+    }
+    if ix.max_usages_per_period.is_some() != ix.period_seconds.is_some() || ix.max_usages_per_period == Some(0) || ix.period_seconds.unwrap_or(1) <= 0 {
+        return Err(error!(ErrorCode::InvalidUseInvalidator));
+    }
+    // escrow release depends on usages only ever increasing so it cannot be combined with resetting usages
+    if ix.usages_reset_seconds.is_some() && (ix.usages_reset_seconds.unwrap() <= 0 || ix.total_usages.is_none() || ix.escrow_extension_payments == Some(true)) {
+        return Err(error!(ErrorCode::InvalidUseInvalidator));
+    }
+    if (ix.usage_price_tiers.is_some() || ix.min_price_per_usage.is_some()) && ix.extension_payment_amount.is_none() {
+        return Err(error!(ErrorCode::InvalidUsagePriceTiers));
+    }
+    if let Some(usage_price_tiers) = &ix.usage_price_tiers {
+        if usage_price_tiers.is_empty() || usage_price_tiers.len() > MAX_USAGE_PRICE_TIERS {
+            return Err(error!(ErrorCode::InvalidUsagePriceTiers));
+        }
+        // tiers must be sorted by minimum usages so the highest tier reached is the last one matched
+        let mut previous_min_usages = 0;
+        for tier in usage_price_tiers {
+            if tier.min_usages <= previous_min_usages || tier.extension_usages == 0 {
+                return Err(error!(ErrorCode::InvalidUsagePriceTiers));
+            }
+            previous_min_usages = tier.min_usages;
+        }
+    }
     let use_invalidator = &mut ctx.accounts.use_invalidator;
     use_invalidator.bump = *ctx.bumps.get("use_invalidator").unwrap();
     use_invalidator.token_manager = ctx.accounts.token_manager.key();
@@ -49,5 +83,17 @@ pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
     use_invalidator.extension_payment_amount = ix.extension_payment_amount;
     use_invalidator.extension_payment_mint = ix.extension_payment_mint;
     use_invalidator.extension_usages = ix.extension_usages;
+    use_invalidator.escrow_extension_payments = ix.escrow_extension_payments;
+    use_invalidator.usage_price_tiers = ix.usage_price_tiers;
+    use_invalidator.min_price_per_usage = ix.min_price_per_usage;
+    use_invalidator.max_usages_per_period = ix.max_usages_per_period;
+    use_invalidator.period_seconds = ix.period_seconds;
+    use_invalidator.period_start = None;
+    use_invalidator.period_usages = 0;
+    use_invalidator.use_authorities = None;
+    use_invalidator.usages_reset_seconds = ix.usages_reset_seconds;
+    use_invalidator.usages_period_start = None;
+    use_invalidator.usage_log = None;
+    use_invalidator.set_use_authorities(ix.use_authorities.unwrap_or_default())?;
     Ok(())
 }
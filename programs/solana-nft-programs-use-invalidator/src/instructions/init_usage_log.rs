@@ -0,0 +1,39 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+
+#[derive(Accounts)]
+pub struct InitUsageLogCtx<'info> {
+    #[account(constraint = token_manager.key() == use_invalidator.token_manager @ ErrorCode::InvalidUseInvalidator)]
+    token_manager: Box<Account<'info, TokenManager>>,
+    #[account(mut)]
+    use_invalidator: Box<Account<'info, UseInvalidator>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = USAGE_LOG_SIZE,
+        seeds = [USAGE_LOG_SEED.as_bytes(), use_invalidator.key().as_ref()], bump,
+    )]
+    usage_log: Box<Account<'info, UsageLog>>,
+
+    #[account(constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitUsageLogCtx>) -> Result<()> {
+    let usage_log = &mut ctx.accounts.usage_log;
+    usage_log.bump = *ctx.bumps.get("usage_log").unwrap();
+    usage_log.use_invalidator = ctx.accounts.use_invalidator.key();
+    usage_log.total_entries = 0;
+    usage_log.entries = Vec::new();
+
+    // increment_usages requires the usage log from now on
+    let use_invalidator = &mut ctx.accounts.use_invalidator;
+    use_invalidator.usage_log = Some(usage_log.key());
+    Ok(())
+}
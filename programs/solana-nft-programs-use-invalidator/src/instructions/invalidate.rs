@@ -9,7 +9,7 @@ pub struct InvalidateCtx<'info> {
     #[account(mut)]
     token_manager: Box<Account<'info, TokenManager>>,
 
-    #[account(mut, constraint = use_invalidator.usages >= use_invalidator.total_usages.expect("No usage limit") @ ErrorCode::InsufficientUsages)]
+    #[account(mut, constraint = use_invalidator.is_exhausted() @ ErrorCode::InsufficientUsages)]
     use_invalidator: Box<Account<'info, UseInvalidator>>,
 
     /// CHECK: This is not dangerous because we don't read or write from this account
@@ -1,11 +1,27 @@
+pub mod batch_invalidate;
 pub mod close;
 pub mod extend_usages;
 pub mod increment_usages;
+pub mod increment_usages_with_memo;
 pub mod init;
+pub mod init_usage_escrow;
+pub mod init_usage_log;
 pub mod invalidate;
+pub mod quote_usages;
+pub mod release_escrow;
+pub mod resize;
+pub mod set_use_authorities;
 
+pub use batch_invalidate::*;
 pub use close::*;
 pub use extend_usages::*;
 pub use increment_usages::*;
+pub use increment_usages_with_memo::*;
 pub use init::*;
+pub use init_usage_escrow::*;
+pub use init_usage_log::*;
 pub use invalidate::*;
+pub use quote_usages::*;
+pub use release_escrow::*;
+pub use resize::*;
+pub use set_use_authorities::*;
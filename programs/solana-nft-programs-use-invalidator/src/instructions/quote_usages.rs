@@ -0,0 +1,42 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::escrow_taker_fee;
+use anchor_lang::prelude::*;
+use solana_nft_programs_payment_manager::state::PaymentManager;
+use solana_program::program::set_return_data;
+
+#[derive(Accounts)]
+pub struct QuoteUsagesCtx<'info> {
+    use_invalidator: Box<Account<'info, UseInvalidator>>,
+
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(constraint = payment_manager.key() == use_invalidator.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<QuoteUsagesCtx>, usages_to_add: u64) -> Result<()> {
+    let use_invalidator = &ctx.accounts.use_invalidator;
+    if use_invalidator.extension_payment_amount.is_none() || use_invalidator.extension_usages.is_none() || use_invalidator.extension_payment_mint.is_none() {
+        return Err(error!(ErrorCode::InvalidUseInvalidator));
+    }
+
+    let price = use_invalidator.extension_price(usages_to_add);
+    let mut total_amount = price;
+    if use_invalidator.escrow_extension_payments == Some(true) {
+        // escrowed payments hold the taker fee they pay when released
+        total_amount = total_amount.checked_add(escrow_taker_fee(&ctx.accounts.payment_manager, price)?).expect("Addition error");
+    } else if ctx.accounts.payment_manager.owner.key() == solana_nft_programs_payment_manager::id() {
+        // the taker fee is charged on top of the price
+        let payment_manager = Account::<PaymentManager>::try_from(&ctx.accounts.payment_manager)?;
+        let taker_fee = price
+            .checked_mul(payment_manager.taker_fee_basis_points.into())
+            .expect("Multiplication error")
+            .checked_div(BASIS_POINTS_DIVISOR)
+            .expect("Division error");
+        total_amount = total_amount.checked_add(taker_fee).expect("Addition error");
+    }
+
+    msg!("Quoted {:?} usages for {:?}", usages_to_add, total_amount);
+    set_return_data(&total_amount.to_le_bytes());
+    Ok(())
+}
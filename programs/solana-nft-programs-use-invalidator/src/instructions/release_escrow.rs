@@ -0,0 +1,111 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+pub struct ReleaseEscrowCtx<'info> {
+    /// CHECK: This is not dangerous because we expect it to potentially be empty
+    token_manager: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because we expect it to potentially be closed
+    #[account(constraint = use_invalidator.key() == usage_escrow.use_invalidator @ ErrorCode::InvalidUseInvalidator)]
+    use_invalidator: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    usage_escrow: Box<Account<'info, UsageEscrow>>,
+    #[account(mut, constraint =
+        escrow_token_account.owner == usage_escrow.key()
+        && escrow_token_account.mint == usage_escrow.payment_mint
+        @ ErrorCode::InvalidEscrowTokenAccount
+    )]
+    escrow_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = payment_token_account.key() == usage_escrow.payment_token_account @ ErrorCode::InvalidPaymentTokenAccount)]
+    payment_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This is not dangerous because account is checked in the handler
+    #[account(mut, constraint = payment_manager.key() == usage_escrow.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ReleaseEscrowCtx<'info>>) -> Result<()> {
+    let use_invalidator_key = ctx.accounts.use_invalidator.key();
+    let usage_escrow_seeds = &[USAGE_ESCROW_SEED.as_bytes(), use_invalidator_key.as_ref(), &[ctx.accounts.usage_escrow.bump]];
+    let usage_escrow_signer = &[&usage_escrow_seeds[..]];
+
+    // while the token manager is claimed the issuer can claim the usages consumed so far, once it is over everything left belongs to the issuer
+    let mut release_usages = ctx.accounts.usage_escrow.usages;
+    if !ctx.accounts.use_invalidator.data_is_empty() {
+        let use_invalidator = Account::<UseInvalidator>::try_from(&ctx.accounts.use_invalidator)?;
+        if ctx.accounts.token_manager.key() != use_invalidator.token_manager {
+            return Err(error!(ErrorCode::InvalidTokenManager));
+        }
+        if !ctx.accounts.token_manager.data_is_empty() {
+            let token_manager = Account::<TokenManager>::try_from(&ctx.accounts.token_manager)?;
+            if token_manager.state == TokenManagerState::Claimed as u8 && use_invalidator.total_usages.is_some() {
+                release_usages = ctx.accounts.usage_escrow.consumed_usages(use_invalidator.total_usages.unwrap(), use_invalidator.usages);
+            }
+        }
+    }
+
+    let usage_escrow = &mut ctx.accounts.usage_escrow;
+    let release_amount = usage_escrow.amount_for_usages(release_usages);
+    usage_escrow.amount = usage_escrow.amount.checked_sub(release_amount).expect("Sub error");
+    usage_escrow.usages = usage_escrow.usages.checked_sub(release_usages).expect("Sub error");
+
+    if release_amount == 0 {
+        return Ok(());
+    }
+    // escrowed payments go through the payment manager like direct extensions so fees and royalties are paid on them
+    if ctx.accounts.payment_manager.owner.key() == solana_nft_programs_payment_manager::id() {
+        let remaining_accs = &mut ctx.remaining_accounts.iter();
+        let fee_collector_token_account_info = next_account_info(remaining_accs)?;
+        let payment_mint_info = next_account_info(remaining_accs)?;
+        if payment_mint_info.key() != ctx.accounts.usage_escrow.payment_mint {
+            return Err(error!(ErrorCode::InvalidPaymentMint));
+        }
+        // payment manager loads the rented mint as an spl token mint so token 2022 rentals cannot pay through it
+        let mint_info = next_account_info(remaining_accs)?;
+        if mint_info.key() != ctx.accounts.usage_escrow.mint {
+            return Err(error!(ErrorCode::InvalidMint));
+        }
+        let mint_metadata_info = next_account_info(remaining_accs)?;
+        let payment_manager_program_info = next_account_info(remaining_accs)?;
+        if payment_manager_program_info.key() != solana_nft_programs_payment_manager::id() {
+            return Err(error!(ErrorCode::InvalidPaymentManagerProgram));
+        }
+
+        let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandlePaymentWithRoyaltiesCtx {
+            payment_manager: ctx.accounts.payment_manager.to_account_info(),
+            payer_token_account: ctx.accounts.escrow_token_account.to_account_info(),
+            fee_collector_token_account: fee_collector_token_account_info.to_account_info(),
+            payment_token_account: ctx.accounts.payment_token_account.to_account_info(),
+            payment_mint: payment_mint_info.to_account_info(),
+            mint: mint_info.to_account_info(),
+            mint_metadata: mint_metadata_info.to_account_info(),
+            payer: ctx.accounts.usage_escrow.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(payment_manager_program_info.to_account_info(), cpi_accounts)
+            .with_remaining_accounts(remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>())
+            .with_signer(usage_escrow_signer);
+        solana_nft_programs_payment_manager::cpi::handle_payment_with_royalties(cpi_ctx, release_amount)?;
+    } else {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            to: ctx.accounts.payment_token_account.to_account_info(),
+            authority: ctx.accounts.usage_escrow.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(usage_escrow_signer);
+        token::transfer(cpi_context, release_amount)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetUseAuthoritiesIx {
+    pub use_authorities: Vec<UseAuthorityQuota>,
+}
+
+#[derive(Accounts)]
+pub struct SetUseAuthoritiesCtx<'info> {
+    #[account(constraint = token_manager.state != TokenManagerState::Invalidated as u8 && use_invalidator.token_manager == token_manager.key() @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    // use invalidators created before use authorities must be resized first or the list will not fit
+    #[account(mut)]
+    use_invalidator: Box<Account<'info, UseInvalidator>>,
+
+    #[account(constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetUseAuthoritiesCtx>, ix: SetUseAuthoritiesIx) -> Result<()> {
+    ctx.accounts.use_invalidator.set_use_authorities(ix.use_authorities)
+}
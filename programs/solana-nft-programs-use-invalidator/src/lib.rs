@@ -1,6 +1,7 @@
 pub mod errors;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
 use instructions::*;
@@ -15,18 +16,50 @@ pub mod solana_nft_programs_use_invalidator {
         init::handler(ctx, ix)
     }
 
-    pub fn increment_usages(ctx: Context<IncrementUsagesCtx>, num_usages: u64) -> Result<()> {
+    pub fn increment_usages<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, IncrementUsagesCtx<'info>>, num_usages: u64) -> Result<()> {
         increment_usages::handler(ctx, num_usages)
     }
 
+    pub fn increment_usages_with_memo<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, IncrementUsagesCtx<'info>>, num_usages: u64, memo: [u8; 32]) -> Result<()> {
+        increment_usages_with_memo::handler(ctx, num_usages, memo)
+    }
+
     pub fn extend_usages<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ExtendUsagesCtx<'info>>, payment_amount: u64) -> Result<()> {
         extend_usages::handler(ctx, payment_amount)
     }
 
+    pub fn quote_usages(ctx: Context<QuoteUsagesCtx>, usages_to_add: u64) -> Result<()> {
+        quote_usages::handler(ctx, usages_to_add)
+    }
+
     pub fn invalidate<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateCtx<'info>>) -> Result<()> {
         invalidate::handler(ctx)
     }
 
+    pub fn batch_invalidate<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, BatchInvalidateCtx<'info>>, ix: BatchInvalidateIx) -> Result<()> {
+        batch_invalidate::handler(ctx, ix)
+    }
+
+    pub fn init_usage_escrow(ctx: Context<InitUsageEscrowCtx>) -> Result<()> {
+        init_usage_escrow::handler(ctx)
+    }
+
+    pub fn init_usage_log(ctx: Context<InitUsageLogCtx>) -> Result<()> {
+        init_usage_log::handler(ctx)
+    }
+
+    pub fn release_escrow<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ReleaseEscrowCtx<'info>>) -> Result<()> {
+        release_escrow::handler(ctx)
+    }
+
+    pub fn resize(ctx: Context<ResizeCtx>) -> Result<()> {
+        resize::handler(ctx)
+    }
+
+    pub fn set_use_authorities(ctx: Context<SetUseAuthoritiesCtx>, ix: SetUseAuthoritiesIx) -> Result<()> {
+        set_use_authorities::handler(ctx, ix)
+    }
+
     pub fn close(ctx: Context<CloseCtx>) -> Result<()> {
         close::handler(ctx)
     }
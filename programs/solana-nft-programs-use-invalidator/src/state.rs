@@ -1,7 +1,33 @@
+use crate::errors::ErrorCode;
 use anchor_lang::prelude::*;
 
 pub const USE_INVALIDATOR_SEED: &str = "use-invalidator";
-pub const USE_INVALIDATOR_SIZE: usize = 8 + std::mem::size_of::<UseInvalidator>() + 8;
+pub const USE_INVALIDATOR_SIZE: usize =
+    8 + std::mem::size_of::<UseInvalidator>() + MAX_USAGE_PRICE_TIERS * std::mem::size_of::<UsagePriceTier>() + MAX_USE_AUTHORITIES * std::mem::size_of::<UseAuthority>() + 8;
+pub const BASIS_POINTS_DIVISOR: u64 = 10_000;
+pub const MAX_USAGE_PRICE_TIERS: usize = 4;
+pub const MAX_USE_AUTHORITIES: usize = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct UsagePriceTier {
+    pub min_usages: u64,
+    pub payment_amount: u64,
+    pub extension_usages: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct UseAuthority {
+    pub authority: Pubkey,
+    pub quota: Option<u64>,
+    pub usages: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct UseAuthorityQuota {
+    pub authority: Pubkey,
+    pub quota: Option<u64>,
+}
+
 #[account]
 pub struct UseInvalidator {
     pub bump: u8,
@@ -15,4 +41,177 @@ pub struct UseInvalidator {
     pub extension_payment_mint: Option<Pubkey>,
     pub extension_usages: Option<u64>,
     pub max_usages: Option<u64>,
+    pub escrow_extension_payments: Option<bool>,
+    pub usage_price_tiers: Option<Vec<UsagePriceTier>>,
+    pub min_price_per_usage: Option<u64>,
+    pub max_usages_per_period: Option<u64>,
+    pub period_seconds: Option<i64>,
+    pub period_start: Option<i64>,
+    pub period_usages: u64,
+    pub use_authorities: Option<Vec<UseAuthority>>,
+    pub usages_reset_seconds: Option<i64>,
+    pub usages_period_start: Option<i64>,
+    pub usage_log: Option<Pubkey>,
+}
+
+impl UseInvalidator {
+    pub fn is_exhausted(&self) -> bool {
+        // usages that reset each period only run out until the next period starts
+        if self.usages_reset_seconds.is_some() {
+            return false;
+        }
+        self.total_usages.is_some() && self.usages >= self.total_usages.unwrap()
+    }
+
+    // unused usages do not roll over, periods are aligned to the first use so each one starts on the same cadence
+    pub fn reset_usages(&mut self, now: i64) {
+        let usages_reset_seconds = match self.usages_reset_seconds {
+            Some(usages_reset_seconds) => usages_reset_seconds,
+            None => return,
+        };
+        let usages_period_start = match self.usages_period_start {
+            Some(usages_period_start) => usages_period_start,
+            None => {
+                self.usages_period_start = Some(now);
+                return;
+            }
+        };
+        let elapsed_periods = now.checked_sub(usages_period_start).expect("Sub error").checked_div(usages_reset_seconds).expect("Division error");
+        if elapsed_periods > 0 {
+            let elapsed_seconds = elapsed_periods.checked_mul(usages_reset_seconds).expect("Multiplication error");
+            self.usages_period_start = Some(usages_period_start.checked_add(elapsed_seconds).expect("Add error"));
+            self.usages = 0;
+            // quotas are per period as well
+            for use_authority in self.use_authorities.iter_mut().flatten() {
+                use_authority.usages = 0;
+            }
+        }
+    }
+
+    pub fn is_use_authority(&self, user: &Pubkey) -> bool {
+        if self.use_authority == Some(*user) {
+            return true;
+        }
+        self.use_authorities.iter().flatten().any(|use_authority| use_authority.authority == *user)
+    }
+
+    // usages already counted against an authority are kept when its quota is updated
+    pub fn set_use_authorities(&mut self, use_authority_quotas: Vec<UseAuthorityQuota>) -> Result<()> {
+        if use_authority_quotas.len() > MAX_USE_AUTHORITIES {
+            return Err(error!(ErrorCode::InvalidUseAuthorities));
+        }
+        if use_authority_quotas.is_empty() {
+            self.use_authorities = None;
+            return Ok(());
+        }
+        let mut use_authorities: Vec<UseAuthority> = Vec::new();
+        for use_authority_quota in use_authority_quotas {
+            if use_authorities.iter().any(|use_authority| use_authority.authority == use_authority_quota.authority) {
+                return Err(error!(ErrorCode::InvalidUseAuthorities));
+            }
+            let usages = self
+                .use_authorities
+                .iter()
+                .flatten()
+                .find(|use_authority| use_authority.authority == use_authority_quota.authority)
+                .map(|use_authority| use_authority.usages)
+                .unwrap_or(0);
+            use_authorities.push(UseAuthority {
+                authority: use_authority_quota.authority,
+                quota: use_authority_quota.quota,
+                usages,
+            });
+        }
+        self.use_authorities = Some(use_authorities);
+        Ok(())
+    }
+
+    // the highest tier reached by the extension prices all of its usages, but never below the per use floor
+    pub fn extension_price(&self, usages_to_add: u64) -> u64 {
+        let mut payment_amount = self.extension_payment_amount.expect("No extension amount");
+        let mut extension_usages = self.extension_usages.expect("No extension usages");
+        for tier in self.usage_price_tiers.iter().flatten() {
+            if usages_to_add < tier.min_usages {
+                break;
+            }
+            payment_amount = tier.payment_amount;
+            extension_usages = tier.extension_usages;
+        }
+        let price = usages_to_add
+            .checked_mul(payment_amount)
+            .expect("Multiplication error")
+            .checked_div(extension_usages)
+            .expect("Division error");
+        let min_price = usages_to_add.checked_mul(self.min_price_per_usage.unwrap_or(0)).expect("Multiplication error");
+        std::cmp::max(price, min_price)
+    }
+}
+
+pub const USAGE_ESCROW_SEED: &str = "usage-escrow";
+pub const USAGE_ESCROW_SIZE: usize = 8 + std::mem::size_of::<UsageEscrow>() + 8;
+#[account]
+pub struct UsageEscrow {
+    pub bump: u8,
+    pub use_invalidator: Pubkey,
+    pub payment_mint: Pubkey,
+    pub payment_token_account: Pubkey,
+    pub amount: u64,
+    pub usages: u64,
+    pub payment_manager: Pubkey,
+    pub mint: Pubkey,
+}
+
+impl UsageEscrow {
+    // extensions raise the usage limit so the escrowed usages are always the last ones before total usages
+    pub fn consumed_usages(&self, total_usages: u64, usages: u64) -> u64 {
+        let start = total_usages.saturating_sub(self.usages);
+        if usages <= start {
+            return 0;
+        }
+        std::cmp::min(usages.checked_sub(start).expect("Sub error"), self.usages)
+    }
+
+    pub fn amount_for_usages(&self, usages: u64) -> u64 {
+        if usages >= self.usages {
+            return self.amount;
+        }
+        (self.amount as u128)
+            .checked_mul(usages as u128)
+            .expect("Multiplication error")
+            .checked_div(self.usages as u128)
+            .expect("Division error") as u64
+    }
+}
+
+pub const USAGE_LOG_SEED: &str = "usage-log";
+pub const MAX_USAGE_LOG_ENTRIES: usize = 16;
+pub const USAGE_LOG_SIZE: usize = 8 + std::mem::size_of::<UsageLog>() + MAX_USAGE_LOG_ENTRIES * std::mem::size_of::<UsageLogEntry>() + 8;
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct UsageLogEntry {
+    pub user: Pubkey,
+    pub count: u64,
+    pub timestamp: i64,
+    pub memo: Option<[u8; 32]>,
+}
+
+#[account]
+pub struct UsageLog {
+    pub bump: u8,
+    pub use_invalidator: Pubkey,
+    pub total_entries: u64,
+    pub entries: Vec<UsageLogEntry>,
+}
+
+impl UsageLog {
+    // once full the oldest entry is overwritten
+    pub fn record(&mut self, entry: UsageLogEntry) {
+        let index = (self.total_entries % MAX_USAGE_LOG_ENTRIES as u64) as usize;
+        if index < self.entries.len() {
+            self.entries[index] = entry;
+        } else {
+            self.entries.push(entry);
+        }
+        self.total_entries = self.total_entries.checked_add(1).expect("Add error");
+    }
 }
@@ -0,0 +1,18 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_payment_manager::state::PaymentManager;
+
+// the escrow pays the payment manager taker fee when it is released, it is rounded up here so fees on partial releases are always covered
+pub fn escrow_taker_fee(payment_manager_info: &AccountInfo, escrow_amount: u64) -> Result<u64> {
+    if payment_manager_info.owner.key() != solana_nft_programs_payment_manager::id() {
+        return Ok(0);
+    }
+    let payment_manager = Account::<PaymentManager>::try_from(payment_manager_info)?;
+    Ok(escrow_amount
+        .checked_mul(payment_manager.taker_fee_basis_points.into())
+        .expect("Multiplication error")
+        .checked_add(BASIS_POINTS_DIVISOR.checked_sub(1).expect("Sub error"))
+        .expect("Addition error")
+        .checked_div(BASIS_POINTS_DIVISOR)
+        .expect("Division error"))
+}
@@ -3,13 +3,20 @@ import type { Connection, Keypair, PublicKey } from "@solana/web3.js";
 import { Transaction } from "@solana/web3.js";
 
 import type { IssueParameters } from ".";
+import type { UseAuthorityQuota } from "./programs/useInvalidator";
 import {
   withClaimToken,
+  withEarlyReturn,
   withExtendExpiration,
   withExtendUsages,
   withInvalidate,
+  withInitUsageLog,
   withIssueToken,
   withRelease,
+  withReleaseEscrow,
+  withReleaseUsageEscrow,
+  withResolveDeposit,
+  withSetUseAuthorities,
   withUnissueToken,
   withUse,
 } from ".";
@@ -19,9 +26,18 @@ export const useTransaction = async (
   wallet: Wallet,
   mintId: PublicKey,
   usages: number,
-  collector?: PublicKey
+  collector?: PublicKey,
+  memo?: number[]
 ): Promise<Transaction> =>
-  withUse(new Transaction(), connection, wallet, mintId, usages, collector);
+  withUse(
+    new Transaction(),
+    connection,
+    wallet,
+    mintId,
+    usages,
+    collector,
+    memo
+  );
 
 export const invalidate = async (
   connection: Connection,
@@ -90,6 +106,67 @@ export const extendExpiration = async (
     paymentAmount
   );
 
+export const earlyReturn = async (
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> =>
+  withEarlyReturn(new Transaction(), connection, wallet, mintId);
+
+export const releaseEscrow = async (
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey
+): Promise<Transaction> =>
+  withReleaseEscrow(new Transaction(), connection, wallet, tokenManagerId);
+
+export const resolveDeposit = async (
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  depositor?: PublicKey
+): Promise<Transaction> =>
+  withResolveDeposit(
+    new Transaction(),
+    connection,
+    wallet,
+    tokenManagerId,
+    depositor
+  );
+
+export const releaseUsageEscrow = async (
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey
+): Promise<Transaction> =>
+  withReleaseUsageEscrow(
+    new Transaction(),
+    connection,
+    wallet,
+    tokenManagerId
+  );
+
+export const initUsageLog = async (
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey
+): Promise<Transaction> =>
+  withInitUsageLog(new Transaction(), connection, wallet, tokenManagerId);
+
+export const setUseAuthorities = async (
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  useAuthorities: UseAuthorityQuota[]
+): Promise<Transaction> =>
+  withSetUseAuthorities(
+    new Transaction(),
+    connection,
+    wallet,
+    tokenManagerId,
+    useAuthorities
+  );
+
 export const extendUsages = async (
   connection: Connection,
   wallet: Wallet,
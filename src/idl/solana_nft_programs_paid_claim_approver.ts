@@ -106,6 +106,22 @@ export type SolanaNftProgramsPaidClaimApprover = {
       ];
       args: [];
     },
+    {
+      name: "quoteClaim";
+      accounts: [
+        {
+          name: "claimApprover";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
     {
       name: "close";
       accounts: [
@@ -360,6 +376,22 @@ export const IDL: SolanaNftProgramsPaidClaimApprover = {
       ],
       args: [],
     },
+    {
+      name: "quoteClaim",
+      accounts: [
+        {
+          name: "claimApprover",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
     {
       name: "close",
       accounts: [
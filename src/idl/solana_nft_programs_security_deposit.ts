@@ -0,0 +1,549 @@
+export type SolanaNftProgramsSecurityDeposit = {
+  version: "1.0.0";
+  name: "solana_nft_programs_security_deposit";
+  instructions: [
+    {
+      name: "init";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claimApprover";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "deposit";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claimApprover";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "deposit";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "depositor";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "claimReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "resolve";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "deposit";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "claimReceipt";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "depositor";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "close";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claimApprover";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "closer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "securityDepositClaimApprover";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "depositMint";
+            type: "publicKey";
+          },
+          {
+            name: "depositAmount";
+            type: "u64";
+          },
+          {
+            name: "collector";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "deposit";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "depositor";
+            type: "publicKey";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "InitIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "depositMint";
+            type: "publicKey";
+          },
+          {
+            name: "depositAmount";
+            type: "u64";
+          },
+          {
+            name: "collector";
+            type: "publicKey";
+          }
+        ];
+      };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidTokenManager";
+      msg: "Invalid token manager for this claim approver";
+    },
+    {
+      code: 6001;
+      name: "InvalidIssuer";
+      msg: "Invalid issuer";
+    },
+    {
+      code: 6002;
+      name: "InvalidCollector";
+      msg: "Invalid collector";
+    },
+    {
+      code: 6003;
+      name: "InvalidDepositor";
+      msg: "Invalid depositor";
+    },
+    {
+      code: 6004;
+      name: "InvalidDepositorTokenAccount";
+      msg: "Token account not owned by the depositor";
+    },
+    {
+      code: 6005;
+      name: "InvalidDepositTokenAccount";
+      msg: "Invalid deposit token account";
+    },
+    {
+      code: 6006;
+      name: "InvalidIssuerTokenAccount";
+      msg: "Token account not owned by the issuer";
+    },
+    {
+      code: 6007;
+      name: "DepositLocked";
+      msg: "Deposit is locked while the token can still be claimed or returned";
+    }
+  ];
+};
+
+export const IDL: SolanaNftProgramsSecurityDeposit = {
+  version: "1.0.0",
+  name: "solana_nft_programs_security_deposit",
+  instructions: [
+    {
+      name: "init",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claimApprover",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "deposit",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claimApprover",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "deposit",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "depositor",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "claimReceipt",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "resolve",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "deposit",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "claimReceipt",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "depositor",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "close",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claimApprover",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "closer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: "securityDepositClaimApprover",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "depositMint",
+            type: "publicKey",
+          },
+          {
+            name: "depositAmount",
+            type: "u64",
+          },
+          {
+            name: "collector",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "deposit",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "depositor",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "InitIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "depositMint",
+            type: "publicKey",
+          },
+          {
+            name: "depositAmount",
+            type: "u64",
+          },
+          {
+            name: "collector",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+  ],
+  errors: [
+    {
+      code: 6000,
+      name: "InvalidTokenManager",
+      msg: "Invalid token manager for this claim approver",
+    },
+    {
+      code: 6001,
+      name: "InvalidIssuer",
+      msg: "Invalid issuer",
+    },
+    {
+      code: 6002,
+      name: "InvalidCollector",
+      msg: "Invalid collector",
+    },
+    {
+      code: 6003,
+      name: "InvalidDepositor",
+      msg: "Invalid depositor",
+    },
+    {
+      code: 6004,
+      name: "InvalidDepositorTokenAccount",
+      msg: "Token account not owned by the depositor",
+    },
+    {
+      code: 6005,
+      name: "InvalidDepositTokenAccount",
+      msg: "Invalid deposit token account",
+    },
+    {
+      code: 6006,
+      name: "InvalidIssuerTokenAccount",
+      msg: "Token account not owned by the issuer",
+    },
+    {
+      code: 6007,
+      name: "DepositLocked",
+      msg: "Deposit is locked while the token can still be claimed or returned",
+    },
+  ],
+};
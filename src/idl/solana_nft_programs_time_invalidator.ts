@@ -87,6 +87,37 @@ export type SolanaNftProgramsTimeInvalidator = {
           name: "solanaNftProgramsPaymentManager";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "secondsToAdd";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "quoteExtension";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "timeInvalidator";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
@@ -191,6 +222,243 @@ export type SolanaNftProgramsTimeInvalidator = {
       ];
       args: [];
     },
+    {
+      name: "batchInvalidate";
+      accounts: [
+        {
+          name: "invalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "BatchInvalidateIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "initExtensionEscrow";
+      accounts: [
+        {
+          name: "timeInvalidator";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "extensionEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrowTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "earlyReturn";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "timeInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "extensionEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrowTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "recipientPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManagerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "releaseEscrow";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "timeInvalidator";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "extensionEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrowTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "resize";
+      accounts: [
+        {
+          name: "timeInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
     {
       name: "close";
       accounts: [
@@ -281,12 +549,120 @@ export type SolanaNftProgramsTimeInvalidator = {
             type: {
               option: "bool";
             };
-          }
-        ];
-      };
-    }
-  ];
-  types: [
+          },
+          {
+            name: "gracePeriodSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "gracePeriodPenaltyBasisPoints";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "overduePenaltyAmount";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "overduePenaltyDurationSeconds";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "overduePenaltyCollectorBasisPoints";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "refundBasisPoints";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "escrowExtensionPayments";
+            type: {
+              option: "bool";
+            };
+          },
+          {
+            name: "extensionPriceTiers";
+            type: {
+              option: {
+                vec: {
+                  defined: "ExtensionPriceTier";
+                };
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "extensionEscrow";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "timeInvalidator";
+            type: "publicKey";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "paymentTokenAccount";
+            type: "publicKey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "seconds";
+            type: "u64";
+          },
+          {
+            name: "claimedAt";
+            type: "i64";
+          },
+          {
+            name: "paymentManager";
+            type: "publicKey";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "BatchInvalidateIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "remainingAccountsLengths";
+            type: "bytes";
+          }
+        ];
+      };
+    },
     {
       name: "InitIx";
       type: {
@@ -335,6 +711,58 @@ export type SolanaNftProgramsTimeInvalidator = {
             type: {
               option: "bool";
             };
+          },
+          {
+            name: "gracePeriodSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "gracePeriodPenaltyBasisPoints";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "overduePenaltyAmount";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "overduePenaltyDurationSeconds";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "overduePenaltyCollectorBasisPoints";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "refundBasisPoints";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "escrowExtensionPayments";
+            type: {
+              option: "bool";
+            };
+          },
+          {
+            name: "extensionPriceTiers";
+            type: {
+              option: {
+                vec: {
+                  defined: "ExtensionPriceTier";
+                };
+              };
+            };
           }
         ];
       };
@@ -350,6 +778,26 @@ export type SolanaNftProgramsTimeInvalidator = {
           }
         ];
       };
+    },
+    {
+      name: "ExtensionPriceTier";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "thresholdSeconds";
+            type: "u64";
+          },
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
+          {
+            name: "durationSeconds";
+            type: "u64";
+          }
+        ];
+      };
     }
   ];
   errors: [
@@ -447,6 +895,41 @@ export type SolanaNftProgramsTimeInvalidator = {
       code: 6018;
       name: "InvalidNewMaxExpiration";
       msg: "Invalid new max expiration";
+    },
+    {
+      code: 6019;
+      name: "InvalidGracePeriod";
+      msg: "Invalid grace period";
+    },
+    {
+      code: 6020;
+      name: "InvalidOverduePenalty";
+      msg: "Invalid overdue penalty";
+    },
+    {
+      code: 6021;
+      name: "InvalidExtensionEscrow";
+      msg: "Invalid extension escrow";
+    },
+    {
+      code: 6022;
+      name: "InvalidEscrowTokenAccount";
+      msg: "Invalid escrow token account";
+    },
+    {
+      code: 6023;
+      name: "InvalidEarlyReturn";
+      msg: "Token manager cannot be returned early";
+    },
+    {
+      code: 6024;
+      name: "InvalidExtensionPriceTiers";
+      msg: "Invalid extension price tiers";
+    },
+    {
+      code: 6025;
+      name: "StaleExtensionEscrow";
+      msg: "Extension escrow holds payments from a previous rental and must be released first";
     }
   ];
 };
@@ -469,36 +952,312 @@ export const IDL: SolanaNftProgramsTimeInvalidator = {
           isSigner: false,
         },
         {
-          name: "issuer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "extendExpiration",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsPaymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "secondsToAdd",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "quoteExtension",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidator",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "secondsToAdd",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "resetExpiration",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "updateMaxExpiration",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdateMaxExpirationIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "invalidate",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "batchInvalidate",
+      accounts: [
+        {
+          name: "invalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "BatchInvalidateIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "initExtensionEscrow",
+      accounts: [
+        {
+          name: "timeInvalidator",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "extensionEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "paymentTokenAccount",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "ix",
-          type: {
-            defined: "InitIx",
-          },
+          name: "paymentManager",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "extendExpiration",
+      name: "earlyReturn",
       accounts: [
         {
           name: "tokenManager",
-          isMut: false,
+          isMut: true,
           isSigner: false,
         },
         {
@@ -507,7 +1266,12 @@ export const IDL: SolanaNftProgramsTimeInvalidator = {
           isSigner: false,
         },
         {
-          name: "paymentManager",
+          name: "extensionEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowTokenAccount",
           isMut: true,
           isSigner: false,
         },
@@ -517,127 +1281,114 @@ export const IDL: SolanaNftProgramsTimeInvalidator = {
           isSigner: false,
         },
         {
-          name: "feeCollectorTokenAccount",
+          name: "paymentManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "payer",
+          name: "recipient",
           isMut: true,
           isSigner: true,
         },
         {
-          name: "payerTokenAccount",
+          name: "recipientPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
+          name: "solanaNftProgramsTokenManager",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "solanaNftProgramsPaymentManager",
+          name: "paymentTokenProgram",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
-        {
-          name: "secondsToAdd",
-          type: "u64",
-        },
-      ],
-    },
-    {
-      name: "resetExpiration",
-      accounts: [
         {
-          name: "tokenManager",
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "timeInvalidator",
+          name: "tokenManagerTokenAccount",
           isMut: true,
           isSigner: false,
         },
-      ],
-      args: [],
-    },
-    {
-      name: "updateMaxExpiration",
-      accounts: [
-        {
-          name: "tokenManager",
-          isMut: false,
-          isSigner: false,
-        },
         {
-          name: "timeInvalidator",
+          name: "mint",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "issuer",
+          name: "recipientTokenAccount",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "ix",
-          type: {
-            defined: "UpdateMaxExpirationIx",
-          },
+          name: "rent",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "invalidate",
+      name: "releaseEscrow",
       accounts: [
         {
           name: "tokenManager",
-          isMut: true,
+          isMut: false,
           isSigner: false,
         },
         {
           name: "timeInvalidator",
-          isMut: true,
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "invalidator",
+          name: "extensionEscrow",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "solanaNftProgramsTokenManager",
-          isMut: false,
+          name: "escrowTokenAccount",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
+          name: "paymentTokenAccount",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenManagerTokenAccount",
+          name: "paymentManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "mint",
-          isMut: true,
+          name: "tokenProgram",
+          isMut: false,
           isSigner: false,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "resize",
+      accounts: [
         {
-          name: "recipientTokenAccount",
+          name: "timeInvalidator",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "rent",
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
@@ -735,11 +1486,119 @@ export const IDL: SolanaNftProgramsTimeInvalidator = {
               option: "bool",
             },
           },
+          {
+            name: "gracePeriodSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "gracePeriodPenaltyBasisPoints",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "overduePenaltyAmount",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "overduePenaltyDurationSeconds",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "overduePenaltyCollectorBasisPoints",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "refundBasisPoints",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "escrowExtensionPayments",
+            type: {
+              option: "bool",
+            },
+          },
+          {
+            name: "extensionPriceTiers",
+            type: {
+              option: {
+                vec: {
+                  defined: "ExtensionPriceTier",
+                },
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "extensionEscrow",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "timeInvalidator",
+            type: "publicKey",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "paymentTokenAccount",
+            type: "publicKey",
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "seconds",
+            type: "u64",
+          },
+          {
+            name: "claimedAt",
+            type: "i64",
+          },
+          {
+            name: "paymentManager",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
         ],
       },
     },
   ],
   types: [
+    {
+      name: "BatchInvalidateIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "remainingAccountsLengths",
+            type: "bytes",
+          },
+        ],
+      },
+    },
     {
       name: "InitIx",
       type: {
@@ -789,6 +1648,58 @@ export const IDL: SolanaNftProgramsTimeInvalidator = {
               option: "bool",
             },
           },
+          {
+            name: "gracePeriodSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "gracePeriodPenaltyBasisPoints",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "overduePenaltyAmount",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "overduePenaltyDurationSeconds",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "overduePenaltyCollectorBasisPoints",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "refundBasisPoints",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "escrowExtensionPayments",
+            type: {
+              option: "bool",
+            },
+          },
+          {
+            name: "extensionPriceTiers",
+            type: {
+              option: {
+                vec: {
+                  defined: "ExtensionPriceTier",
+                },
+              },
+            },
+          },
         ],
       },
     },
@@ -804,6 +1715,26 @@ export const IDL: SolanaNftProgramsTimeInvalidator = {
         ],
       },
     },
+    {
+      name: "ExtensionPriceTier",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "thresholdSeconds",
+            type: "u64",
+          },
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "durationSeconds",
+            type: "u64",
+          },
+        ],
+      },
+    },
   ],
   errors: [
     {
@@ -901,5 +1832,40 @@ export const IDL: SolanaNftProgramsTimeInvalidator = {
       name: "InvalidNewMaxExpiration",
       msg: "Invalid new max expiration",
     },
+    {
+      code: 6019,
+      name: "InvalidGracePeriod",
+      msg: "Invalid grace period",
+    },
+    {
+      code: 6020,
+      name: "InvalidOverduePenalty",
+      msg: "Invalid overdue penalty",
+    },
+    {
+      code: 6021,
+      name: "InvalidExtensionEscrow",
+      msg: "Invalid extension escrow",
+    },
+    {
+      code: 6022,
+      name: "InvalidEscrowTokenAccount",
+      msg: "Invalid escrow token account",
+    },
+    {
+      code: 6023,
+      name: "InvalidEarlyReturn",
+      msg: "Token manager cannot be returned early",
+    },
+    {
+      code: 6024,
+      name: "InvalidExtensionPriceTiers",
+      msg: "Invalid extension price tiers",
+    },
+    {
+      code: 6025,
+      name: "StaleExtensionEscrow",
+      msg: "Extension escrow holds payments from a previous rental and must be released first",
+    },
   ],
 };
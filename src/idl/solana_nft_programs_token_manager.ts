@@ -50,6 +50,54 @@ export type SolanaNftProgramsTokenManager = {
         }
       ];
     },
+    {
+      name: "initCounted";
+      accounts: [
+        {
+          name: "mintCounter";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "issuerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitIx";
+          };
+        }
+      ];
+    },
     {
       name: "uninit";
       accounts: [
@@ -807,6 +855,47 @@ export type SolanaNftProgramsTokenManager = {
       ];
       args: [];
     },
+    {
+      name: "revokeDelegate";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManagerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
     {
       name: "migrate";
       accounts: [
@@ -1117,6 +1206,9 @@ export type SolanaNftProgramsTokenManager = {
           },
           {
             name: "Programmable";
+          },
+          {
+            name: "PermanentDelegate";
           }
         ];
       };
@@ -1145,6 +1237,268 @@ export type SolanaNftProgramsTokenManager = {
       };
     }
   ];
+  events: [
+    {
+      name: "TokenManagerIssued";
+      fields: [
+        {
+          name: "tokenManager";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "issuer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "kind";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "invalidationType";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TokenManagerUnissued";
+      fields: [
+        {
+          name: "tokenManager";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "issuer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "kind";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TokenManagerClaimed";
+      fields: [
+        {
+          name: "tokenManager";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "issuer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "recipient";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "kind";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TokenManagerTransferred";
+      fields: [
+        {
+          name: "tokenManager";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "issuer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "fromTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "recipient";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "kind";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TokenManagerInvalidated";
+      fields: [
+        {
+          name: "tokenManager";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "issuer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "invalidator";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "kind";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "invalidationType";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "state";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "closed";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TokenManagerMigrated";
+      fields: [
+        {
+          name: "tokenManager";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "issuer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "kind";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    }
+  ];
   errors: [
     {
       code: 6000;
@@ -1342,27 +1696,105 @@ export type SolanaNftProgramsTokenManager = {
       msg: "Cannot migrate delegated token";
     },
     {
-      code: 6039;
-      name: "InvalidReturnTarget";
-      msg: "Invalid return target";
-    }
-  ];
-};
-
-export const IDL: SolanaNftProgramsTokenManager = {
-  version: "1.0.0",
-  name: "solana_nft_programs_token_manager",
-  instructions: [
-    {
-      name: "init",
+      code: 6039;
+      name: "InvalidReturnTarget";
+      msg: "Invalid return target";
+    },
+    {
+      code: 6040;
+      name: "NonTransferableMint";
+      msg: "Non transferable mints cannot be managed";
+    },
+    {
+      code: 6041;
+      name: "TransferFeeNotSupported";
+      msg: "Mints with transfer fees are not supported";
+    },
+    {
+      code: 6042;
+      name: "TransferHookNotSupported";
+      msg: "Mints with transfer hooks are not supported";
+    },
+    {
+      code: 6043;
+      name: "InvalidPermanentDelegate";
+      msg: "Mint permanent delegate must be the mint manager";
+    },
+    {
+      code: 6044;
+      name: "InvalidAmount";
+      msg: "Invalid token manager amount";
+    },
+    {
+      code: 6045;
+      name: "RecipientTokenAccountNotEmpty";
+      msg: "Recipient token account must be empty for this token manager kind";
+    }
+  ];
+};
+
+export const IDL: SolanaNftProgramsTokenManager = {
+  version: "1.0.0",
+  name: "solana_nft_programs_token_manager",
+  instructions: [
+    {
+      name: "init",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "issuerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "initCounted",
       accounts: [
         {
-          name: "tokenManager",
+          name: "mintCounter",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "mintCounter",
+          name: "tokenManager",
           isMut: true,
           isSigner: false,
         },
@@ -2158,6 +2590,47 @@ export const IDL: SolanaNftProgramsTokenManager = {
       ],
       args: [],
     },
+    {
+      name: "revokeDelegate",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transferAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
     {
       name: "migrate",
       accounts: [
@@ -2469,6 +2942,9 @@ export const IDL: SolanaNftProgramsTokenManager = {
           {
             name: "Programmable",
           },
+          {
+            name: "PermanentDelegate",
+          },
         ],
       },
     },
@@ -2496,6 +2972,268 @@ export const IDL: SolanaNftProgramsTokenManager = {
       },
     },
   ],
+  events: [
+    {
+      name: "TokenManagerIssued",
+      fields: [
+        {
+          name: "tokenManager",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "issuer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "kind",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "invalidationType",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TokenManagerUnissued",
+      fields: [
+        {
+          name: "tokenManager",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "issuer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "kind",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TokenManagerClaimed",
+      fields: [
+        {
+          name: "tokenManager",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "issuer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "recipient",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "kind",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TokenManagerTransferred",
+      fields: [
+        {
+          name: "tokenManager",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "issuer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "fromTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "recipient",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "kind",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TokenManagerInvalidated",
+      fields: [
+        {
+          name: "tokenManager",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "issuer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "invalidator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "kind",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "invalidationType",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "state",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "closed",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TokenManagerMigrated",
+      fields: [
+        {
+          name: "tokenManager",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "issuer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "kind",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+  ],
   errors: [
     {
       code: 6000,
@@ -2697,5 +3435,35 @@ export const IDL: SolanaNftProgramsTokenManager = {
       name: "InvalidReturnTarget",
       msg: "Invalid return target",
     },
+    {
+      code: 6040,
+      name: "NonTransferableMint",
+      msg: "Non transferable mints cannot be managed",
+    },
+    {
+      code: 6041,
+      name: "TransferFeeNotSupported",
+      msg: "Mints with transfer fees are not supported",
+    },
+    {
+      code: 6042,
+      name: "TransferHookNotSupported",
+      msg: "Mints with transfer hooks are not supported",
+    },
+    {
+      code: 6043,
+      name: "InvalidPermanentDelegate",
+      msg: "Mint permanent delegate must be the mint manager",
+    },
+    {
+      code: 6044,
+      name: "InvalidAmount",
+      msg: "Invalid token manager amount",
+    },
+    {
+      code: 6045,
+      name: "RecipientTokenAccountNotEmpty",
+      msg: "Recipient token account must be empty for this token manager kind",
+    },
   ],
 };
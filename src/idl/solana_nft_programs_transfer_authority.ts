@@ -199,6 +199,11 @@ export type SolanaNftProgramsTransferAuthority = {
           name: "instructions";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "englishAuction";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
@@ -232,6 +237,11 @@ export type SolanaNftProgramsTransferAuthority = {
           name: "lister";
           isMut: true;
           isSigner: true;
+        },
+        {
+          name: "englishAuction";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
@@ -423,88 +433,118 @@ export type SolanaNftProgramsTransferAuthority = {
       args: [];
     },
     {
-      name: "initMarketplace";
+      name: "removeExpiredListing";
       accounts: [
         {
-          name: "marketplace";
+          name: "listing";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "paymentManager";
+          name: "lister";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "payer";
+          name: "tokenManager";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "tokenManagerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listerMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuction";
           isMut: false;
           isSigner: false;
-        }
-      ];
-      args: [
+        },
         {
-          name: "ix";
-          type: {
-            defined: "InitMarketplaceIx";
-          };
-        }
-      ];
-    },
-    {
-      name: "updateMarketplace";
-      accounts: [
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
         {
-          name: "marketplace";
+          name: "mintManager";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "authority";
-          isMut: true;
-          isSigner: true;
-        }
-      ];
-      args: [
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
         {
-          name: "ix";
-          type: {
-            defined: "UpdateMarketplaceIx";
-          };
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "initTransfer";
+      name: "makeOffer";
       accounts: [
         {
-          name: "transfer";
+          name: "offer";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenManager";
-          isMut: false;
+          name: "offerEscrow";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "holderTokenAccount";
+          name: "offerPaymentTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "holder";
+          name: "transferAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "payer";
+          name: "buyerPaymentTokenAccount";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
           name: "systemProgram";
@@ -516,102 +556,157 @@ export type SolanaNftProgramsTransferAuthority = {
         {
           name: "ix";
           type: {
-            defined: "InitTransferIx";
+            defined: "MakeOfferIx";
           };
         }
       ];
     },
     {
-      name: "cancelTransfer";
+      name: "cancelOffer";
       accounts: [
         {
-          name: "transfer";
+          name: "offer";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenManager";
-          isMut: false;
+          name: "offerEscrow";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "holderTokenAccount";
-          isMut: false;
+          name: "offerPaymentTokenAccount";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "holder";
+          name: "buyer";
           isMut: true;
           isSigner: true;
+        },
+        {
+          name: "buyerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [];
     },
     {
-      name: "acceptTransfer";
+      name: "acceptOffer";
       accounts: [
         {
-          name: "transfer";
+          name: "transferAuthority";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "transferAuthority";
-          isMut: false;
+          name: "transferReceipt";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "transferReceipt";
+          name: "offer";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "listing";
+          name: "offerEscrow";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenManager";
+          name: "offerPaymentTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "mint";
-          isMut: false;
+          name: "holderMintTokenAccount";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "recipientTokenAccount";
+          name: "holderPaymentTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "recipient";
+          name: "holder";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "payer";
+          name: "buyer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "holderTokenAccount";
+          name: "buyerMintTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "holder";
+          name: "buyerPaymentTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "solanaNftProgramsTokenManager";
+          name: "marketplace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "associatedTokenProgram";
+          name: "mintMetadataInfo";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "feeCollectorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeCollector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsPaymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
           isMut: false;
           isSigner: false;
         },
@@ -626,82 +721,1379 @@ export type SolanaNftProgramsTransferAuthority = {
           isSigner: false;
         },
         {
-          name: "rent";
+          name: "instructions";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "instructions";
+          name: "englishAuction";
           isMut: false;
           isSigner: false;
         }
       ];
-      args: [];
-    }
-  ];
-  accounts: [
-    {
-      name: "transferAuthority";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "name";
-            type: "string";
-          },
-          {
-            name: "authority";
-            type: "publicKey";
-          },
-          {
-            name: "allowedMarketplaces";
-            type: {
-              option: {
-                vec: "publicKey";
-              };
-            };
-          }
-        ];
-      };
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "AcceptOfferIx";
+          };
+        }
+      ];
     },
     {
-      name: "marketplace";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "name";
-            type: "string";
-          },
-          {
-            name: "paymentManager";
-            type: "publicKey";
-          },
-          {
-            name: "authority";
+      name: "makeCollectionBid";
+      accounts: [
+        {
+          name: "collectionBid";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectionBidEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectionBidPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "buyerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "MakeCollectionBidIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "cancelCollectionBid";
+      accounts: [
+        {
+          name: "collectionBid";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectionBidEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectionBidPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "buyerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "acceptCollectionBid";
+      accounts: [
+        {
+          name: "transferAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectionBid";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectionBidEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectionBidPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "holderMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "holderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "holder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "buyerMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadataInfo";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "feeCollectorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeCollector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsPaymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "englishAuction";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "AcceptCollectionBidIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "createDutchAuction";
+      accounts: [
+        {
+          name: "dutchAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "CreateDutchAuctionIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "acceptDutchAuction";
+      accounts: [
+        {
+          name: "transferAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "dutchAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listerMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyerMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadataInfo";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "feeCollectorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeCollector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsPaymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "englishAuction";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "AcceptDutchAuctionIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "removeDutchAuction";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "dutchAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listerMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createEnglishAuction";
+      accounts: [
+        {
+          name: "englishAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuctionEscrow";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "transferAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "listing";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "CreateEnglishAuctionIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "placeBid";
+      accounts: [
+        {
+          name: "englishAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuctionEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuctionPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "bidder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "bidderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "previousBidder";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "previousBidderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "PlaceBidIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "settleEnglishAuction";
+      accounts: [
+        {
+          name: "transferAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuctionEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuctionPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuctionMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listerMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "bidder";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "bidderMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "bidderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "settler";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "marketplace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadataInfo";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "feeCollectorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeCollector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsPaymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "cancelEnglishAuction";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listerMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "claimEnglishAuction";
+      accounts: [
+        {
+          name: "transferAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuctionEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuctionPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuctionMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "bidder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "bidderMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "bidderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "listerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadataInfo";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "feeCollectorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeCollector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsPaymentManager";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeEnglishAuction";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "listerMintTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "englishAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuctionEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "englishAuctionPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "bidder";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "bidderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initMarketplace";
+      accounts: [
+        {
+          name: "marketplace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitMarketplaceIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "updateMarketplace";
+      accounts: [
+        {
+          name: "marketplace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "UpdateMarketplaceIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "initTransfer";
+      accounts: [
+        {
+          name: "transfer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "holderTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "holder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitTransferIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "cancelTransfer";
+      accounts: [
+        {
+          name: "transfer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "holderTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "holder";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "acceptTransfer";
+      accounts: [
+        {
+          name: "transfer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "transferReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listing";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "holderTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "holder";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "transferAuthority";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "allowedMarketplaces";
+            type: {
+              option: {
+                vec: "publicKey";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "marketplace";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "paymentManager";
+            type: "publicKey";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "paymentMints";
+            type: {
+              option: {
+                vec: "publicKey";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "listing";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "lister";
             type: "publicKey";
           },
           {
-            name: "paymentMints";
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "marketplace";
+            type: "publicKey";
+          },
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "startAt";
             type: {
-              option: {
-                vec: "publicKey";
-              };
+              option: "i64";
+            };
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "allowedBuyer";
+            type: {
+              option: "publicKey";
             };
           }
         ];
       };
     },
     {
-      name: "listing";
+      name: "transfer";
       type: {
         kind: "struct";
         fields: [
@@ -710,7 +2102,35 @@ export type SolanaNftProgramsTransferAuthority = {
             type: "u8";
           },
           {
-            name: "lister";
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "from";
+            type: "publicKey";
+          },
+          {
+            name: "to";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "offer";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "escrowBump";
+            type: "u8";
+          },
+          {
+            name: "buyer";
             type: "publicKey";
           },
           {
@@ -733,7 +2153,47 @@ export type SolanaNftProgramsTransferAuthority = {
       };
     },
     {
-      name: "transfer";
+      name: "collectionBid";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "escrowBump";
+            type: "u8";
+          },
+          {
+            name: "buyer";
+            type: "publicKey";
+          },
+          {
+            name: "collection";
+            type: "publicKey";
+          },
+          {
+            name: "transferAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "marketplace";
+            type: "publicKey";
+          },
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "dutchAuction";
       type: {
         kind: "struct";
         fields: [
@@ -741,23 +2201,221 @@ export type SolanaNftProgramsTransferAuthority = {
             name: "bump";
             type: "u8";
           },
+          {
+            name: "lister";
+            type: "publicKey";
+          },
           {
             name: "tokenManager";
             type: "publicKey";
           },
           {
-            name: "from";
+            name: "marketplace";
             type: "publicKey";
           },
           {
-            name: "to";
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "startAmount";
+            type: "u64";
+          },
+          {
+            name: "floorAmount";
+            type: "u64";
+          },
+          {
+            name: "startAt";
+            type: "i64";
+          },
+          {
+            name: "endAt";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "englishAuction";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "escrowBump";
+            type: "u8";
+          },
+          {
+            name: "lister";
+            type: "publicKey";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "marketplace";
+            type: "publicKey";
+          },
+          {
+            name: "paymentMint";
             type: "publicKey";
+          },
+          {
+            name: "reserveAmount";
+            type: "u64";
+          },
+          {
+            name: "minIncrement";
+            type: "u64";
+          },
+          {
+            name: "endAt";
+            type: "i64";
+          },
+          {
+            name: "antiSnipeSeconds";
+            type: "i64";
+          },
+          {
+            name: "highestBidder";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "highestBid";
+            type: "u64";
+          },
+          {
+            name: "highestBidEscrowAmount";
+            type: "u64";
+          },
+          {
+            name: "settled";
+            type: "bool";
           }
         ];
       };
     }
   ];
   types: [
+    {
+      name: "AcceptDutchAuctionIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "maxPaymentAmount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "CreateDutchAuctionIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "startAmount";
+            type: "u64";
+          },
+          {
+            name: "floorAmount";
+            type: "u64";
+          },
+          {
+            name: "startAt";
+            type: "i64";
+          },
+          {
+            name: "endAt";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "CreateEnglishAuctionIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "reserveAmount";
+            type: "u64";
+          },
+          {
+            name: "minIncrement";
+            type: "u64";
+          },
+          {
+            name: "endAt";
+            type: "i64";
+          },
+          {
+            name: "antiSnipeSeconds";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "PlaceBidIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "paymentAmount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "AcceptCollectionBidIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "paymentAmount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "MakeCollectionBidIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "collection";
+            type: "publicKey";
+          },
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
     {
       name: "AcceptListingIx";
       type: {
@@ -782,6 +2440,24 @@ export type SolanaNftProgramsTransferAuthority = {
           {
             name: "paymentMint";
             type: "publicKey";
+          },
+          {
+            name: "startAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "allowedBuyer";
+            type: {
+              option: "publicKey";
+            };
           }
         ];
       };
@@ -802,6 +2478,24 @@ export type SolanaNftProgramsTransferAuthority = {
           {
             name: "paymentMint";
             type: "publicKey";
+          },
+          {
+            name: "startAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "allowedBuyer";
+            type: {
+              option: "publicKey";
+            };
           }
         ];
       };
@@ -854,6 +2548,34 @@ export type SolanaNftProgramsTransferAuthority = {
         ];
       };
     },
+    {
+      name: "AcceptOfferIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "paymentAmount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "MakeOfferIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
     {
       name: "InitTransferIx";
       type: {
@@ -891,329 +2613,1802 @@ export type SolanaNftProgramsTransferAuthority = {
       };
     },
     {
-      name: "UpdateTransferAuthorityIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "authority";
-            type: "publicKey";
-          },
-          {
-            name: "allowedMarketplaces";
-            type: {
-              option: {
-                vec: "publicKey";
-              };
-            };
-          }
-        ];
-      };
+      name: "UpdateTransferAuthorityIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "allowedMarketplaces";
+            type: {
+              option: {
+                vec: "publicKey";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "WhitelistMarketplacesIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "allowedMarketplaces";
+            type: {
+              vec: "publicKey";
+            };
+          }
+        ];
+      };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidTokenManager";
+      msg: "Invalid token manager for this transfer authority";
+    },
+    {
+      code: 6001;
+      name: "InvalidLister";
+      msg: "Invalid lister";
+    },
+    {
+      code: 6002;
+      name: "InvalidPaymentMint";
+      msg: "Invalid payment mint";
+    },
+    {
+      code: 6003;
+      name: "InvalidMarketplace";
+      msg: "Invalid marketplace";
+    },
+    {
+      code: 6004;
+      name: "InvalidBuyerPaymentTokenAccount";
+      msg: "Invalid buyer payment token account";
+    },
+    {
+      code: 6005;
+      name: "InvalidBuyerMintTokenAccount";
+      msg: "Invalid buyer mint token account";
+    },
+    {
+      code: 6006;
+      name: "InvalidOfferTokenAccount";
+      msg: "Invalid offer token account";
+    },
+    {
+      code: 6007;
+      name: "InvalidPaymentManager";
+      msg: "Invalid payment manager";
+    },
+    {
+      code: 6008;
+      name: "InvalidMint";
+      msg: "Invalid mint";
+    },
+    {
+      code: 6009;
+      name: "InvalidFeeCollector";
+      msg: "Invalid fee collector";
+    },
+    {
+      code: 6010;
+      name: "InvalidListerPaymentTokenAccount";
+      msg: "Invalid lister payment token account";
+    },
+    {
+      code: 6011;
+      name: "InvalidListerMintTokenAccount";
+      msg: "Invalid lister mint token account";
+    },
+    {
+      code: 6012;
+      name: "InvalidMarketplaceAuthority";
+      msg: "Invalid marketplace authority";
+    },
+    {
+      code: 6013;
+      name: "InvalidTransferAuthorityAuthority";
+      msg: "Invalid transfer authority authority";
+    },
+    {
+      code: 6014;
+      name: "InvalidTransferAuthority";
+      msg: "Invalid transfer authority";
+    },
+    {
+      code: 6015;
+      name: "MarketplaceNotAllowed";
+      msg: "Marketplace place not allowed by transfer authority";
+    },
+    {
+      code: 6016;
+      name: "InvalidHolder";
+      msg: "Invalid token holder";
+    },
+    {
+      code: 6017;
+      name: "InvalidHolderMintTokenAccount";
+      msg: "Invalid holder token account";
+    },
+    {
+      code: 6018;
+      name: "InvalidTransfer";
+      msg: "Invalid transfer account";
+    },
+    {
+      code: 6019;
+      name: "InvalidRecipient";
+      msg: "Invalid recipient";
+    },
+    {
+      code: 6020;
+      name: "InvalidRecipientMintTokenAccount";
+      msg: "Invalid recipient mint token account";
+    },
+    {
+      code: 6021;
+      name: "InvalidDerivation";
+      msg: "Invalid derivation";
+    },
+    {
+      code: 6022;
+      name: "InstructionsDisallowed";
+      msg: "Transaction included disallowed";
+    },
+    {
+      code: 6023;
+      name: "TokenNotDelegated";
+      msg: "Token must be delegated";
+    },
+    {
+      code: 6024;
+      name: "ListingChanged";
+      msg: "Listing payment amount or mint has changed";
+    },
+    {
+      code: 6025;
+      name: "InvalidRemainingAccountsSize";
+      msg: "Invalid remaining accounts size";
+    },
+    {
+      code: 6026;
+      name: "InvalidPayerPaymentTokenAccount";
+      msg: "Invalid payer payment token account";
+    },
+    {
+      code: 6027;
+      name: "InvalidOffer";
+      msg: "Invalid offer";
+    },
+    {
+      code: 6028;
+      name: "OfferChanged";
+      msg: "Offer payment amount has changed";
+    },
+    {
+      code: 6029;
+      name: "InvalidHolderPaymentTokenAccount";
+      msg: "Invalid holder payment token account";
+    },
+    {
+      code: 6030;
+      name: "InvalidCollectionBid";
+      msg: "Invalid collection bid";
+    },
+    {
+      code: 6031;
+      name: "InvalidCollectionBidTokenAccount";
+      msg: "Invalid collection bid token account";
+    },
+    {
+      code: 6032;
+      name: "CollectionBidChanged";
+      msg: "Collection bid payment amount has changed";
+    },
+    {
+      code: 6033;
+      name: "InvalidMintMetadata";
+      msg: "Invalid mint metadata";
+    },
+    {
+      code: 6034;
+      name: "MintNotInCollection";
+      msg: "Mint is not a verified member of the collection";
+    },
+    {
+      code: 6035;
+      name: "InvalidListingSchedule";
+      msg: "Invalid listing start or expiration";
+    },
+    {
+      code: 6036;
+      name: "ListingNotStarted";
+      msg: "Listing has not started yet";
+    },
+    {
+      code: 6037;
+      name: "ListingExpired";
+      msg: "Listing has expired";
+    },
+    {
+      code: 6038;
+      name: "ListingNotExpired";
+      msg: "Listing has not expired";
+    },
+    {
+      code: 6039;
+      name: "InvalidDutchAuction";
+      msg: "Invalid dutch auction";
+    },
+    {
+      code: 6040;
+      name: "InvalidEnglishAuction";
+      msg: "Invalid english auction";
+    },
+    {
+      code: 6041;
+      name: "AuctionNotStarted";
+      msg: "Auction has not started yet";
+    },
+    {
+      code: 6042;
+      name: "AuctionEnded";
+      msg: "Auction has ended";
+    },
+    {
+      code: 6043;
+      name: "AuctionNotEnded";
+      msg: "Auction has not ended";
+    },
+    {
+      code: 6044;
+      name: "AuctionPriceExceeded";
+      msg: "Auction price is above the max payment amount";
     },
     {
-      name: "WhitelistMarketplacesIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "allowedMarketplaces";
-            type: {
-              vec: "publicKey";
-            };
-          }
-        ];
-      };
-    }
-  ];
-  errors: [
+      code: 6045;
+      name: "BidTooLow";
+      msg: "Bid is below the minimum bid";
+    },
     {
-      code: 6000;
-      name: "InvalidTokenManager";
-      msg: "Invalid token manager for this transfer authority";
+      code: 6046;
+      name: "AuctionHasBids";
+      msg: "Auction already has bids";
     },
     {
-      code: 6001;
-      name: "InvalidLister";
-      msg: "Invalid lister";
+      code: 6047;
+      name: "InvalidBidder";
+      msg: "Invalid bidder";
     },
     {
-      code: 6002;
-      name: "InvalidPaymentMint";
-      msg: "Invalid payment mint";
+      code: 6048;
+      name: "InvalidBidderPaymentTokenAccount";
+      msg: "Invalid bidder payment token account";
     },
     {
-      code: 6003;
-      name: "InvalidMarketplace";
-      msg: "Invalid marketplace";
+      code: 6049;
+      name: "InvalidAuctionTokenAccount";
+      msg: "Invalid auction token account";
     },
     {
-      code: 6004;
-      name: "InvalidBuyerPaymentTokenAccount";
-      msg: "Invalid buyer payment token account";
+      code: 6050;
+      name: "InvalidSettler";
+      msg: "Only the lister or the highest bidder can settle the auction";
     },
     {
-      code: 6005;
-      name: "InvalidBuyerMintTokenAccount";
-      msg: "Invalid buyer mint token account";
+      code: 6051;
+      name: "AuctionSettled";
+      msg: "Auction has been settled";
     },
     {
-      code: 6006;
-      name: "InvalidOfferTokenAccount";
-      msg: "Invalid offer token account";
+      code: 6052;
+      name: "AuctionNotSettled";
+      msg: "Auction has not been settled";
     },
     {
-      code: 6007;
-      name: "InvalidPaymentManager";
-      msg: "Invalid payment manager";
+      code: 6053;
+      name: "AuctionSettleable";
+      msg: "Auction can still be settled";
     },
     {
-      code: 6008;
-      name: "InvalidMint";
-      msg: "Invalid mint";
+      code: 6054;
+      name: "TokenInEnglishAuction";
+      msg: "Token is in an english auction";
     },
     {
-      code: 6009;
-      name: "InvalidFeeCollector";
-      msg: "Invalid fee collector";
+      code: 6059;
+      name: "BuyerNotAllowed";
+      msg: "Buyer is not allowed to accept this listing";
     },
     {
-      code: 6010;
-      name: "InvalidListerPaymentTokenAccount";
-      msg: "Invalid lister payment token account";
+      code: 6056;
+      name: "TokenListed";
+      msg: "Token is listed";
+    }
+  ];
+};
+
+export const IDL: SolanaNftProgramsTransferAuthority = {
+  version: "1.0.0",
+  name: "solana_nft_programs_transfer_authority",
+  instructions: [
+    {
+      name: "initTransferAuthority",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitTransferAuthorityIx",
+          },
+        },
+      ],
     },
     {
-      code: 6011;
-      name: "InvalidListerMintTokenAccount";
-      msg: "Invalid lister mint token account";
+      name: "updateTransferAuthority",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdateTransferAuthorityIx",
+          },
+        },
+      ],
     },
     {
-      code: 6012;
-      name: "InvalidMarketplaceAuthority";
-      msg: "Invalid marketplace authority";
+      name: "whitelistMarketplaces",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "WhitelistMarketplacesIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "release",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holderTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "collector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createListing",
+      accounts: [
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transferAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "englishAuction",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "CreateListingIx",
+          },
+        },
+      ],
     },
     {
-      code: 6013;
-      name: "InvalidTransferAuthorityAuthority";
-      msg: "Invalid transfer authority authority";
+      name: "updateListing",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "englishAuction",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdateListingIx",
+          },
+        },
+      ],
     },
     {
-      code: 6014;
-      name: "InvalidTransferAuthority";
-      msg: "Invalid transfer authority";
+      name: "acceptListing",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transferReceipt",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadataInfo",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeCollector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsPaymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "AcceptListingIx",
+          },
+        },
+      ],
     },
     {
-      code: 6015;
-      name: "MarketplaceNotAllowed";
-      msg: "Marketplace place not allowed by transfer authority";
+      name: "removeListing",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
-    {
-      code: 6016;
-      name: "InvalidHolder";
-      msg: "Invalid token holder";
+    {
+      name: "removeExpiredListing",
+      accounts: [
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transferAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "englishAuction",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6017;
-      name: "InvalidHolderMintTokenAccount";
-      msg: "Invalid holder token account";
+      name: "makeOffer",
+      accounts: [
+        {
+          name: "offer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transferAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "MakeOfferIx",
+          },
+        },
+      ],
     },
     {
-      code: 6018;
-      name: "InvalidTransfer";
-      msg: "Invalid transfer account";
+      name: "cancelOffer",
+      accounts: [
+        {
+          name: "offer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6019;
-      name: "InvalidRecipient";
-      msg: "Invalid recipient";
+      name: "acceptOffer",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transferReceipt",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holderMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holderPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadataInfo",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeCollector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsPaymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "englishAuction",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "AcceptOfferIx",
+          },
+        },
+      ],
     },
     {
-      code: 6020;
-      name: "InvalidRecipientMintTokenAccount";
-      msg: "Invalid recipient mint token account";
+      name: "makeCollectionBid",
+      accounts: [
+        {
+          name: "collectionBid",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionBidEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionBidPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transferAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "MakeCollectionBidIx",
+          },
+        },
+      ],
     },
     {
-      code: 6021;
-      name: "InvalidDerivation";
-      msg: "Invalid derivation";
+      name: "cancelCollectionBid",
+      accounts: [
+        {
+          name: "collectionBid",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionBidEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionBidPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6022;
-      name: "InstructionsDisallowed";
-      msg: "Transaction included disallowed";
+      name: "acceptCollectionBid",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transferReceipt",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionBid",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionBidEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionBidPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holderMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holderPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadataInfo",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeCollector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsPaymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "englishAuction",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "AcceptCollectionBidIx",
+          },
+        },
+      ],
     },
     {
-      code: 6023;
-      name: "TokenNotDelegated";
-      msg: "Token must be delegated";
+      name: "createDutchAuction",
+      accounts: [
+        {
+          name: "dutchAuction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transferAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "CreateDutchAuctionIx",
+          },
+        },
+      ],
     },
     {
-      code: 6024;
-      name: "ListingChanged";
-      msg: "Listing payment amount or mint has changed";
+      name: "acceptDutchAuction",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transferReceipt",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "dutchAuction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadataInfo",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeCollector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsPaymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "englishAuction",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "AcceptDutchAuctionIx",
+          },
+        },
+      ],
     },
     {
-      code: 6025;
-      name: "InvalidRemainingAccountsSize";
-      msg: "Invalid remaining accounts size";
+      name: "removeDutchAuction",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "dutchAuction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6026;
-      name: "InvalidPayerPaymentTokenAccount";
-      msg: "Invalid payer payment token account";
-    }
-  ];
-};
-
-export const IDL: SolanaNftProgramsTransferAuthority = {
-  version: "1.0.0",
-  name: "solana_nft_programs_transfer_authority",
-  instructions: [
-    {
-      name: "initTransferAuthority",
+      name: "createEnglishAuction",
       accounts: [
+        {
+          name: "englishAuction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "englishAuctionEscrow",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "transferAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
         {
           name: "payer",
           isMut: true,
           isSigner: true,
         },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "listing",
+          isMut: false,
+          isSigner: false,
+        },
       ],
       args: [
         {
           name: "ix",
           type: {
-            defined: "InitTransferAuthorityIx",
+            defined: "CreateEnglishAuctionIx",
           },
         },
       ],
     },
     {
-      name: "updateTransferAuthority",
+      name: "placeBid",
       accounts: [
         {
-          name: "transferAuthority",
+          name: "englishAuction",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "authority",
+          name: "englishAuctionEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "englishAuctionPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "bidder",
           isMut: true,
           isSigner: true,
         },
-      ],
-      args: [
         {
-          name: "ix",
-          type: {
-            defined: "UpdateTransferAuthorityIx",
-          },
+          name: "bidderPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
         },
-      ],
-    },
-    {
-      name: "whitelistMarketplaces",
-      accounts: [
         {
-          name: "transferAuthority",
+          name: "previousBidder",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "authority",
+          name: "previousBidderPaymentTokenAccount",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
         },
       ],
       args: [
         {
           name: "ix",
           type: {
-            defined: "WhitelistMarketplacesIx",
+            defined: "PlaceBidIx",
           },
         },
       ],
     },
     {
-      name: "release",
+      name: "settleEnglishAuction",
       accounts: [
         {
           name: "transferAuthority",
-          isMut: false,
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenManager",
+          name: "transferReceipt",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "mint",
+          name: "englishAuction",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenManagerTokenAccount",
+          name: "englishAuctionEscrow",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "holderTokenAccount",
+          name: "englishAuctionPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "holder",
+          name: "englishAuctionMintTokenAccount",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "collector",
+          name: "listerPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "solanaNftProgramsTokenManager",
-          isMut: false,
+          name: "listerMintTokenAccount",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
+          name: "lister",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "rent",
-          isMut: false,
+          name: "bidder",
+          isMut: true,
           isSigner: false,
         },
-      ],
-      args: [],
-    },
-    {
-      name: "createListing",
-      accounts: [
         {
-          name: "listing",
+          name: "bidderMintTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "transferAuthority",
-          isMut: false,
+          name: "bidderPaymentTokenAccount",
+          isMut: true,
           isSigner: false,
         },
+        {
+          name: "settler",
+          isMut: true,
+          isSigner: true,
+        },
         {
           name: "marketplace",
-          isMut: false,
+          isMut: true,
           isSigner: false,
         },
         {
@@ -1227,30 +4422,45 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
           isSigner: false,
         },
         {
-          name: "mintManager",
-          isMut: true,
+          name: "mintMetadataInfo",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "listerTokenAccount",
+          name: "paymentManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "lister",
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "payer",
+          name: "feeCollector",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsPaymentManager",
+          isMut: false,
+          isSigner: false,
         },
         {
           name: "solanaNftProgramsTokenManager",
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "tokenProgram",
           isMut: false,
@@ -1267,17 +4477,10 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
           isSigner: false,
         },
       ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "CreateListingIx",
-          },
-        },
-      ],
+      args: [],
     },
     {
-      name: "updateListing",
+      name: "cancelEnglishAuction",
       accounts: [
         {
           name: "tokenManager",
@@ -1285,7 +4488,7 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
           isSigner: false,
         },
         {
-          name: "listing",
+          name: "englishAuction",
           isMut: true,
           isSigner: false,
         },
@@ -1299,18 +4502,31 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
           isMut: true,
           isSigner: true,
         },
-      ],
-      args: [
         {
-          name: "ix",
-          type: {
-            defined: "UpdateListingIx",
-          },
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "acceptListing",
+      name: "claimEnglishAuction",
       accounts: [
         {
           name: "transferAuthority",
@@ -1323,57 +4539,77 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
           isSigner: false,
         },
         {
-          name: "listing",
+          name: "englishAuction",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "listerPaymentTokenAccount",
+          name: "englishAuctionEscrow",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "listerMintTokenAccount",
+          name: "englishAuctionPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "lister",
+          name: "englishAuctionMintTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "buyerMintTokenAccount",
+          name: "lister",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "buyer",
+          name: "bidder",
           isMut: true,
           isSigner: true,
         },
         {
-          name: "payer",
+          name: "bidderMintTokenAccount",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "payerPaymentTokenAccount",
+          name: "bidderPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "marketplace",
-          isMut: true,
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "tokenManager",
+          name: "listerPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "mint",
+          name: "marketplace",
           isMut: false,
           isSigner: false,
         },
@@ -1407,81 +4643,59 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
           isMut: false,
           isSigner: false,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "closeEnglishAuction",
+      accounts: [
         {
-          name: "solanaNftProgramsTokenManager",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
+          name: "tokenManager",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "instructions",
+          name: "listerMintTokenAccount",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "AcceptListingIx",
-          },
-        },
-      ],
-    },
-    {
-      name: "removeListing",
-      accounts: [
         {
-          name: "tokenManager",
+          name: "englishAuction",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "listing",
+          name: "englishAuctionEscrow",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "listerMintTokenAccount",
+          name: "englishAuctionPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
           name: "lister",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "mint",
-          isMut: false,
+          name: "bidder",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "mintManager",
+          name: "bidderPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "solanaNftProgramsTokenManager",
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
+          name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
@@ -1704,10 +4918,220 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
       ],
       args: [],
     },
-  ],
-  accounts: [
+  ],
+  accounts: [
+    {
+      name: "transferAuthority",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "allowedMarketplaces",
+            type: {
+              option: {
+                vec: "publicKey",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "marketplace",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "paymentManager",
+            type: "publicKey",
+          },
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "paymentMints",
+            type: {
+              option: {
+                vec: "publicKey",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "listing",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "lister",
+            type: "publicKey",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "marketplace",
+            type: "publicKey",
+          },
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "startAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "expiresAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "allowedBuyer",
+            type: {
+              option: "publicKey",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "transfer",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "from",
+            type: "publicKey",
+          },
+          {
+            name: "to",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "offer",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "escrowBump",
+            type: "u8",
+          },
+          {
+            name: "buyer",
+            type: "publicKey",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "marketplace",
+            type: "publicKey",
+          },
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "collectionBid",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "escrowBump",
+            type: "u8",
+          },
+          {
+            name: "buyer",
+            type: "publicKey",
+          },
+          {
+            name: "collection",
+            type: "publicKey",
+          },
+          {
+            name: "transferAuthority",
+            type: "publicKey",
+          },
+          {
+            name: "marketplace",
+            type: "publicKey",
+          },
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
     {
-      name: "transferAuthority",
+      name: "dutchAuction",
       type: {
         kind: "struct",
         fields: [
@@ -1716,26 +5140,42 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             type: "u8",
           },
           {
-            name: "name",
-            type: "string",
+            name: "lister",
+            type: "publicKey",
           },
           {
-            name: "authority",
+            name: "tokenManager",
             type: "publicKey",
           },
           {
-            name: "allowedMarketplaces",
-            type: {
-              option: {
-                vec: "publicKey",
-              },
-            },
+            name: "marketplace",
+            type: "publicKey",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "startAmount",
+            type: "u64",
+          },
+          {
+            name: "floorAmount",
+            type: "u64",
+          },
+          {
+            name: "startAt",
+            type: "i64",
+          },
+          {
+            name: "endAt",
+            type: "i64",
           },
         ],
       },
     },
     {
-      name: "marketplace",
+      name: "englishAuction",
       type: {
         kind: "struct",
         fields: [
@@ -1744,86 +5184,176 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             type: "u8",
           },
           {
-            name: "name",
-            type: "string",
+            name: "escrowBump",
+            type: "u8",
           },
           {
-            name: "paymentManager",
+            name: "lister",
             type: "publicKey",
           },
           {
-            name: "authority",
+            name: "tokenManager",
             type: "publicKey",
           },
           {
-            name: "paymentMints",
+            name: "marketplace",
+            type: "publicKey",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "reserveAmount",
+            type: "u64",
+          },
+          {
+            name: "minIncrement",
+            type: "u64",
+          },
+          {
+            name: "endAt",
+            type: "i64",
+          },
+          {
+            name: "antiSnipeSeconds",
+            type: "i64",
+          },
+          {
+            name: "highestBidder",
             type: {
-              option: {
-                vec: "publicKey",
-              },
+              option: "publicKey",
             },
           },
+          {
+            name: "highestBid",
+            type: "u64",
+          },
+          {
+            name: "highestBidEscrowAmount",
+            type: "u64",
+          },
+          {
+            name: "settled",
+            type: "bool",
+          },
         ],
       },
     },
+  ],
+  types: [
     {
-      name: "listing",
+      name: "AcceptDutchAuctionIx",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "bump",
-            type: "u8",
+            name: "maxPaymentAmount",
+            type: "u64",
           },
+        ],
+      },
+    },
+    {
+      name: "CreateDutchAuctionIx",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "lister",
+            name: "paymentMint",
             type: "publicKey",
           },
           {
-            name: "tokenManager",
-            type: "publicKey",
+            name: "startAmount",
+            type: "u64",
           },
           {
-            name: "marketplace",
-            type: "publicKey",
+            name: "floorAmount",
+            type: "u64",
           },
           {
-            name: "paymentAmount",
-            type: "u64",
+            name: "startAt",
+            type: "i64",
+          },
+          {
+            name: "endAt",
+            type: "i64",
           },
+        ],
+      },
+    },
+    {
+      name: "CreateEnglishAuctionIx",
+      type: {
+        kind: "struct",
+        fields: [
           {
             name: "paymentMint",
             type: "publicKey",
           },
+          {
+            name: "reserveAmount",
+            type: "u64",
+          },
+          {
+            name: "minIncrement",
+            type: "u64",
+          },
+          {
+            name: "endAt",
+            type: "i64",
+          },
+          {
+            name: "antiSnipeSeconds",
+            type: "i64",
+          },
         ],
       },
     },
     {
-      name: "transfer",
+      name: "PlaceBidIx",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "bump",
-            type: "u8",
+            name: "paymentAmount",
+            type: "u64",
           },
+        ],
+      },
+    },
+    {
+      name: "AcceptCollectionBidIx",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "tokenManager",
-            type: "publicKey",
+            name: "paymentAmount",
+            type: "u64",
           },
+        ],
+      },
+    },
+    {
+      name: "MakeCollectionBidIx",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "from",
+            name: "collection",
             type: "publicKey",
           },
           {
-            name: "to",
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
             type: "publicKey",
           },
         ],
       },
     },
-  ],
-  types: [
     {
       name: "AcceptListingIx",
       type: {
@@ -1849,6 +5379,24 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             name: "paymentMint",
             type: "publicKey",
           },
+          {
+            name: "startAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "expiresAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "allowedBuyer",
+            type: {
+              option: "publicKey",
+            },
+          },
         ],
       },
     },
@@ -1869,6 +5417,24 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             name: "paymentMint",
             type: "publicKey",
           },
+          {
+            name: "startAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "expiresAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "allowedBuyer",
+            type: {
+              option: "publicKey",
+            },
+          },
         ],
       },
     },
@@ -1920,6 +5486,34 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
         ],
       },
     },
+    {
+      name: "AcceptOfferIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "MakeOfferIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
     {
       name: "InitTransferIx",
       type: {
@@ -2127,5 +5721,155 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
       name: "InvalidPayerPaymentTokenAccount",
       msg: "Invalid payer payment token account",
     },
+    {
+      code: 6027,
+      name: "InvalidOffer",
+      msg: "Invalid offer",
+    },
+    {
+      code: 6028,
+      name: "OfferChanged",
+      msg: "Offer payment amount has changed",
+    },
+    {
+      code: 6029,
+      name: "InvalidHolderPaymentTokenAccount",
+      msg: "Invalid holder payment token account",
+    },
+    {
+      code: 6030,
+      name: "InvalidCollectionBid",
+      msg: "Invalid collection bid",
+    },
+    {
+      code: 6031,
+      name: "InvalidCollectionBidTokenAccount",
+      msg: "Invalid collection bid token account",
+    },
+    {
+      code: 6032,
+      name: "CollectionBidChanged",
+      msg: "Collection bid payment amount has changed",
+    },
+    {
+      code: 6033,
+      name: "InvalidMintMetadata",
+      msg: "Invalid mint metadata",
+    },
+    {
+      code: 6034,
+      name: "MintNotInCollection",
+      msg: "Mint is not a verified member of the collection",
+    },
+    {
+      code: 6035,
+      name: "InvalidListingSchedule",
+      msg: "Invalid listing start or expiration",
+    },
+    {
+      code: 6036,
+      name: "ListingNotStarted",
+      msg: "Listing has not started yet",
+    },
+    {
+      code: 6037,
+      name: "ListingExpired",
+      msg: "Listing has expired",
+    },
+    {
+      code: 6038,
+      name: "ListingNotExpired",
+      msg: "Listing has not expired",
+    },
+    {
+      code: 6039,
+      name: "InvalidDutchAuction",
+      msg: "Invalid dutch auction",
+    },
+    {
+      code: 6040,
+      name: "InvalidEnglishAuction",
+      msg: "Invalid english auction",
+    },
+    {
+      code: 6041,
+      name: "AuctionNotStarted",
+      msg: "Auction has not started yet",
+    },
+    {
+      code: 6042,
+      name: "AuctionEnded",
+      msg: "Auction has ended",
+    },
+    {
+      code: 6043,
+      name: "AuctionNotEnded",
+      msg: "Auction has not ended",
+    },
+    {
+      code: 6044,
+      name: "AuctionPriceExceeded",
+      msg: "Auction price is above the max payment amount",
+    },
+    {
+      code: 6045,
+      name: "BidTooLow",
+      msg: "Bid is below the minimum bid",
+    },
+    {
+      code: 6046,
+      name: "AuctionHasBids",
+      msg: "Auction already has bids",
+    },
+    {
+      code: 6047,
+      name: "InvalidBidder",
+      msg: "Invalid bidder",
+    },
+    {
+      code: 6048,
+      name: "InvalidBidderPaymentTokenAccount",
+      msg: "Invalid bidder payment token account",
+    },
+    {
+      code: 6049,
+      name: "InvalidAuctionTokenAccount",
+      msg: "Invalid auction token account",
+    },
+    {
+      code: 6050,
+      name: "InvalidSettler",
+      msg: "Only the lister or the highest bidder can settle the auction",
+    },
+    {
+      code: 6051,
+      name: "AuctionSettled",
+      msg: "Auction has been settled",
+    },
+    {
+      code: 6052,
+      name: "AuctionNotSettled",
+      msg: "Auction has not been settled",
+    },
+    {
+      code: 6053,
+      name: "AuctionSettleable",
+      msg: "Auction can still be settled",
+    },
+    {
+      code: 6054,
+      name: "TokenInEnglishAuction",
+      msg: "Token is in an english auction",
+    },
+    {
+      code: 6059,
+      name: "BuyerNotAllowed",
+      msg: "Buyer is not allowed to accept this listing",
+    },
+    {
+      code: 6056,
+      name: "TokenListed",
+      msg: "Token is listed",
+    },
   ],
 };
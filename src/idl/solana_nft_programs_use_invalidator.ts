@@ -71,6 +71,43 @@ export type SolanaNftProgramsUseInvalidator = {
         }
       ];
     },
+    {
+      name: "incrementUsagesWithMemo";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "useInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "numUsages";
+          type: "u64";
+        },
+        {
+          name: "memo";
+          type: {
+            array: ["u8", 32];
+          };
+        }
+      ];
+    },
     {
       name: "extendUsages";
       accounts: [
@@ -118,6 +155,11 @@ export type SolanaNftProgramsUseInvalidator = {
           name: "solanaNftProgramsPaymentManager";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
@@ -127,6 +169,27 @@ export type SolanaNftProgramsUseInvalidator = {
         }
       ];
     },
+    {
+      name: "quoteUsages";
+      accounts: [
+        {
+          name: "useInvalidator";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "usagesToAdd";
+          type: "u64";
+        }
+      ];
+    },
     {
       name: "invalidate";
       accounts: [
@@ -178,6 +241,226 @@ export type SolanaNftProgramsUseInvalidator = {
       ];
       args: [];
     },
+    {
+      name: "batchInvalidate";
+      accounts: [
+        {
+          name: "invalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "BatchInvalidateIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "initUsageEscrow";
+      accounts: [
+        {
+          name: "useInvalidator";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "usageEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrowTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initUsageLog";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "useInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "usageLog";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "releaseEscrow";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "useInvalidator";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "usageEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrowTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "resize";
+      accounts: [
+        {
+          name: "useInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setUseAuthorities";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "useInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "SetUseAuthoritiesIx";
+          };
+        }
+      ];
+    },
     {
       name: "close";
       accounts: [
@@ -212,59 +495,199 @@ export type SolanaNftProgramsUseInvalidator = {
         kind: "struct";
         fields: [
           {
-            name: "bump";
-            type: "u8";
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "usages";
+            type: "u64";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "paymentManager";
+            type: "publicKey";
+          },
+          {
+            name: "collector";
+            type: "publicKey";
+          },
+          {
+            name: "useAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "totalUsages";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "extensionPaymentAmount";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "extensionPaymentMint";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "extensionUsages";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "maxUsages";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "escrowExtensionPayments";
+            type: {
+              option: "bool";
+            };
+          },
+          {
+            name: "usagePriceTiers";
+            type: {
+              option: {
+                vec: {
+                  defined: "UsagePriceTier";
+                };
+              };
+            };
+          },
+          {
+            name: "minPricePerUsage";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "usages";
-            type: "u64";
+            name: "maxUsagesPerPeriod";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "tokenManager";
-            type: "publicKey";
+            name: "periodSeconds";
+            type: {
+              option: "i64";
+            };
           },
           {
-            name: "paymentManager";
-            type: "publicKey";
+            name: "periodStart";
+            type: {
+              option: "i64";
+            };
           },
           {
-            name: "collector";
-            type: "publicKey";
+            name: "periodUsages";
+            type: "u64";
           },
           {
-            name: "useAuthority";
+            name: "useAuthorities";
             type: {
-              option: "publicKey";
+              option: {
+                vec: {
+                  defined: "UseAuthority";
+                };
+              };
             };
           },
           {
-            name: "totalUsages";
+            name: "usagesResetSeconds";
             type: {
-              option: "u64";
+              option: "i64";
             };
           },
           {
-            name: "extensionPaymentAmount";
+            name: "usagesPeriodStart";
             type: {
-              option: "u64";
+              option: "i64";
             };
           },
           {
-            name: "extensionPaymentMint";
+            name: "usageLog";
             type: {
               option: "publicKey";
             };
+          }
+        ];
+      };
+    },
+    {
+      name: "usageEscrow";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
           },
           {
-            name: "extensionUsages";
-            type: {
-              option: "u64";
-            };
+            name: "useInvalidator";
+            type: "publicKey";
           },
           {
-            name: "maxUsages";
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "paymentTokenAccount";
+            type: "publicKey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "usages";
+            type: "u64";
+          },
+          {
+            name: "paymentManager";
+            type: "publicKey";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "usageLog";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "useInvalidator";
+            type: "publicKey";
+          },
+          {
+            name: "totalEntries";
+            type: "u64";
+          },
+          {
+            name: "entries";
             type: {
-              option: "u64";
+              vec: {
+                defined: "UsageLogEntry";
+              };
             };
           }
         ];
@@ -272,6 +695,18 @@ export type SolanaNftProgramsUseInvalidator = {
     }
   ];
   types: [
+    {
+      name: "BatchInvalidateIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "remainingAccountsLengths";
+            type: "bytes";
+          }
+        ];
+      };
+    },
     {
       name: "InitIx";
       type: {
@@ -320,6 +755,160 @@ export type SolanaNftProgramsUseInvalidator = {
             type: {
               option: "u64";
             };
+          },
+          {
+            name: "escrowExtensionPayments";
+            type: {
+              option: "bool";
+            };
+          },
+          {
+            name: "usagePriceTiers";
+            type: {
+              option: {
+                vec: {
+                  defined: "UsagePriceTier";
+                };
+              };
+            };
+          },
+          {
+            name: "minPricePerUsage";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "maxUsagesPerPeriod";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "periodSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "useAuthorities";
+            type: {
+              option: {
+                vec: {
+                  defined: "UseAuthorityQuota";
+                };
+              };
+            };
+          },
+          {
+            name: "usagesResetSeconds";
+            type: {
+              option: "i64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "SetUseAuthoritiesIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "useAuthorities";
+            type: {
+              vec: {
+                defined: "UseAuthorityQuota";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "UsagePriceTier";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "minUsages";
+            type: "u64";
+          },
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
+          {
+            name: "extensionUsages";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "UseAuthority";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "quota";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "usages";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "UseAuthorityQuota";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "quota";
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "UsageLogEntry";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "count";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          },
+          {
+            name: "memo";
+            type: {
+              option: {
+                array: ["u8", 32];
+              };
+            };
           }
         ];
       };
@@ -405,6 +994,41 @@ export type SolanaNftProgramsUseInvalidator = {
       code: 6015;
       name: "InvalidIssuer";
       msg: "Invalid issuer";
+    },
+    {
+      code: 6016;
+      name: "InvalidUsageEscrow";
+      msg: "Invalid usage escrow";
+    },
+    {
+      code: 6017;
+      name: "InvalidEscrowTokenAccount";
+      msg: "Invalid escrow token account";
+    },
+    {
+      code: 6018;
+      name: "InvalidUsagePriceTiers";
+      msg: "Invalid usage price tiers";
+    },
+    {
+      code: 6019;
+      name: "UsagePeriodLimitReached";
+      msg: "Usages for this period exceeded";
+    },
+    {
+      code: 6020;
+      name: "InvalidUsageLog";
+      msg: "Invalid usage log";
+    },
+    {
+      code: 6021;
+      name: "InvalidUseAuthorities";
+      msg: "Invalid use authorities";
+    },
+    {
+      code: 6022;
+      name: "UseAuthorityQuotaExceeded";
+      msg: "Use authority quota exceeded";
     }
   ];
 };
@@ -414,7 +1038,45 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
   name: "solana_nft_programs_use_invalidator",
   instructions: [
     {
-      name: "init",
+      name: "init",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "useInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "incrementUsages",
       accounts: [
         {
           name: "tokenManager",
@@ -427,32 +1089,25 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
           isSigner: false,
         },
         {
-          name: "issuer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "recipientTokenAccount",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "user",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
         },
       ],
       args: [
         {
-          name: "ix",
-          type: {
-            defined: "InitIx",
-          },
+          name: "numUsages",
+          type: "u64",
         },
       ],
     },
     {
-      name: "incrementUsages",
+      name: "incrementUsagesWithMemo",
       accounts: [
         {
           name: "tokenManager",
@@ -480,6 +1135,12 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
           name: "numUsages",
           type: "u64",
         },
+        {
+          name: "memo",
+          type: {
+            array: ["u8", 32],
+          },
+        },
       ],
     },
     {
@@ -530,6 +1191,11 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
       ],
       args: [
         {
@@ -538,6 +1204,27 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
         },
       ],
     },
+    {
+      name: "quoteUsages",
+      accounts: [
+        {
+          name: "useInvalidator",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "usagesToAdd",
+          type: "u64",
+        },
+      ],
+    },
     {
       name: "invalidate",
       accounts: [
@@ -567,27 +1254,247 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
           isSigner: false,
         },
         {
-          name: "tokenManagerTokenAccount",
-          isMut: true,
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "batchInvalidate",
+      accounts: [
+        {
+          name: "invalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "BatchInvalidateIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "initUsageEscrow",
+      accounts: [
+        {
+          name: "useInvalidator",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "usageEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initUsageLog",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "useInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "usageLog",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "releaseEscrow",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "useInvalidator",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "usageEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "resize",
+      accounts: [
+        {
+          name: "useInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setUseAuthorities",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "mint",
+          name: "useInvalidator",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "recipientTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "issuer",
+          isMut: false,
+          isSigner: true,
         },
+      ],
+      args: [
         {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
+          name: "ix",
+          type: {
+            defined: "SetUseAuthoritiesIx",
+          },
         },
       ],
-      args: [],
     },
     {
       name: "close",
@@ -678,11 +1585,163 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
               option: "u64",
             },
           },
+          {
+            name: "escrowExtensionPayments",
+            type: {
+              option: "bool",
+            },
+          },
+          {
+            name: "usagePriceTiers",
+            type: {
+              option: {
+                vec: {
+                  defined: "UsagePriceTier",
+                },
+              },
+            },
+          },
+          {
+            name: "minPricePerUsage",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "maxUsagesPerPeriod",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "periodSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "periodStart",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "periodUsages",
+            type: "u64",
+          },
+          {
+            name: "useAuthorities",
+            type: {
+              option: {
+                vec: {
+                  defined: "UseAuthority",
+                },
+              },
+            },
+          },
+          {
+            name: "usagesResetSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "usagesPeriodStart",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "usageLog",
+            type: {
+              option: "publicKey",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "usageEscrow",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "useInvalidator",
+            type: "publicKey",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "paymentTokenAccount",
+            type: "publicKey",
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "usages",
+            type: "u64",
+          },
+          {
+            name: "paymentManager",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "usageLog",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "useInvalidator",
+            type: "publicKey",
+          },
+          {
+            name: "totalEntries",
+            type: "u64",
+          },
+          {
+            name: "entries",
+            type: {
+              vec: {
+                defined: "UsageLogEntry",
+              },
+            },
+          },
         ],
       },
     },
   ],
   types: [
+    {
+      name: "BatchInvalidateIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "remainingAccountsLengths",
+            type: "bytes",
+          },
+        ],
+      },
+    },
     {
       name: "InitIx",
       type: {
@@ -732,6 +1791,160 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
               option: "u64",
             },
           },
+          {
+            name: "escrowExtensionPayments",
+            type: {
+              option: "bool",
+            },
+          },
+          {
+            name: "usagePriceTiers",
+            type: {
+              option: {
+                vec: {
+                  defined: "UsagePriceTier",
+                },
+              },
+            },
+          },
+          {
+            name: "minPricePerUsage",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "maxUsagesPerPeriod",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "periodSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "useAuthorities",
+            type: {
+              option: {
+                vec: {
+                  defined: "UseAuthorityQuota",
+                },
+              },
+            },
+          },
+          {
+            name: "usagesResetSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetUseAuthoritiesIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "useAuthorities",
+            type: {
+              vec: {
+                defined: "UseAuthorityQuota",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "UsagePriceTier",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "minUsages",
+            type: "u64",
+          },
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "extensionUsages",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "UseAuthority",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "quota",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "usages",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "UseAuthorityQuota",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "quota",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "UsageLogEntry",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "count",
+            type: "u64",
+          },
+          {
+            name: "timestamp",
+            type: "i64",
+          },
+          {
+            name: "memo",
+            type: {
+              option: {
+                array: ["u8", 32],
+              },
+            },
+          },
         ],
       },
     },
@@ -817,5 +2030,40 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
       name: "InvalidIssuer",
       msg: "Invalid issuer",
     },
+    {
+      code: 6016,
+      name: "InvalidUsageEscrow",
+      msg: "Invalid usage escrow",
+    },
+    {
+      code: 6017,
+      name: "InvalidEscrowTokenAccount",
+      msg: "Invalid escrow token account",
+    },
+    {
+      code: 6018,
+      name: "InvalidUsagePriceTiers",
+      msg: "Invalid usage price tiers",
+    },
+    {
+      code: 6019,
+      name: "UsagePeriodLimitReached",
+      msg: "Usages for this period exceeded",
+    },
+    {
+      code: 6020,
+      name: "InvalidUsageLog",
+      msg: "Invalid usage log",
+    },
+    {
+      code: 6021,
+      name: "InvalidUseAuthorities",
+      msg: "Invalid use authorities",
+    },
+    {
+      code: 6022,
+      name: "UseAuthorityQuotaExceeded",
+      msg: "Use authority quota exceeded",
+    },
   ],
 };
@@ -24,7 +24,7 @@ import { PAYMENT_MANAGER_ADDRESS } from "@solana-nft-programs/payment-manager";
 import { getPaymentManager } from "@solana-nft-programs/payment-manager/dist/cjs/accounts";
 import { findPaymentManagerAddress } from "@solana-nft-programs/payment-manager/dist/cjs/pda";
 import { withRemainingAccountsForHandlePaymentWithRoyalties } from "@solana-nft-programs/payment-manager/dist/cjs/utils";
-import type BN from "bn.js";
+import BN from "bn.js";
 
 import {
   getRemainingAccountsForKind,
@@ -45,12 +45,23 @@ import {
   WSOL_MINT,
 } from "./programs/transferAuthority";
 import {
+  getCollectionBid,
+  getDutchAuction,
+  getEnglishAuction,
   getListing,
   getMarketplace,
+  getOffer,
 } from "./programs/transferAuthority/accounts";
 import {
+  findCollectionBidAddress,
+  findCollectionBidEscrowAddress,
+  findDutchAuctionAddress,
+  findEnglishAuctionAddress,
+  findEnglishAuctionEscrowAddress,
   findListingAddress,
   findMarketplaceAddress,
+  findOfferAddress,
+  findOfferEscrowAddress,
   findTransferAddress,
   findTransferAuthorityAddress,
 } from "./programs/transferAuthority/pda";
@@ -244,6 +255,14 @@ export const withUpdateMarketplace = async (
   return transaction;
 };
 
+export type ListingParams = {
+  // unix timestamps, the listing can be accepted from startAt until expiresAt
+  startAt?: number;
+  expiresAt?: number;
+  // only this buyer can accept the listing when set
+  allowedBuyer?: PublicKey;
+};
+
 export const withCreateListing = async (
   transaction: Transaction,
   connection: Connection,
@@ -252,7 +271,8 @@ export const withCreateListing = async (
   markeptlaceName: string,
   paymentAmount: BN,
   paymentMint = PublicKey.default,
-  payer = wallet.publicKey
+  payer = wallet.publicKey,
+  listingParams: ListingParams = {}
 ): Promise<[Transaction, PublicKey]> => {
   const transferAuthProgram = transferAuthorityProgram(connection, wallet);
   const listingId = findListingAddress(mintId);
@@ -280,7 +300,8 @@ export const withCreateListing = async (
         mintId,
         marketplaceId,
         paymentAmount,
-        paymentMint
+        paymentMint,
+        listingParams
       )
     );
   } else {
@@ -289,6 +310,11 @@ export const withCreateListing = async (
       .createListing({
         paymentAmount: paymentAmount,
         paymentMint: paymentMint,
+        startAt: listingParams.startAt ? new BN(listingParams.startAt) : null,
+        expiresAt: listingParams.expiresAt
+          ? new BN(listingParams.expiresAt)
+          : null,
+        allowedBuyer: listingParams.allowedBuyer ?? null,
       })
       .accounts({
         listing: listingId,
@@ -304,6 +330,7 @@ export const withCreateListing = async (
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        englishAuction: findEnglishAuctionAddress(mintId),
       })
       .instruction();
     transaction.add(createListingIx);
@@ -319,7 +346,8 @@ export const withUpdateListing = async (
   mintId: PublicKey,
   marketplaceId: PublicKey,
   paymentAmount: BN,
-  paymentMint: PublicKey
+  paymentMint: PublicKey,
+  listingParams: ListingParams = {}
 ): Promise<Transaction> => {
   const transferAuthProgram = transferAuthorityProgram(connection, wallet);
   const listingData = await tryGetAccount(() => getListing(connection, mintId));
@@ -338,12 +366,18 @@ export const withUpdateListing = async (
       marketplace: marketplaceId,
       paymentAmount: paymentAmount,
       paymentMint: paymentMint,
+      startAt: listingParams.startAt ? new BN(listingParams.startAt) : null,
+      expiresAt: listingParams.expiresAt
+        ? new BN(listingParams.expiresAt)
+        : null,
+      allowedBuyer: listingParams.allowedBuyer ?? null,
     })
     .accounts({
       tokenManager: listingData.parsed.tokenManager,
       listing: listingId,
       listerMintTokenAccount: listerMintTokenAccountId,
       lister: wallet.publicKey,
+      englishAuction: findEnglishAuctionAddress(mintId),
     })
     .instruction();
   transaction.add(updateListingIx);
@@ -379,6 +413,46 @@ export const withRemoveListing = async (
   return transaction;
 };
 
+export const withRemoveExpiredListing = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const listingData = await tryGetAccount(() => getListing(connection, mintId));
+  if (!listingData?.parsed) {
+    throw `No listing found for mint address ${mintId.toString()}`;
+  }
+
+  // the token manager may already be closed, it is only read while claimed
+  const tokenManagerId = listingData.parsed.tokenManager;
+  const tokenManagerData = await tryGetAccount(() =>
+    getTokenManager(connection, tokenManagerId)
+  );
+
+  const removeExpiredListingIx = await transferAuthProgram.methods
+    .removeExpiredListing()
+    .accounts({
+      listing: listingData.pubkey,
+      lister: listingData.parsed.lister,
+      transferAuthority:
+        tokenManagerData?.parsed.transferAuthority ?? tokenManagerId,
+      tokenManager: tokenManagerId,
+      tokenManagerTokenAccount: await findAta(mintId, tokenManagerId, true),
+      listerMintTokenAccount:
+        tokenManagerData?.parsed.recipientTokenAccount ?? tokenManagerId,
+      englishAuction: findEnglishAuctionAddress(mintId),
+      mint: mintId,
+      mintManager: findMintManagerId(mintId),
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  transaction.add(removeExpiredListingIx);
+  return transaction;
+};
+
 export const withAcceptListing = async (
   transaction: Transaction,
   connection: Connection,
@@ -749,3 +823,1099 @@ export const withRelease = async (
   transaction.add(releaseIx);
   return transaction;
 };
+
+export const withMakeOffer = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  marketplaceName: string,
+  paymentAmount: BN,
+  paymentMint = PublicKey.default
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const tokenManagerData = await tryGetAccount(() =>
+    getTokenManager(connection, tokenManagerId)
+  );
+  if (!tokenManagerData?.parsed.transferAuthority) {
+    throw `No transfer authority found for mint id ${mintId.toString()}`;
+  }
+  const marketplaceId = findMarketplaceAddress(marketplaceName);
+  const marketplaceData = await getMarketplace(connection, marketplaceId);
+  const offerId = findOfferAddress(tokenManagerId, wallet.publicKey);
+  const offerEscrowId = findOfferEscrowAddress(offerId);
+  const nativePayment = paymentMint.equals(PublicKey.default);
+
+  const offerPaymentTokenAccountId = nativePayment
+    ? offerEscrowId
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        offerEscrowId,
+        wallet.publicKey,
+        true
+      );
+  const buyerPaymentTokenAccountId = nativePayment
+    ? wallet.publicKey
+    : await findAta(paymentMint, wallet.publicKey, true);
+
+  const makeOfferIx = await transferAuthProgram.methods
+    .makeOffer({
+      paymentAmount: paymentAmount,
+      paymentMint: paymentMint,
+    })
+    .accounts({
+      offer: offerId,
+      offerEscrow: offerEscrowId,
+      offerPaymentTokenAccount: offerPaymentTokenAccountId,
+      transferAuthority: tokenManagerData.parsed.transferAuthority,
+      marketplace: marketplaceId,
+      paymentManager: marketplaceData.parsed.paymentManager,
+      tokenManager: tokenManagerId,
+      buyer: wallet.publicKey,
+      buyerPaymentTokenAccount: buyerPaymentTokenAccountId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(makeOfferIx);
+  return transaction;
+};
+
+export const withCancelOffer = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const offerId = findOfferAddress(tokenManagerId, wallet.publicKey);
+  const offerData = await tryGetAccount(() => getOffer(connection, offerId));
+  if (!offerData?.parsed) {
+    throw `No offer found for mint id ${mintId.toString()}`;
+  }
+  const offerEscrowId = findOfferEscrowAddress(offerId);
+  const nativePayment = offerData.parsed.paymentMint.equals(PublicKey.default);
+
+  const cancelOfferIx = await transferAuthProgram.methods
+    .cancelOffer()
+    .accounts({
+      offer: offerId,
+      offerEscrow: offerEscrowId,
+      offerPaymentTokenAccount: nativePayment
+        ? offerEscrowId
+        : await findAta(offerData.parsed.paymentMint, offerEscrowId, true),
+      buyer: wallet.publicKey,
+      buyerPaymentTokenAccount: nativePayment
+        ? wallet.publicKey
+        : await withFindOrInitAssociatedTokenAccount(
+            transaction,
+            connection,
+            offerData.parsed.paymentMint,
+            wallet.publicKey,
+            wallet.publicKey
+          ),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(cancelOfferIx);
+  return transaction;
+};
+
+export const withAcceptOffer = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  buyer: PublicKey,
+  paymentAmount: BN,
+  buySideReceiver?: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const offerId = findOfferAddress(tokenManagerId, buyer);
+  const offerData = await tryGetAccount(() => getOffer(connection, offerId));
+  if (!offerData?.parsed) {
+    throw `No offer found for mint id ${mintId.toString()}`;
+  }
+  if (!paymentAmount.eq(offerData.parsed.paymentAmount)) {
+    throw "Offer data does not match expected values";
+  }
+  const tokenManagerData = await getTokenManager(connection, tokenManagerId);
+  if (!tokenManagerData.parsed.transferAuthority) {
+    throw `No transfer authority for token manager`;
+  }
+  const marketplaceData = await getMarketplace(
+    connection,
+    offerData.parsed.marketplace
+  );
+  const paymentManagerData = await tryGetAccount(() =>
+    getPaymentManager(connection, marketplaceData.parsed.paymentManager)
+  );
+  if (!paymentManagerData?.parsed) {
+    throw `No payment manager found for marketplace with name ${marketplaceData.parsed.name}`;
+  }
+  const paymentMint = offerData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+  const offerEscrowId = findOfferEscrowAddress(offerId);
+
+  const holderPaymentTokenAccountId = nativePayment
+    ? wallet.publicKey
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        wallet.publicKey,
+        wallet.publicKey
+      );
+  const feeCollectorTokenAccountId = nativePayment
+    ? paymentManagerData.parsed.feeCollector
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        paymentManagerData.parsed.feeCollector,
+        wallet.publicKey,
+        true
+      );
+  const buyerMintTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    mintId,
+    buyer,
+    buyer,
+    true
+  );
+  const buyerPaymentTokenAccountId = nativePayment
+    ? buyer
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        buyer,
+        buyer,
+        true
+      );
+
+  const remainingAccountsForHandlePaymentWithRoyalties =
+    await withRemainingAccountsForHandlePaymentWithRoyalties(
+      transaction,
+      connection,
+      wallet,
+      mintId,
+      paymentMint,
+      buySideReceiver,
+      [wallet.publicKey.toString(), buyer.toString()]
+    );
+
+  const acceptOfferIx = await transferAuthProgram.methods
+    .acceptOffer({
+      paymentAmount: paymentAmount,
+    })
+    .accounts({
+      transferAuthority: tokenManagerData.parsed.transferAuthority,
+      transferReceipt: findTransferReceiptId(tokenManagerId),
+      offer: offerId,
+      offerEscrow: offerEscrowId,
+      offerPaymentTokenAccount: nativePayment
+        ? offerEscrowId
+        : await findAta(paymentMint, offerEscrowId, true),
+      holderMintTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      holderPaymentTokenAccount: holderPaymentTokenAccountId,
+      holder: wallet.publicKey,
+      buyer: buyer,
+      buyerMintTokenAccount: buyerMintTokenAccountId,
+      buyerPaymentTokenAccount: buyerPaymentTokenAccountId,
+      marketplace: offerData.parsed.marketplace,
+      tokenManager: tokenManagerId,
+      englishAuction: findEnglishAuctionAddress(mintId),
+      mint: mintId,
+      mintMetadataInfo: findMintMetadataId(mintId),
+      paymentManager: marketplaceData.parsed.paymentManager,
+      paymentMint: paymentMint,
+      feeCollectorTokenAccount: feeCollectorTokenAccountId,
+      feeCollector: paymentManagerData.parsed.feeCollector,
+      solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    })
+    .remainingAccounts([
+      ...remainingAccountsForHandlePaymentWithRoyalties,
+      ...getRemainingAccountsForKind(mintId, tokenManagerData.parsed.kind),
+    ])
+    .instruction();
+  transaction.add(acceptOfferIx);
+  return transaction;
+};
+
+export const withMakeCollectionBid = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  transferAuthorityName: string,
+  marketplaceName: string,
+  collection: PublicKey,
+  paymentAmount: BN,
+  paymentMint = PublicKey.default
+): Promise<[Transaction, PublicKey]> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const transferAuthorityId = findTransferAuthorityAddress(
+    transferAuthorityName
+  );
+  const marketplaceId = findMarketplaceAddress(marketplaceName);
+  const marketplaceData = await getMarketplace(connection, marketplaceId);
+  const collectionBidId = findCollectionBidAddress(
+    transferAuthorityId,
+    collection,
+    wallet.publicKey
+  );
+  const collectionBidEscrowId = findCollectionBidEscrowAddress(collectionBidId);
+  const nativePayment = paymentMint.equals(PublicKey.default);
+
+  const collectionBidPaymentTokenAccountId = nativePayment
+    ? collectionBidEscrowId
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        collectionBidEscrowId,
+        wallet.publicKey,
+        true
+      );
+
+  const makeCollectionBidIx = await transferAuthProgram.methods
+    .makeCollectionBid({
+      collection: collection,
+      paymentAmount: paymentAmount,
+      paymentMint: paymentMint,
+    })
+    .accounts({
+      collectionBid: collectionBidId,
+      collectionBidEscrow: collectionBidEscrowId,
+      collectionBidPaymentTokenAccount: collectionBidPaymentTokenAccountId,
+      transferAuthority: transferAuthorityId,
+      marketplace: marketplaceId,
+      paymentManager: marketplaceData.parsed.paymentManager,
+      buyer: wallet.publicKey,
+      buyerPaymentTokenAccount: nativePayment
+        ? wallet.publicKey
+        : await findAta(paymentMint, wallet.publicKey, true),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(makeCollectionBidIx);
+  return [transaction, collectionBidId];
+};
+
+export const withCancelCollectionBid = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  collectionBidId: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const collectionBidData = await getCollectionBid(connection, collectionBidId);
+  const collectionBidEscrowId = findCollectionBidEscrowAddress(collectionBidId);
+  const paymentMint = collectionBidData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+
+  const cancelCollectionBidIx = await transferAuthProgram.methods
+    .cancelCollectionBid()
+    .accounts({
+      collectionBid: collectionBidId,
+      collectionBidEscrow: collectionBidEscrowId,
+      collectionBidPaymentTokenAccount: nativePayment
+        ? collectionBidEscrowId
+        : await findAta(paymentMint, collectionBidEscrowId, true),
+      buyer: wallet.publicKey,
+      buyerPaymentTokenAccount: nativePayment
+        ? wallet.publicKey
+        : await withFindOrInitAssociatedTokenAccount(
+            transaction,
+            connection,
+            paymentMint,
+            wallet.publicKey,
+            wallet.publicKey
+          ),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(cancelCollectionBidIx);
+  return transaction;
+};
+
+export const withAcceptCollectionBid = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  collectionBidId: PublicKey,
+  paymentAmount: BN,
+  buySideReceiver?: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const collectionBidData = await getCollectionBid(connection, collectionBidId);
+  if (!paymentAmount.eq(collectionBidData.parsed.paymentAmount)) {
+    throw "Collection bid data does not match expected values";
+  }
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const tokenManagerData = await getTokenManager(connection, tokenManagerId);
+  const marketplaceData = await getMarketplace(
+    connection,
+    collectionBidData.parsed.marketplace
+  );
+  const paymentManagerData = await tryGetAccount(() =>
+    getPaymentManager(connection, marketplaceData.parsed.paymentManager)
+  );
+  if (!paymentManagerData?.parsed) {
+    throw `No payment manager found for marketplace with name ${marketplaceData.parsed.name}`;
+  }
+  const paymentMint = collectionBidData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+  const collectionBidEscrowId = findCollectionBidEscrowAddress(collectionBidId);
+  const buyer = collectionBidData.parsed.buyer;
+
+  const holderPaymentTokenAccountId = nativePayment
+    ? wallet.publicKey
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        wallet.publicKey,
+        wallet.publicKey
+      );
+  const feeCollectorTokenAccountId = nativePayment
+    ? paymentManagerData.parsed.feeCollector
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        paymentManagerData.parsed.feeCollector,
+        wallet.publicKey,
+        true
+      );
+  const buyerMintTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    mintId,
+    buyer,
+    buyer,
+    true
+  );
+  const buyerPaymentTokenAccountId = nativePayment
+    ? buyer
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        buyer,
+        buyer,
+        true
+      );
+
+  const remainingAccountsForHandlePaymentWithRoyalties =
+    await withRemainingAccountsForHandlePaymentWithRoyalties(
+      transaction,
+      connection,
+      wallet,
+      mintId,
+      paymentMint,
+      buySideReceiver,
+      [wallet.publicKey.toString(), buyer.toString()]
+    );
+
+  const acceptCollectionBidIx = await transferAuthProgram.methods
+    .acceptCollectionBid({
+      paymentAmount: paymentAmount,
+    })
+    .accounts({
+      transferAuthority: collectionBidData.parsed.transferAuthority,
+      transferReceipt: findTransferReceiptId(tokenManagerId),
+      collectionBid: collectionBidId,
+      collectionBidEscrow: collectionBidEscrowId,
+      collectionBidPaymentTokenAccount: nativePayment
+        ? collectionBidEscrowId
+        : await findAta(paymentMint, collectionBidEscrowId, true),
+      holderMintTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      holderPaymentTokenAccount: holderPaymentTokenAccountId,
+      holder: wallet.publicKey,
+      buyer: buyer,
+      buyerMintTokenAccount: buyerMintTokenAccountId,
+      buyerPaymentTokenAccount: buyerPaymentTokenAccountId,
+      marketplace: collectionBidData.parsed.marketplace,
+      tokenManager: tokenManagerId,
+      englishAuction: findEnglishAuctionAddress(mintId),
+      mint: mintId,
+      mintMetadataInfo: findMintMetadataId(mintId),
+      paymentManager: marketplaceData.parsed.paymentManager,
+      paymentMint: paymentMint,
+      feeCollectorTokenAccount: feeCollectorTokenAccountId,
+      feeCollector: paymentManagerData.parsed.feeCollector,
+      solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    })
+    .remainingAccounts([
+      ...remainingAccountsForHandlePaymentWithRoyalties,
+      ...getRemainingAccountsForKind(mintId, tokenManagerData.parsed.kind),
+    ])
+    .instruction();
+  transaction.add(acceptCollectionBidIx);
+  return transaction;
+};
+
+export type DutchAuctionParams = {
+  // unix timestamps, the price decays linearly from startAmount to floorAmount
+  startAmount: BN;
+  floorAmount: BN;
+  startAt: number;
+  endAt: number;
+};
+
+export const withCreateDutchAuction = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  marketplaceName: string,
+  dutchAuctionParams: DutchAuctionParams,
+  paymentMint = PublicKey.default,
+  payer = wallet.publicKey
+): Promise<[Transaction, PublicKey]> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const tokenManagerData = await tryGetAccount(() =>
+    getTokenManager(connection, tokenManagerId)
+  );
+  if (!tokenManagerData?.parsed.transferAuthority) {
+    throw `No transfer authority found for mint id ${mintId.toString()}`;
+  }
+  const dutchAuctionId = findDutchAuctionAddress(mintId);
+
+  const createDutchAuctionIx = await transferAuthProgram.methods
+    .createDutchAuction({
+      paymentMint: paymentMint,
+      startAmount: dutchAuctionParams.startAmount,
+      floorAmount: dutchAuctionParams.floorAmount,
+      startAt: new BN(dutchAuctionParams.startAt),
+      endAt: new BN(dutchAuctionParams.endAt),
+    })
+    .accounts({
+      dutchAuction: dutchAuctionId,
+      transferAuthority: tokenManagerData.parsed.transferAuthority,
+      marketplace: findMarketplaceAddress(marketplaceName),
+      tokenManager: tokenManagerId,
+      mint: mintId,
+      mintManager: findMintManagerId(mintId),
+      listerTokenAccount: await findAta(mintId, wallet.publicKey, true),
+      lister: wallet.publicKey,
+      payer: payer,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    })
+    .instruction();
+  transaction.add(createDutchAuctionIx);
+  return [transaction, dutchAuctionId];
+};
+
+export const withAcceptDutchAuction = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  maxPaymentAmount: BN,
+  buySideReceiver?: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const dutchAuctionData = await tryGetAccount(() =>
+    getDutchAuction(connection, mintId)
+  );
+  if (!dutchAuctionData?.parsed) {
+    throw `No dutch auction found for mint id ${mintId.toString()}`;
+  }
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const tokenManagerData = await getTokenManager(connection, tokenManagerId);
+  if (!tokenManagerData.parsed.transferAuthority) {
+    throw `No transfer authority for token manager`;
+  }
+  const marketplaceData = await getMarketplace(
+    connection,
+    dutchAuctionData.parsed.marketplace
+  );
+  const paymentManagerData = await tryGetAccount(() =>
+    getPaymentManager(connection, marketplaceData.parsed.paymentManager)
+  );
+  if (!paymentManagerData?.parsed) {
+    throw `No payment manager found for marketplace with name ${marketplaceData.parsed.name}`;
+  }
+  const lister = dutchAuctionData.parsed.lister;
+  const paymentMint = dutchAuctionData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+
+  const listerPaymentTokenAccountId = nativePayment
+    ? lister
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        lister,
+        wallet.publicKey
+      );
+  const feeCollectorTokenAccountId = nativePayment
+    ? paymentManagerData.parsed.feeCollector
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        paymentManagerData.parsed.feeCollector,
+        wallet.publicKey,
+        true
+      );
+  if (paymentMint.equals(WSOL_MINT)) {
+    await withWrapSol(
+      transaction,
+      connection,
+      wallet,
+      maxPaymentAmount.toNumber(),
+      true
+    );
+  }
+  const buyerMintTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    mintId,
+    wallet.publicKey,
+    wallet.publicKey,
+    true
+  );
+
+  const remainingAccountsForHandlePaymentWithRoyalties =
+    await withRemainingAccountsForHandlePaymentWithRoyalties(
+      transaction,
+      connection,
+      wallet,
+      mintId,
+      paymentMint,
+      buySideReceiver,
+      [lister.toString(), wallet.publicKey.toString()]
+    );
+
+  const acceptDutchAuctionIx = await transferAuthProgram.methods
+    .acceptDutchAuction({
+      maxPaymentAmount: maxPaymentAmount,
+    })
+    .accounts({
+      transferAuthority: tokenManagerData.parsed.transferAuthority,
+      transferReceipt: findTransferReceiptId(tokenManagerId),
+      dutchAuction: dutchAuctionData.pubkey,
+      listerPaymentTokenAccount: listerPaymentTokenAccountId,
+      listerMintTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      lister: lister,
+      buyerMintTokenAccount: buyerMintTokenAccountId,
+      buyer: wallet.publicKey,
+      payer: wallet.publicKey,
+      payerPaymentTokenAccount: nativePayment
+        ? wallet.publicKey
+        : await findAta(paymentMint, wallet.publicKey, true),
+      marketplace: dutchAuctionData.parsed.marketplace,
+      tokenManager: tokenManagerId,
+      englishAuction: findEnglishAuctionAddress(mintId),
+      mint: mintId,
+      mintMetadataInfo: findMintMetadataId(mintId),
+      paymentManager: marketplaceData.parsed.paymentManager,
+      paymentMint: paymentMint,
+      feeCollectorTokenAccount: feeCollectorTokenAccountId,
+      feeCollector: paymentManagerData.parsed.feeCollector,
+      solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    })
+    .remainingAccounts([
+      ...remainingAccountsForHandlePaymentWithRoyalties,
+      ...getRemainingAccountsForKind(mintId, tokenManagerData.parsed.kind),
+    ])
+    .instruction();
+  transaction.add(acceptDutchAuctionIx);
+  return transaction;
+};
+
+export const withRemoveDutchAuction = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+
+  const removeDutchAuctionIx = await transferAuthProgram.methods
+    .removeDutchAuction()
+    .accounts({
+      tokenManager: findTokenManagerAddress(mintId),
+      dutchAuction: findDutchAuctionAddress(mintId),
+      listerMintTokenAccount: await findAta(mintId, wallet.publicKey, true),
+      lister: wallet.publicKey,
+      mint: mintId,
+      mintManager: findMintManagerId(mintId),
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  transaction.add(removeDutchAuctionIx);
+  return transaction;
+};
+
+export type EnglishAuctionParams = {
+  reserveAmount: BN;
+  minIncrement: BN;
+  // unix timestamp, bids in the last antiSnipeSeconds push it back
+  endAt: number;
+  antiSnipeSeconds: number;
+};
+
+export const withCreateEnglishAuction = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  marketplaceName: string,
+  englishAuctionParams: EnglishAuctionParams,
+  paymentMint = PublicKey.default,
+  payer = wallet.publicKey
+): Promise<[Transaction, PublicKey]> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const tokenManagerData = await tryGetAccount(() =>
+    getTokenManager(connection, tokenManagerId)
+  );
+  if (!tokenManagerData?.parsed.transferAuthority) {
+    throw `No transfer authority found for mint id ${mintId.toString()}`;
+  }
+  const englishAuctionId = findEnglishAuctionAddress(mintId);
+
+  const createEnglishAuctionIx = await transferAuthProgram.methods
+    .createEnglishAuction({
+      paymentMint: paymentMint,
+      reserveAmount: englishAuctionParams.reserveAmount,
+      minIncrement: englishAuctionParams.minIncrement,
+      endAt: new BN(englishAuctionParams.endAt),
+      antiSnipeSeconds: new BN(englishAuctionParams.antiSnipeSeconds),
+    })
+    .accounts({
+      englishAuction: englishAuctionId,
+      englishAuctionEscrow: findEnglishAuctionEscrowAddress(englishAuctionId),
+      transferAuthority: tokenManagerData.parsed.transferAuthority,
+      marketplace: findMarketplaceAddress(marketplaceName),
+      tokenManager: tokenManagerId,
+      mint: mintId,
+      mintManager: findMintManagerId(mintId),
+      listerTokenAccount: await findAta(mintId, wallet.publicKey, true),
+      lister: wallet.publicKey,
+      payer: payer,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      listing: findListingAddress(mintId),
+    })
+    .instruction();
+  transaction.add(createEnglishAuctionIx);
+  return [transaction, englishAuctionId];
+};
+
+export const withPlaceBid = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  paymentAmount: BN
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const englishAuctionData = await tryGetAccount(() =>
+    getEnglishAuction(connection, mintId)
+  );
+  if (!englishAuctionData?.parsed) {
+    throw `No english auction found for mint id ${mintId.toString()}`;
+  }
+  const marketplaceData = await getMarketplace(
+    connection,
+    englishAuctionData.parsed.marketplace
+  );
+  const paymentMint = englishAuctionData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+  const englishAuctionEscrowId = findEnglishAuctionEscrowAddress(
+    englishAuctionData.pubkey
+  );
+  const previousBidder =
+    englishAuctionData.parsed.highestBidder ?? wallet.publicKey;
+
+  const placeBidIx = await transferAuthProgram.methods
+    .placeBid({
+      paymentAmount: paymentAmount,
+    })
+    .accounts({
+      englishAuction: englishAuctionData.pubkey,
+      englishAuctionEscrow: englishAuctionEscrowId,
+      englishAuctionPaymentTokenAccount: nativePayment
+        ? englishAuctionEscrowId
+        : await withFindOrInitAssociatedTokenAccount(
+            transaction,
+            connection,
+            paymentMint,
+            englishAuctionEscrowId,
+            wallet.publicKey,
+            true
+          ),
+      marketplace: englishAuctionData.parsed.marketplace,
+      paymentManager: marketplaceData.parsed.paymentManager,
+      bidder: wallet.publicKey,
+      bidderPaymentTokenAccount: nativePayment
+        ? wallet.publicKey
+        : await findAta(paymentMint, wallet.publicKey, true),
+      previousBidder: previousBidder,
+      previousBidderPaymentTokenAccount: nativePayment
+        ? previousBidder
+        : await findAta(paymentMint, previousBidder, true),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(placeBidIx);
+  return transaction;
+};
+
+export const withSettleEnglishAuction = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  buySideReceiver?: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const englishAuctionData = await tryGetAccount(() =>
+    getEnglishAuction(connection, mintId)
+  );
+  if (!englishAuctionData?.parsed) {
+    throw `No english auction found for mint id ${mintId.toString()}`;
+  }
+  const bidder = englishAuctionData.parsed.highestBidder;
+  if (!bidder) {
+    throw `English auction for mint id ${mintId.toString()} has no bids`;
+  }
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const tokenManagerData = await getTokenManager(connection, tokenManagerId);
+  if (!tokenManagerData.parsed.transferAuthority) {
+    throw `No transfer authority for token manager`;
+  }
+  const marketplaceData = await getMarketplace(
+    connection,
+    englishAuctionData.parsed.marketplace
+  );
+  const paymentManagerData = await tryGetAccount(() =>
+    getPaymentManager(connection, marketplaceData.parsed.paymentManager)
+  );
+  if (!paymentManagerData?.parsed) {
+    throw `No payment manager found for marketplace with name ${marketplaceData.parsed.name}`;
+  }
+  const lister = englishAuctionData.parsed.lister;
+  const paymentMint = englishAuctionData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+  const englishAuctionEscrowId = findEnglishAuctionEscrowAddress(
+    englishAuctionData.pubkey
+  );
+
+  const listerPaymentTokenAccountId = nativePayment
+    ? lister
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        lister,
+        wallet.publicKey
+      );
+  const feeCollectorTokenAccountId = nativePayment
+    ? paymentManagerData.parsed.feeCollector
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        paymentManagerData.parsed.feeCollector,
+        wallet.publicKey,
+        true
+      );
+  // the bidder receives the token directly when they settle
+  const bidderMintTokenAccountId = bidder.equals(wallet.publicKey)
+    ? await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        mintId,
+        bidder,
+        wallet.publicKey,
+        true
+      )
+    : await findAta(mintId, bidder, true);
+
+  const remainingAccountsForHandlePaymentWithRoyalties =
+    await withRemainingAccountsForHandlePaymentWithRoyalties(
+      transaction,
+      connection,
+      wallet,
+      mintId,
+      paymentMint,
+      buySideReceiver,
+      [lister.toString(), bidder.toString()]
+    );
+
+  const settleEnglishAuctionIx = await transferAuthProgram.methods
+    .settleEnglishAuction()
+    .accounts({
+      transferAuthority: tokenManagerData.parsed.transferAuthority,
+      transferReceipt: findTransferReceiptId(tokenManagerId),
+      englishAuction: englishAuctionData.pubkey,
+      englishAuctionEscrow: englishAuctionEscrowId,
+      englishAuctionPaymentTokenAccount: nativePayment
+        ? englishAuctionEscrowId
+        : await findAta(paymentMint, englishAuctionEscrowId, true),
+      englishAuctionMintTokenAccount: await findAta(
+        mintId,
+        englishAuctionEscrowId,
+        true
+      ),
+      listerPaymentTokenAccount: listerPaymentTokenAccountId,
+      listerMintTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      lister: lister,
+      bidder: bidder,
+      bidderMintTokenAccount: bidderMintTokenAccountId,
+      bidderPaymentTokenAccount: nativePayment
+        ? bidder
+        : await findAta(paymentMint, bidder, true),
+      settler: wallet.publicKey,
+      marketplace: englishAuctionData.parsed.marketplace,
+      tokenManager: tokenManagerId,
+      mint: mintId,
+      mintMetadataInfo: findMintMetadataId(mintId),
+      paymentManager: marketplaceData.parsed.paymentManager,
+      paymentMint: paymentMint,
+      feeCollectorTokenAccount: feeCollectorTokenAccountId,
+      feeCollector: paymentManagerData.parsed.feeCollector,
+      solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    })
+    .remainingAccounts([
+      ...remainingAccountsForHandlePaymentWithRoyalties,
+      ...getRemainingAccountsForKind(mintId, tokenManagerData.parsed.kind),
+    ])
+    .instruction();
+  transaction.add(settleEnglishAuctionIx);
+  return transaction;
+};
+
+export const withClaimEnglishAuction = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  buySideReceiver?: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const englishAuctionData = await tryGetAccount(() =>
+    getEnglishAuction(connection, mintId)
+  );
+  if (!englishAuctionData?.parsed) {
+    throw `No english auction found for mint id ${mintId.toString()}`;
+  }
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const tokenManagerData = await getTokenManager(connection, tokenManagerId);
+  if (!tokenManagerData.parsed.transferAuthority) {
+    throw `No transfer authority for token manager`;
+  }
+  const marketplaceData = await getMarketplace(
+    connection,
+    englishAuctionData.parsed.marketplace
+  );
+  const paymentManagerData = await tryGetAccount(() =>
+    getPaymentManager(connection, marketplaceData.parsed.paymentManager)
+  );
+  if (!paymentManagerData?.parsed) {
+    throw `No payment manager found for marketplace with name ${marketplaceData.parsed.name}`;
+  }
+  const lister = englishAuctionData.parsed.lister;
+  const paymentMint = englishAuctionData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+  const englishAuctionEscrowId = findEnglishAuctionEscrowAddress(
+    englishAuctionData.pubkey
+  );
+
+  const listerPaymentTokenAccountId = nativePayment
+    ? lister
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        lister,
+        wallet.publicKey
+      );
+  const feeCollectorTokenAccountId = nativePayment
+    ? paymentManagerData.parsed.feeCollector
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        paymentManagerData.parsed.feeCollector,
+        wallet.publicKey,
+        true
+      );
+
+  const bidderMintTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    mintId,
+    wallet.publicKey,
+    wallet.publicKey,
+    true
+  );
+
+  const remainingAccountsForHandlePaymentWithRoyalties =
+    await withRemainingAccountsForHandlePaymentWithRoyalties(
+      transaction,
+      connection,
+      wallet,
+      mintId,
+      paymentMint,
+      buySideReceiver,
+      [lister.toString(), wallet.publicKey.toString()]
+    );
+
+  const claimEnglishAuctionIx = await transferAuthProgram.methods
+    .claimEnglishAuction()
+    .accounts({
+      transferAuthority: tokenManagerData.parsed.transferAuthority,
+      transferReceipt: findTransferReceiptId(tokenManagerId),
+      englishAuction: englishAuctionData.pubkey,
+      englishAuctionEscrow: englishAuctionEscrowId,
+      englishAuctionPaymentTokenAccount: nativePayment
+        ? englishAuctionEscrowId
+        : await findAta(paymentMint, englishAuctionEscrowId, true),
+      englishAuctionMintTokenAccount:
+        tokenManagerData.parsed.recipientTokenAccount,
+      lister: lister,
+      bidder: wallet.publicKey,
+      bidderMintTokenAccount: bidderMintTokenAccountId,
+      bidderPaymentTokenAccount: nativePayment
+        ? wallet.publicKey
+        : await findAta(paymentMint, wallet.publicKey, true),
+      tokenManager: tokenManagerId,
+      mint: mintId,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      listerPaymentTokenAccount: listerPaymentTokenAccountId,
+      marketplace: englishAuctionData.parsed.marketplace,
+      mintMetadataInfo: findMintMetadataId(mintId),
+      paymentManager: marketplaceData.parsed.paymentManager,
+      paymentMint: paymentMint,
+      feeCollectorTokenAccount: feeCollectorTokenAccountId,
+      feeCollector: paymentManagerData.parsed.feeCollector,
+      solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+    })
+    .remainingAccounts([
+      ...remainingAccountsForHandlePaymentWithRoyalties,
+      ...getRemainingAccountsForKind(mintId, tokenManagerData.parsed.kind),
+    ])
+    .instruction();
+  transaction.add(claimEnglishAuctionIx);
+  return transaction;
+};
+
+export const withCancelEnglishAuction = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+
+  const cancelEnglishAuctionIx = await transferAuthProgram.methods
+    .cancelEnglishAuction()
+    .accounts({
+      tokenManager: findTokenManagerAddress(mintId),
+      englishAuction: findEnglishAuctionAddress(mintId),
+      listerMintTokenAccount: await findAta(mintId, wallet.publicKey, true),
+      lister: wallet.publicKey,
+      mint: mintId,
+      mintManager: findMintManagerId(mintId),
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  transaction.add(cancelEnglishAuctionIx);
+  return transaction;
+};
+
+export const withCloseEnglishAuction = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const englishAuctionData = await tryGetAccount(() =>
+    getEnglishAuction(connection, mintId)
+  );
+  if (!englishAuctionData?.parsed) {
+    throw `No english auction found for mint id ${mintId.toString()}`;
+  }
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const tokenManagerData = await tryGetAccount(() =>
+    getTokenManager(connection, tokenManagerId)
+  );
+  const lister = englishAuctionData.parsed.lister;
+  const bidder = englishAuctionData.parsed.highestBidder ?? lister;
+  const paymentMint = englishAuctionData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+  const englishAuctionEscrowId = findEnglishAuctionEscrowAddress(
+    englishAuctionData.pubkey
+  );
+
+  const closeEnglishAuctionIx = await transferAuthProgram.methods
+    .closeEnglishAuction()
+    .accounts({
+      tokenManager: tokenManagerId,
+      listerMintTokenAccount:
+        tokenManagerData?.parsed.recipientTokenAccount ??
+        (await findAta(mintId, lister, true)),
+      englishAuction: englishAuctionData.pubkey,
+      englishAuctionEscrow: englishAuctionEscrowId,
+      englishAuctionPaymentTokenAccount: nativePayment
+        ? englishAuctionEscrowId
+        : await findAta(paymentMint, englishAuctionEscrowId, true),
+      lister: lister,
+      bidder: bidder,
+      bidderPaymentTokenAccount: nativePayment
+        ? bidder
+        : await findAta(paymentMint, bidder, true),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(closeEnglishAuctionIx);
+  return transaction;
+};
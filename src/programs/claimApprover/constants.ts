@@ -30,6 +30,7 @@ export const defaultPaymentManagerId = findPaymentManagerAddress(
 );
 
 export type ClaimApproverParams = {
+  // default pubkey for native sol
   paymentMint: PublicKey;
   paymentAmount: number;
   collector?: PublicKey;
@@ -0,0 +1,28 @@
+import type { BN } from "@coral-xyz/anchor";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import type { Connection, PublicKey } from "@solana/web3.js";
+
+import { simulateQuote } from "../tokenManager/utils";
+import { getClaimApprover } from "./accounts";
+import { claimApproverProgram } from "./constants";
+
+/**
+ * Simulates quote_claim for the price of claiming this token manager
+ * Includes the payment manager taker fee when one is charged
+ * @returns amount in the claim payment mint
+ */
+export const quoteClaim = async (
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey
+): Promise<BN> => {
+  const claimApproverData = await getClaimApprover(connection, tokenManagerId);
+  const quoteIx = await claimApproverProgram(connection, wallet)
+    .methods.quoteClaim()
+    .accounts({
+      claimApprover: claimApproverData.pubkey,
+      paymentManager: claimApproverData.parsed.paymentManager,
+    })
+    .instruction();
+  return simulateQuote(connection, wallet, quoteIx);
+};
@@ -1,4 +1,5 @@
 export * as claimApprover from "./claimApprover";
+export * as securityDeposit from "./securityDeposit";
 export * as timeInvalidator from "./timeInvalidator";
 export * as tokenManager from "./tokenManager";
 export * as transferAuthority from "./transferAuthority";
@@ -0,0 +1,36 @@
+import type { Connection, PublicKey } from "@solana/web3.js";
+import type { AccountData } from "@solana-nft-programs/common";
+
+import type { DepositData, SecurityDepositClaimApproverData } from "./constants";
+import { securityDepositProgram } from "./constants";
+import { findSecurityDepositClaimApproverAddress } from "./pda";
+
+export const getSecurityDepositClaimApprover = async (
+  connection: Connection,
+  tokenManagerId: PublicKey
+): Promise<AccountData<SecurityDepositClaimApproverData>> => {
+  const program = securityDepositProgram(connection);
+  const claimApproverId =
+    findSecurityDepositClaimApproverAddress(tokenManagerId);
+
+  const parsed = await program.account.securityDepositClaimApprover.fetch(
+    claimApproverId
+  );
+  return {
+    parsed,
+    pubkey: claimApproverId,
+  };
+};
+
+export const getDeposit = async (
+  connection: Connection,
+  depositId: PublicKey
+): Promise<AccountData<DepositData>> => {
+  const program = securityDepositProgram(connection);
+
+  const parsed = await program.account.deposit.fetch(depositId);
+  return {
+    parsed,
+    pubkey: depositId,
+  };
+};
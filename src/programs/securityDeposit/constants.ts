@@ -0,0 +1,56 @@
+import { AnchorProvider, Program } from "@coral-xyz/anchor";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import type { ConfirmOptions, Connection } from "@solana/web3.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import type { ParsedIdlAccountData } from "@solana-nft-programs/common";
+import { emptyWallet } from "@solana-nft-programs/common";
+
+import * as SECURITY_DEPOSIT_TYPES from "../../idl/solana_nft_programs_security_deposit";
+
+export const SECURITY_DEPOSIT_ADDRESS = new PublicKey(
+  "6zMSkGgRbv4vbKiNh1RH3nT6dLgz2zLG6rULRbn1VGXB"
+);
+
+export const SECURITY_DEPOSIT_CLAIM_APPROVER_SEED =
+  "security-deposit-claim-approver";
+export const DEPOSIT_SEED = "deposit";
+
+export const NATIVE_DEPOSIT_MINT = PublicKey.default;
+
+export const SECURITY_DEPOSIT_IDL = SECURITY_DEPOSIT_TYPES.IDL;
+
+export type SECURITY_DEPOSIT_PROGRAM =
+  SECURITY_DEPOSIT_TYPES.SolanaNftProgramsSecurityDeposit;
+
+export type SecurityDepositClaimApproverData = ParsedIdlAccountData<
+  "securityDepositClaimApprover",
+  SECURITY_DEPOSIT_PROGRAM
+>;
+
+export type DepositData = ParsedIdlAccountData<
+  "deposit",
+  SECURITY_DEPOSIT_PROGRAM
+>;
+
+export type SecurityDepositParams = {
+  // defaults to native sol
+  depositMint?: PublicKey;
+  depositAmount: number;
+  collector?: PublicKey;
+};
+
+export const securityDepositProgram = (
+  connection: Connection,
+  wallet?: Wallet,
+  confirmOptions?: ConfirmOptions
+) => {
+  return new Program<SECURITY_DEPOSIT_PROGRAM>(
+    SECURITY_DEPOSIT_IDL,
+    SECURITY_DEPOSIT_ADDRESS,
+    new AnchorProvider(
+      connection,
+      wallet ?? emptyWallet(Keypair.generate().publicKey),
+      confirmOptions ?? {}
+    )
+  );
+};
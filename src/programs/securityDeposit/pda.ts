@@ -0,0 +1,42 @@
+import { utils } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+import {
+  DEPOSIT_SEED,
+  SECURITY_DEPOSIT_ADDRESS,
+  SECURITY_DEPOSIT_CLAIM_APPROVER_SEED,
+} from "./constants";
+
+/**
+ * Finds the address of the security deposit claim approver.
+ * @returns
+ */
+export const findSecurityDepositClaimApproverAddress = (
+  tokenManagerId: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(SECURITY_DEPOSIT_CLAIM_APPROVER_SEED),
+      tokenManagerId.toBuffer(),
+    ],
+    SECURITY_DEPOSIT_ADDRESS
+  )[0];
+};
+
+/**
+ * Finds the deposit locked by this depositor.
+ * @returns
+ */
+export const findDepositAddress = (
+  tokenManagerId: PublicKey,
+  depositor: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(DEPOSIT_SEED),
+      tokenManagerId.toBuffer(),
+      depositor.toBuffer(),
+    ],
+    SECURITY_DEPOSIT_ADDRESS
+  )[0];
+};
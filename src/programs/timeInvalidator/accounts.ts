@@ -2,7 +2,7 @@ import { BN, BorshAccountsCoder } from "@coral-xyz/anchor";
 import type { Connection, PublicKey } from "@solana/web3.js";
 import type { AccountData } from "@solana-nft-programs/common";
 
-import type { TimeInvalidatorData } from "./constants";
+import type { ExtensionEscrowData, TimeInvalidatorData } from "./constants";
 import {
   TIME_INVALIDATOR_ADDRESS,
   TIME_INVALIDATOR_IDL,
@@ -22,6 +22,19 @@ export const getTimeInvalidator = async (
   };
 };
 
+export const getExtensionEscrow = async (
+  connection: Connection,
+  extensionEscrowId: PublicKey
+): Promise<AccountData<ExtensionEscrowData>> => {
+  const program = timeInvalidatorProgram(connection);
+
+  const parsed = await program.account.extensionEscrow.fetch(extensionEscrowId);
+  return {
+    parsed,
+    pubkey: extensionEscrowId,
+  };
+};
+
 export const getTimeInvalidators = async (
   connection: Connection,
   timeInvalidatorIds: PublicKey[]
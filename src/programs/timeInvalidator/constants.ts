@@ -12,6 +12,7 @@ export const TIME_INVALIDATOR_ADDRESS = new PublicKey(
 );
 
 export const TIME_INVALIDATOR_SEED = "time-invalidator";
+export const EXTENSION_ESCROW_SEED = "extension-escrow";
 
 export const TIME_INVALIDATOR_IDL = TIME_INVALIDATOR_TYPES.IDL;
 
@@ -23,19 +24,42 @@ export type TimeInvalidatorData = ParsedIdlAccountData<
   TIME_INVALIDATOR_PROGRAM
 >;
 
+export type ExtensionEscrowData = ParsedIdlAccountData<
+  "extensionEscrow",
+  TIME_INVALIDATOR_PROGRAM
+>;
+
 export type TimeInvalidationParams = {
   collector?: PublicKey;
   paymentManager?: PublicKey;
   durationSeconds?: number;
   maxExpiration?: number;
+  gracePeriodSeconds?: number;
   extension?: {
     extensionPaymentAmount: number;
     extensionDurationSeconds: number;
+    // default pubkey for native sol
     extensionPaymentMint: PublicKey;
     disablePartialExtension?: boolean;
+    gracePeriodPenaltyBasisPoints?: number;
+    overduePenaltyAmount?: number;
+    overduePenaltyDurationSeconds?: number;
+    overduePenaltyCollectorBasisPoints?: number;
+    refundBasisPoints?: number;
+    // released with fees and royalties through an initialized
+    // paymentManager, its taker fee is escrowed on top
+    escrowExtensionPayments?: boolean;
+    // seconds past each threshold are priced at that tier's rate
+    extensionPriceTiers?: {
+      thresholdSeconds: number;
+      paymentAmount: number;
+      durationSeconds: number;
+    }[];
   };
 };
 
+export const BASIS_POINTS_DIVISOR = 10_000;
+
 export const timeInvalidatorProgram = (
   connection: Connection,
   wallet?: Wallet,
@@ -1,7 +1,11 @@
 import { utils } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 
-import { TIME_INVALIDATOR_ADDRESS, TIME_INVALIDATOR_SEED } from "./constants";
+import {
+  EXTENSION_ESCROW_SEED,
+  TIME_INVALIDATOR_ADDRESS,
+  TIME_INVALIDATOR_SEED,
+} from "./constants";
 
 /**
  * Finds the time invalidator for this token manager.
@@ -15,3 +19,19 @@ export const findTimeInvalidatorAddress = (
     TIME_INVALIDATOR_ADDRESS
   )[0];
 };
+
+/**
+ * Finds the extension escrow for this time invalidator.
+ * @returns
+ */
+export const findExtensionEscrowAddress = (
+  timeInvalidatorId: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(EXTENSION_ESCROW_SEED),
+      timeInvalidatorId.toBuffer(),
+    ],
+    TIME_INVALIDATOR_ADDRESS
+  )[0];
+};
@@ -1,9 +1,28 @@
 import { BN } from "@coral-xyz/anchor";
+import {
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import type {
+  AccountMeta,
+  Connection,
+  PublicKey,
+  Transaction,
+} from "@solana/web3.js";
 import type { AccountData } from "@solana-nft-programs/common";
+import { tryGetAccount } from "@solana-nft-programs/common";
 
 import type { TokenManagerData } from "../tokenManager";
 import { TokenManagerState } from "../tokenManager";
+import {
+  simulateQuote,
+  withRemainingAccountsForEscrowRelease,
+} from "../tokenManager/utils";
 import type { TimeInvalidatorData } from ".";
+import { getExtensionEscrow, getTimeInvalidator } from "./accounts";
+import { BASIS_POINTS_DIVISOR, timeInvalidatorProgram } from "./constants";
+import { findExtensionEscrowAddress, findTimeInvalidatorAddress } from "./pda";
 
 export const shouldTimeInvalidate = (
   tokenManagerData: AccountData<TokenManagerData>,
@@ -13,6 +32,9 @@ export const shouldTimeInvalidate = (
   const invalidators = tokenManagerData.parsed.invalidators.map((i) =>
     i.toString()
   );
+  // max expiration is a hard limit and is not extended by the grace period
+  const gracePeriodSeconds =
+    timeInvalidatorData.parsed.gracePeriodSeconds ?? new BN(0);
   return (
     invalidators.includes(timeInvalidatorData.pubkey.toString()) &&
     tokenManagerData?.parsed.state !== TokenManagerState.Invalidated &&
@@ -21,14 +43,139 @@ export const shouldTimeInvalidate = (
       new BN(UTCNow).gte(timeInvalidatorData.parsed.maxExpiration)) ||
       (timeInvalidatorData.parsed.expiration &&
         tokenManagerData.parsed.state === TokenManagerState.Claimed &&
-        new BN(UTCNow).gte(timeInvalidatorData.parsed.expiration)) ||
+        new BN(UTCNow).gte(
+          timeInvalidatorData.parsed.expiration.add(gracePeriodSeconds)
+        )) ||
       (!timeInvalidatorData.parsed.expiration &&
         tokenManagerData.parsed.state === TokenManagerState.Claimed &&
         !!timeInvalidatorData.parsed.durationSeconds &&
         new BN(UTCNow).gte(
-          tokenManagerData.parsed.stateChangedAt.add(
-            timeInvalidatorData.parsed.durationSeconds
-          )
+          tokenManagerData.parsed.stateChangedAt
+            .add(timeInvalidatorData.parsed.durationSeconds)
+            .add(gracePeriodSeconds)
         )))
   );
 };
+
+export const overduePenalty = (
+  tokenManagerData: AccountData<TokenManagerData>,
+  timeInvalidatorData: AccountData<TimeInvalidatorData>,
+  UTCNow: number = Date.now() / 1000
+): { overduePenalty: BN; collectorPenalty: BN } => {
+  const {
+    overduePenaltyAmount,
+    overduePenaltyDurationSeconds,
+    overduePenaltyCollectorBasisPoints,
+    expiration,
+    durationSeconds,
+  } = timeInvalidatorData.parsed;
+  if (
+    !overduePenaltyAmount ||
+    !overduePenaltyDurationSeconds ||
+    tokenManagerData.parsed.state !== TokenManagerState.Claimed
+  ) {
+    return { overduePenalty: new BN(0), collectorPenalty: new BN(0) };
+  }
+  const currentExpiration =
+    expiration ??
+    tokenManagerData.parsed.stateChangedAt.add(durationSeconds ?? new BN(0));
+  const now = new BN(Math.floor(UTCNow));
+  if (now.lte(currentExpiration)) {
+    return { overduePenalty: new BN(0), collectorPenalty: new BN(0) };
+  }
+  const penalty = now
+    .sub(currentExpiration)
+    .mul(overduePenaltyAmount)
+    .div(overduePenaltyDurationSeconds);
+  return {
+    overduePenalty: penalty,
+    collectorPenalty: penalty
+      .mul(new BN(overduePenaltyCollectorBasisPoints ?? 0))
+      .div(new BN(BASIS_POINTS_DIVISOR)),
+  };
+};
+
+export const usesExtensionEscrow = (
+  timeInvalidatorData: AccountData<TimeInvalidatorData>
+): boolean =>
+  timeInvalidatorData.parsed.refundBasisPoints !== null ||
+  timeInvalidatorData.parsed.escrowExtensionPayments === true;
+
+/**
+ * Accounts to pay out and reset the extension escrow on invalidation
+ */
+export const withRemainingAccountsForSettleExtensionEscrow = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  timeInvalidatorData: AccountData<TimeInvalidatorData>
+): Promise<AccountMeta[]> => {
+  if (!usesExtensionEscrow(timeInvalidatorData)) return [];
+  const extensionEscrowId = findExtensionEscrowAddress(
+    timeInvalidatorData.pubkey
+  );
+  const extensionEscrowData = await tryGetAccount(() =>
+    getExtensionEscrow(connection, extensionEscrowId)
+  );
+  if (!extensionEscrowData) {
+    return [{ pubkey: extensionEscrowId, isSigner: false, isWritable: false }];
+  }
+  return [
+    { pubkey: extensionEscrowId, isSigner: false, isWritable: true },
+    {
+      pubkey: getAssociatedTokenAddressSync(
+        extensionEscrowData.parsed.paymentMint,
+        extensionEscrowId,
+        true
+      ),
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: extensionEscrowData.parsed.paymentTokenAccount,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    {
+      pubkey: extensionEscrowData.parsed.paymentManager,
+      isSigner: false,
+      isWritable: true,
+    },
+    ...(await withRemainingAccountsForEscrowRelease(
+      transaction,
+      connection,
+      wallet,
+      extensionEscrowData.parsed.paymentManager,
+      extensionEscrowData.parsed.paymentMint,
+      extensionEscrowData.parsed.mint
+    )),
+  ];
+};
+
+/**
+ * Simulates quote_extension for the price of extending this token manager
+ * Includes the payment manager taker fee when one is charged
+ * @returns amount in the extension payment mint
+ */
+export const quoteExtension = async (
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  secondsToAdd: number
+): Promise<BN> => {
+  const timeInvalidatorId = findTimeInvalidatorAddress(tokenManagerId);
+  const timeInvalidatorData = await getTimeInvalidator(
+    connection,
+    timeInvalidatorId
+  );
+  const quoteIx = await timeInvalidatorProgram(connection, wallet)
+    .methods.quoteExtension(new BN(secondsToAdd))
+    .accounts({
+      tokenManager: tokenManagerId,
+      timeInvalidator: timeInvalidatorId,
+      paymentManager: timeInvalidatorData.parsed.paymentManager,
+    })
+    .instruction();
+  return simulateQuote(connection, wallet, quoteIx);
+};
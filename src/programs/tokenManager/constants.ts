@@ -54,6 +54,7 @@ export enum TokenManagerKind {
   Edition = 3,
   Permissioned = 4,
   Programmable = 5,
+  PermanentDelegate = 6,
 }
 
 export enum InvalidationType {
@@ -1,3 +1,4 @@
+import type { BN } from "@coral-xyz/anchor";
 import { utils } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 
@@ -48,6 +49,24 @@ export const findTokenManagerAddress = (mint: PublicKey): PublicKey => {
   )[0];
 };
 
+/**
+ * Finds the counter-derived token manager address for a given mint and count
+ * @returns
+ */
+export const findCountedTokenManagerAddress = (
+  mint: PublicKey,
+  count: BN
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(TOKEN_MANAGER_SEED),
+      mint.toBuffer(),
+      count.toArrayLike(Buffer, "le", 8),
+    ],
+    TOKEN_MANAGER_ADDRESS
+  )[0];
+};
+
 /**
  * Finds the claim receipt id.
  * @returns
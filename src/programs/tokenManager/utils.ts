@@ -1,4 +1,4 @@
-import { Wallet } from "@coral-xyz/anchor";
+import { BN, Wallet } from "@coral-xyz/anchor";
 import {
   PREFIX as TOKEN_AUTH_RULESET_PREFIX,
   PROGRAM_ID as TOKEN_AUTH_RULES_ID,
@@ -11,8 +11,9 @@ import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAccount,
   getAssociatedTokenAddressSync,
+  TOKEN_2022_PROGRAM_ID,
 } from "@solana/spl-token";
-import type { AccountMeta } from "@solana/web3.js";
+import type { AccountMeta, TransactionInstruction } from "@solana/web3.js";
 import {
   Connection,
   Keypair,
@@ -21,6 +22,8 @@ import {
   SystemProgram,
   SYSVAR_INSTRUCTIONS_PUBKEY,
   Transaction,
+  TransactionMessage,
+  VersionedTransaction,
 } from "@solana/web3.js";
 import type { AccountData } from "@solana-nft-programs/common";
 import {
@@ -28,8 +31,12 @@ import {
   findMintEditionId,
   findMintMetadataId,
   METADATA_PROGRAM_ID,
+  tryGetAccount,
   withFindOrInitAssociatedTokenAccount,
 } from "@solana-nft-programs/common";
+import { PAYMENT_MANAGER_ADDRESS } from "@solana-nft-programs/payment-manager";
+import { getPaymentManager } from "@solana-nft-programs/payment-manager/dist/cjs/accounts";
+import { withRemainingAccountsForHandlePaymentWithRoyalties } from "@solana-nft-programs/payment-manager/dist/cjs/utils";
 
 import type { SolanaNftProgramsTokenManager } from "../../idl/solana_nft_programs_token_manager";
 import type { TokenManagerData } from ".";
@@ -52,7 +59,8 @@ export const getRemainingAccountsForKind = (
 ): AccountMeta[] => {
   if (
     tokenManagerKind === TokenManagerKind.Managed ||
-    tokenManagerKind === TokenManagerKind.Permissioned
+    tokenManagerKind === TokenManagerKind.Permissioned ||
+    tokenManagerKind === TokenManagerKind.PermanentDelegate
   ) {
     return [
       {
@@ -184,9 +192,19 @@ export const withRemainingAccountsForInvalidate = async (
   }
 
   if (tokenManagerData.parsed.state === TokenManagerState.Claimed) {
-    remainingAccounts.push(
-      ...getRemainingAccountsForKind(mintId, tokenManagerData.parsed.kind)
-    );
+    if (tokenManagerData.parsed.kind === TokenManagerKind.PermanentDelegate) {
+      remainingAccounts.push(
+        ...(await getRemainingAccountsForPermanentDelegateReclaim(
+          connection,
+          mintId,
+          tokenManagerData.parsed
+        ))
+      );
+    } else {
+      remainingAccounts.push(
+        ...getRemainingAccountsForKind(mintId, tokenManagerData.parsed.kind)
+      );
+    }
   }
 
   if (
@@ -216,6 +234,237 @@ export const withRemainingAccountsForInvalidate = async (
   return remainingAccounts;
 };
 
+/**
+ * Permanent delegate tokens are reclaimed from the recorded recipient token account. If the holder
+ * moved them out of it, the account now holding them is passed in, which the program only accepts
+ * when the token manager holds the whole supply of the mint
+ */
+export const getRemainingAccountsForPermanentDelegateReclaim = async (
+  connection: Connection,
+  mintId: PublicKey,
+  tokenManagerData: TokenManagerData
+): Promise<AccountMeta[]> => {
+  if (
+    tokenManagerData.invalidationType !== InvalidationType.Return &&
+    tokenManagerData.invalidationType !== InvalidationType.Reissue
+  ) {
+    return [];
+  }
+  const remainingAccounts: AccountMeta[] = [
+    {
+      pubkey: findMintManagerId(mintId),
+      isSigner: false,
+      isWritable: true,
+    },
+  ];
+  const recipientTokenAccount = await getAccount(
+    connection,
+    tokenManagerData.recipientTokenAccount,
+    undefined,
+    TOKEN_2022_PROGRAM_ID
+  );
+  if (
+    new BN(recipientTokenAccount.amount.toString()).lt(tokenManagerData.amount)
+  ) {
+    const largestAccounts = await connection.getTokenLargestAccounts(mintId);
+    const holderTokenAccount = largestAccounts.value.find((account) =>
+      new BN(account.amount).gte(tokenManagerData.amount)
+    );
+    if (!holderTokenAccount) throw "Token holder not found";
+    remainingAccounts.push({
+      pubkey: holderTokenAccount.address,
+      isSigner: false,
+      isWritable: true,
+    });
+  }
+  return remainingAccounts;
+};
+
+/**
+ * Remaining accounts for an invalidator batch invalidate
+ * Closed entries only need their token manager and invalidator addresses
+ * @returns forwarded invalidate account counts and the remaining accounts
+ */
+export const withRemainingAccountsForBatchInvalidate = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  entries: {
+    mintId: PublicKey;
+    invalidatorId: PublicKey;
+    // read by the invalidator before the token manager invalidate accounts
+    invalidatorAccounts?: AccountMeta[];
+  }[]
+): Promise<[number[], AccountMeta[]]> => {
+  const remainingAccountsLengths: number[] = [];
+  const remainingAccounts: AccountMeta[] = [];
+  for (const { mintId, invalidatorId, invalidatorAccounts = [] } of entries) {
+    const tokenManagerId = findTokenManagerAddress(mintId);
+    const tokenManagerTokenAccountId = getAssociatedTokenAddressSync(
+      mintId,
+      tokenManagerId,
+      true
+    );
+    const [tokenManagerInfo, metadataInfo] =
+      await connection.getMultipleAccountsInfo([
+        tokenManagerId,
+        findMintMetadataId(mintId),
+      ]);
+    let recipientTokenAccountId = tokenManagerTokenAccountId;
+    let invalidateAccounts: AccountMeta[] = [...invalidatorAccounts];
+    if (tokenManagerInfo) {
+      const tokenManagerData = decodeIdlAccount<
+        "tokenManager",
+        SolanaNftProgramsTokenManager
+      >(tokenManagerInfo, "tokenManager", TOKEN_MANAGER_IDL);
+      const metadata = metadataInfo
+        ? Metadata.deserialize(metadataInfo.data)[0]
+        : null;
+      recipientTokenAccountId = tokenManagerData.parsed.recipientTokenAccount;
+      const recipientTokenAccount = await getAccount(
+        connection,
+        recipientTokenAccountId
+      );
+      invalidateAccounts.push(
+        ...(await withRemainingAccountsForInvalidate(
+          transaction,
+          connection,
+          wallet,
+          mintId,
+          { ...tokenManagerData, pubkey: tokenManagerId },
+          recipientTokenAccount.owner,
+          metadata
+        ))
+      );
+    }
+    remainingAccountsLengths.push(invalidateAccounts.length);
+    remainingAccounts.push(
+      { pubkey: tokenManagerId, isSigner: false, isWritable: true },
+      { pubkey: invalidatorId, isSigner: false, isWritable: true },
+      { pubkey: tokenManagerTokenAccountId, isSigner: false, isWritable: true },
+      { pubkey: mintId, isSigner: false, isWritable: true },
+      { pubkey: recipientTokenAccountId, isSigner: false, isWritable: true },
+      ...invalidateAccounts
+    );
+  }
+  return [remainingAccountsLengths, remainingAccounts];
+};
+
+/**
+ * Native SOL counterpart of withRemainingAccountsForPayment
+ * The payment and fee collector accounts are wallets instead of token accounts
+ * @returns payment target, fee collector and remaining accounts
+ */
+export const withRemainingAccountsForNativePayment = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  issuerId: PublicKey,
+  paymentManagerId: PublicKey,
+  buySideReceiver?: PublicKey,
+  options?: {
+    receiptMint?: PublicKey | null;
+  }
+): Promise<[PublicKey, PublicKey, AccountMeta[]]> => {
+  const remainingAccounts: AccountMeta[] = [];
+  let paymentTargetId = issuerId;
+  if (options?.receiptMint) {
+    const receiptMintLargestAccount = await connection.getTokenLargestAccounts(
+      options.receiptMint
+    );
+
+    // get holder of receipt mint
+    const receiptTokenAccountId = receiptMintLargestAccount.value[0]?.address;
+    if (!receiptTokenAccountId) throw new Error("No token accounts found");
+    const receiptTokenAccount = await getAccount(
+      connection,
+      receiptTokenAccountId
+    );
+    paymentTargetId = receiptTokenAccount.owner;
+    remainingAccounts.push({
+      pubkey: receiptTokenAccountId,
+      isSigner: false,
+      isWritable: false,
+    });
+  }
+
+  const paymentManagerData = await tryGetAccount(() =>
+    getPaymentManager(connection, paymentManagerId)
+  );
+  if (!paymentManagerData?.parsed) {
+    return [paymentTargetId, paymentTargetId, remainingAccounts];
+  }
+  remainingAccounts.push(
+    { pubkey: mintId, isSigner: false, isWritable: false },
+    {
+      pubkey: findMintMetadataId(mintId),
+      isSigner: false,
+      isWritable: false,
+    },
+    ...(await withRemainingAccountsForHandlePaymentWithRoyalties(
+      transaction,
+      connection,
+      wallet,
+      mintId,
+      PublicKey.default,
+      buySideReceiver,
+      [paymentTargetId.toString(), wallet.publicKey.toString()]
+    ))
+  );
+  return [
+    paymentTargetId,
+    paymentManagerData.parsed.feeCollector,
+    remainingAccounts,
+  ];
+};
+
+/**
+ * Accounts for an escrow to pay out through its payment manager
+ * Empty when the escrow was created without a payment manager
+ */
+export const withRemainingAccountsForEscrowRelease = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  paymentManagerId: PublicKey,
+  paymentMint: PublicKey,
+  mintId: PublicKey
+): Promise<AccountMeta[]> => {
+  const paymentManagerData = await tryGetAccount(() =>
+    getPaymentManager(connection, paymentManagerId)
+  );
+  if (!paymentManagerData?.parsed) return [];
+  const feeCollectorTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    paymentMint,
+    paymentManagerData.parsed.feeCollector,
+    wallet.publicKey,
+    true
+  );
+  return [
+    { pubkey: feeCollectorTokenAccountId, isSigner: false, isWritable: true },
+    { pubkey: paymentMint, isSigner: false, isWritable: false },
+    { pubkey: mintId, isSigner: false, isWritable: false },
+    {
+      pubkey: findMintMetadataId(mintId),
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: PAYMENT_MANAGER_ADDRESS, isSigner: false, isWritable: false },
+    ...(await withRemainingAccountsForHandlePaymentWithRoyalties(
+      transaction,
+      connection,
+      wallet,
+      mintId,
+      paymentMint,
+      undefined,
+      []
+    )),
+  ];
+};
+
 export const withRemainingAccountsForReturn = async (
   transaction: Transaction,
   connection: Connection,
@@ -687,6 +936,33 @@ export function findTokenRecordId(
   )[0];
 }
 
+/**
+ * Simulates a quote instruction and decodes the u64 it sets as return data
+ * @returns quoted amount
+ */
+export const simulateQuote = async (
+  connection: Connection,
+  wallet: Wallet,
+  quoteIx: TransactionInstruction
+): Promise<BN> => {
+  const { blockhash } = await connection.getLatestBlockhash();
+  const simulation = await connection.simulateTransaction(
+    new VersionedTransaction(
+      new TransactionMessage({
+        payerKey: wallet.publicKey,
+        recentBlockhash: blockhash,
+        instructions: [quoteIx],
+      }).compileToV0Message()
+    ),
+    { sigVerify: false }
+  );
+  const returnData = simulation.value.returnData?.data[0];
+  if (simulation.value.err || !returnData) {
+    throw `Failed to simulate quote ${JSON.stringify(simulation.value.err)}`;
+  }
+  return new BN(Buffer.from(returnData, "base64"), "le");
+};
+
 export const findRuleSetId = (authority: PublicKey, name: string) => {
   return PublicKey.findProgramAddressSync(
     [
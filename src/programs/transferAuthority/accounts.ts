@@ -3,8 +3,12 @@ import type { Connection, PublicKey } from "@solana/web3.js";
 import type { AccountData } from "@solana-nft-programs/common";
 
 import type {
+  CollectionBidData,
+  DutchAuctionData,
+  EnglishAuctionData,
   ListingData,
   MarketplaceData,
+  OfferData,
   TransferAuthorityData,
   TransferData,
 } from "./constants";
@@ -14,6 +18,8 @@ import {
   transferAuthorityProgram,
 } from "./constants";
 import {
+  findDutchAuctionAddress,
+  findEnglishAuctionAddress,
   findListingAddress,
   findMarketplaceAddress,
   findTransferAddress,
@@ -296,6 +302,95 @@ export const getTransfersToUser = async (
   );
 };
 
+//////// OFFER ////////
+
+export const getOffer = async (
+  connection: Connection,
+  offerId: PublicKey
+): Promise<AccountData<OfferData>> => {
+  const program = transferAuthorityProgram(connection);
+
+  const parsed = await program.account.offer.fetch(offerId);
+  return {
+    parsed,
+    pubkey: offerId,
+  };
+};
+
+export const getOffersForTokenManager = async (
+  connection: Connection,
+  tokenManagerId: PublicKey
+): Promise<AccountData<OfferData>[]> =>
+  (await getAllOfType<OfferData>(connection, "offer")).filter((offer) =>
+    offer.parsed.tokenManager.equals(tokenManagerId)
+  );
+
+//////// COLLECTION BID ////////
+
+export const getCollectionBid = async (
+  connection: Connection,
+  collectionBidId: PublicKey
+): Promise<AccountData<CollectionBidData>> => {
+  const program = transferAuthorityProgram(connection);
+
+  const parsed = await program.account.collectionBid.fetch(collectionBidId);
+  return {
+    parsed,
+    pubkey: collectionBidId,
+  };
+};
+
+export const getCollectionBidsForCollection = async (
+  connection: Connection,
+  collection: PublicKey
+): Promise<AccountData<CollectionBidData>[]> =>
+  (await getAllOfType<CollectionBidData>(connection, "collectionBid")).filter(
+    (collectionBid) => collectionBid.parsed.collection.equals(collection)
+  );
+
+//////// AUCTION ////////
+
+export const getDutchAuction = async (
+  connection: Connection,
+  mintId: PublicKey
+): Promise<AccountData<DutchAuctionData>> => {
+  const program = transferAuthorityProgram(connection);
+  const dutchAuctionId = findDutchAuctionAddress(mintId);
+
+  const parsed = await program.account.dutchAuction.fetch(dutchAuctionId);
+  return {
+    parsed,
+    pubkey: dutchAuctionId,
+  };
+};
+
+export const getEnglishAuction = async (
+  connection: Connection,
+  mintId: PublicKey
+): Promise<AccountData<EnglishAuctionData>> => {
+  const program = transferAuthorityProgram(connection);
+  const englishAuctionId = findEnglishAuctionAddress(mintId);
+
+  const parsed = await program.account.englishAuction.fetch(englishAuctionId);
+  return {
+    parsed,
+    pubkey: englishAuctionId,
+  };
+};
+
+export const getEnglishAuctionsForMarketplace = async (
+  connection: Connection,
+  marketplaceId: PublicKey
+): Promise<AccountData<EnglishAuctionData>[]> => {
+  const englishAuctions = await getAllOfType<EnglishAuctionData>(
+    connection,
+    "englishAuction"
+  );
+  return englishAuctions.filter((englishAuction) =>
+    englishAuction.parsed.marketplace.equals(marketplaceId)
+  );
+};
+
 //////// utils ////////
 export const getAllOfType = async <T>(
   connection: Connection,
@@ -15,6 +15,13 @@ export const TRANSFER_AUTHORITY_SEED = "transfer-authority";
 export const MARKETPLACE_SEED = "marketplace";
 export const LISTING_SEED = "listing";
 export const TRANSFER_SEED = "transfer";
+export const OFFER_SEED = "offer";
+export const OFFER_ESCROW_SEED = "offer-escrow";
+export const COLLECTION_BID_SEED = "collection-bid";
+export const COLLECTION_BID_ESCROW_SEED = "collection-bid-escrow";
+export const DUTCH_AUCTION_SEED = "dutch-auction";
+export const ENGLISH_AUCTION_SEED = "english-auction";
+export const ENGLISH_AUCTION_ESCROW_SEED = "english-auction-escrow";
 
 export const TRANSFER_AUTHORITY_IDL = TRANSFER_AUTHORITY_TYPES.IDL;
 
@@ -43,6 +50,22 @@ export type TransferData = ParsedIdlAccountData<
   "transfer",
   TRANSFER_AUTHORITY_PROGRAM
 >;
+export type OfferData = ParsedIdlAccountData<
+  "offer",
+  TRANSFER_AUTHORITY_PROGRAM
+>;
+export type CollectionBidData = ParsedIdlAccountData<
+  "collectionBid",
+  TRANSFER_AUTHORITY_PROGRAM
+>;
+export type DutchAuctionData = ParsedIdlAccountData<
+  "dutchAuction",
+  TRANSFER_AUTHORITY_PROGRAM
+>;
+export type EnglishAuctionData = ParsedIdlAccountData<
+  "englishAuction",
+  TRANSFER_AUTHORITY_PROGRAM
+>;
 
 export const transferAuthorityProgram = (
   connection: Connection,
@@ -3,8 +3,15 @@ import { PublicKey } from "@solana/web3.js";
 
 import { findTokenManagerAddress } from "../tokenManager/pda";
 import {
+  COLLECTION_BID_ESCROW_SEED,
+  COLLECTION_BID_SEED,
+  DUTCH_AUCTION_SEED,
+  ENGLISH_AUCTION_ESCROW_SEED,
+  ENGLISH_AUCTION_SEED,
   LISTING_SEED,
   MARKETPLACE_SEED,
+  OFFER_ESCROW_SEED,
+  OFFER_SEED,
   TRANSFER_AUTHORITY_ADDRESS,
   TRANSFER_AUTHORITY_SEED,
   TRANSFER_SEED,
@@ -58,3 +65,108 @@ export const findTransferAddress = (mintId: PublicKey): PublicKey => {
     TRANSFER_AUTHORITY_ADDRESS
   )[0];
 };
+
+/**
+ * Finds the address of the buyer's offer on this token manager.
+ * @returns
+ */
+export const findOfferAddress = (
+  tokenManagerId: PublicKey,
+  buyer: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(OFFER_SEED),
+      tokenManagerId.toBytes(),
+      buyer.toBytes(),
+    ],
+    TRANSFER_AUTHORITY_ADDRESS
+  )[0];
+};
+
+/**
+ * Finds the address of the escrow holding the offer's funds.
+ * @returns
+ */
+export const findOfferEscrowAddress = (offerId: PublicKey): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(OFFER_ESCROW_SEED), offerId.toBytes()],
+    TRANSFER_AUTHORITY_ADDRESS
+  )[0];
+};
+
+/**
+ * Finds the address of the buyer's bid on a collection.
+ * @returns
+ */
+export const findCollectionBidAddress = (
+  transferAuthorityId: PublicKey,
+  collection: PublicKey,
+  buyer: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(COLLECTION_BID_SEED),
+      transferAuthorityId.toBytes(),
+      collection.toBytes(),
+      buyer.toBytes(),
+    ],
+    TRANSFER_AUTHORITY_ADDRESS
+  )[0];
+};
+
+/**
+ * Finds the address of the escrow holding the collection bid's funds.
+ * @returns
+ */
+export const findCollectionBidEscrowAddress = (
+  collectionBidId: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(COLLECTION_BID_ESCROW_SEED),
+      collectionBidId.toBytes(),
+    ],
+    TRANSFER_AUTHORITY_ADDRESS
+  )[0];
+};
+
+/**
+ * Finds the address of the dutch auction.
+ * @returns
+ */
+export const findDutchAuctionAddress = (mintId: PublicKey): PublicKey => {
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(DUTCH_AUCTION_SEED), tokenManagerId.toBytes()],
+    TRANSFER_AUTHORITY_ADDRESS
+  )[0];
+};
+
+/**
+ * Finds the address of the english auction.
+ * @returns
+ */
+export const findEnglishAuctionAddress = (mintId: PublicKey): PublicKey => {
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(ENGLISH_AUCTION_SEED), tokenManagerId.toBytes()],
+    TRANSFER_AUTHORITY_ADDRESS
+  )[0];
+};
+
+/**
+ * Finds the address of the escrow holding the english auction's highest bid.
+ * @returns
+ */
+export const findEnglishAuctionEscrowAddress = (
+  englishAuctionId: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(ENGLISH_AUCTION_ESCROW_SEED),
+      englishAuctionId.toBytes(),
+    ],
+    TRANSFER_AUTHORITY_ADDRESS
+  )[0];
+};
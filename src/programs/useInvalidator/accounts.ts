@@ -1,7 +1,11 @@
 import type { Connection, PublicKey } from "@solana/web3.js";
 import type { AccountData } from "@solana-nft-programs/common";
 
-import type { UseInvalidatorData } from "./constants";
+import type {
+  UsageEscrowData,
+  UsageLogData,
+  UseInvalidatorData,
+} from "./constants";
 import { useInvalidatorProgram } from "./constants";
 
 export const getUseInvalidator = async (
@@ -17,6 +21,32 @@ export const getUseInvalidator = async (
   };
 };
 
+export const getUsageEscrow = async (
+  connection: Connection,
+  usageEscrowId: PublicKey
+): Promise<AccountData<UsageEscrowData>> => {
+  const program = useInvalidatorProgram(connection);
+
+  const parsed = await program.account.usageEscrow.fetch(usageEscrowId);
+  return {
+    parsed,
+    pubkey: usageEscrowId,
+  };
+};
+
+export const getUsageLog = async (
+  connection: Connection,
+  usageLogId: PublicKey
+): Promise<AccountData<UsageLogData>> => {
+  const program = useInvalidatorProgram(connection);
+
+  const parsed = await program.account.usageLog.fetch(usageLogId);
+  return {
+    parsed,
+    pubkey: usageLogId,
+  };
+};
+
 export const getUseInvalidators = async (
   connection: Connection,
   useInvalidatorIds: PublicKey[]
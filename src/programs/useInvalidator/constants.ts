@@ -12,6 +12,8 @@ export const USE_INVALIDATOR_ADDRESS = new PublicKey(
 );
 
 export const USE_INVALIDATOR_SEED = "use-invalidator";
+export const USAGE_ESCROW_SEED = "usage-escrow";
+export const USAGE_LOG_SEED = "usage-log";
 
 export const USE_INVALIDATOR_IDL = USE_INVALIDATOR_TYPES.IDL;
 
@@ -23,16 +25,52 @@ export type UseInvalidatorData = ParsedIdlAccountData<
   USE_INVALIDATOR_PROGRAM
 >;
 
+export type UsageEscrowData = ParsedIdlAccountData<
+  "usageEscrow",
+  USE_INVALIDATOR_PROGRAM
+>;
+
+export type UsageLogData = ParsedIdlAccountData<
+  "usageLog",
+  USE_INVALIDATOR_PROGRAM
+>;
+
+export type UseAuthorityQuota = {
+  authority: PublicKey;
+  quota?: number;
+};
+
 export type UseInvalidationParams = {
   collector?: PublicKey;
   paymentManager?: PublicKey;
   totalUsages?: number;
+  // totalUsages become available again every usagesResetSeconds
+  // and unused usages do not roll over
+  usagesResetSeconds?: number;
   useAuthority?: PublicKey;
+  // scanners allowed to use the token, each limited to its quota if set
+  useAuthorities?: UseAuthorityQuota[];
+  // at most maxUsagesPerPeriod usages within periodSeconds of the first use
+  rateLimit?: {
+    maxUsagesPerPeriod: number;
+    periodSeconds: number;
+  };
   extension?: {
     extensionUsages: number;
+    // default pubkey for native sol
     extensionPaymentMint: PublicKey;
     extensionPaymentAmount: number;
     maxUsages?: number;
+    // released with fees and royalties through an initialized
+    // paymentManager, its taker fee is escrowed on top
+    escrowExtensionPayments?: boolean;
+    // the highest tier whose minUsages is reached prices the whole extension
+    usagePriceTiers?: {
+      minUsages: number;
+      paymentAmount: number;
+      extensionUsages: number;
+    }[];
+    minPricePerUsage?: number;
   };
 };
 
@@ -1,3 +1,4 @@
 export * as accounts from "./accounts";
 export * from "./constants";
 export * as pda from "./pda";
+export * as utils from "./utils";
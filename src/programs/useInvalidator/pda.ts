@@ -1,7 +1,12 @@
 import { utils } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 
-import { USE_INVALIDATOR_ADDRESS, USE_INVALIDATOR_SEED } from "./constants";
+import {
+  USAGE_ESCROW_SEED,
+  USAGE_LOG_SEED,
+  USE_INVALIDATOR_ADDRESS,
+  USE_INVALIDATOR_SEED,
+} from "./constants";
 
 /**
  * Finds the use invalidator for this token manager.
@@ -15,3 +20,27 @@ export const findUseInvalidatorAddress = (
     USE_INVALIDATOR_ADDRESS
   )[0];
 };
+
+/**
+ * Finds the usage escrow for this use invalidator.
+ * @returns
+ */
+export const findUsageEscrowAddress = (
+  useInvalidatorId: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(USAGE_ESCROW_SEED), useInvalidatorId.toBuffer()],
+    USE_INVALIDATOR_ADDRESS
+  )[0];
+};
+
+/**
+ * Finds the usage log for this use invalidator.
+ * @returns
+ */
+export const findUsageLogAddress = (useInvalidatorId: PublicKey): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(USAGE_LOG_SEED), useInvalidatorId.toBuffer()],
+    USE_INVALIDATOR_ADDRESS
+  )[0];
+};
@@ -0,0 +1,34 @@
+import { BN } from "@coral-xyz/anchor";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import type { Connection, PublicKey } from "@solana/web3.js";
+
+import { simulateQuote } from "../tokenManager/utils";
+import { getUseInvalidator } from "./accounts";
+import { useInvalidatorProgram } from "./constants";
+import { findUseInvalidatorAddress } from "./pda";
+
+/**
+ * Simulates quote_usages for the price of adding usages to this token manager
+ * Includes the payment manager taker fee when one is charged
+ * @returns amount in the extension payment mint
+ */
+export const quoteUsages = async (
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  usagesToAdd: number
+): Promise<BN> => {
+  const useInvalidatorId = findUseInvalidatorAddress(tokenManagerId);
+  const useInvalidatorData = await getUseInvalidator(
+    connection,
+    useInvalidatorId
+  );
+  const quoteIx = await useInvalidatorProgram(connection, wallet)
+    .methods.quoteUsages(new BN(usagesToAdd))
+    .accounts({
+      useInvalidator: useInvalidatorId,
+      paymentManager: useInvalidatorData.parsed.paymentManager,
+    })
+    .instruction();
+  return simulateQuote(connection, wallet, quoteIx);
+};
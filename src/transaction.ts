@@ -13,10 +13,11 @@ import {
   TOKEN_PROGRAM_ID,
   unpackAccount,
 } from "@solana/spl-token";
-import type { Connection, PublicKey, Transaction } from "@solana/web3.js";
+import type { AccountMeta, Connection, Transaction } from "@solana/web3.js";
 import {
   ComputeBudgetProgram,
   Keypair,
+  PublicKey,
   SystemProgram,
   SYSVAR_INSTRUCTIONS_PUBKEY,
   SYSVAR_RENT_PUBKEY,
@@ -49,10 +50,28 @@ import {
   defaultPaymentManagerId,
 } from "./programs/claimApprover";
 import { findClaimApproverAddress } from "./programs/claimApprover/pda";
+import type {
+  SECURITY_DEPOSIT_PROGRAM,
+  SecurityDepositParams,
+} from "./programs/securityDeposit";
+import {
+  NATIVE_DEPOSIT_MINT,
+  SECURITY_DEPOSIT_IDL,
+  securityDepositProgram,
+} from "./programs/securityDeposit";
+import {
+  findDepositAddress,
+  findSecurityDepositClaimApproverAddress,
+} from "./programs/securityDeposit/pda";
 import type { TimeInvalidationParams } from "./programs/timeInvalidator";
 import { timeInvalidatorProgram } from "./programs/timeInvalidator";
 import { findTimeInvalidatorAddress } from "./programs/timeInvalidator/pda";
-import { shouldTimeInvalidate } from "./programs/timeInvalidator/utils";
+import {
+  overduePenalty,
+  shouldTimeInvalidate,
+  usesExtensionEscrow,
+  withRemainingAccountsForSettleExtensionEscrow,
+} from "./programs/timeInvalidator/utils";
 import type {
   TOKEN_MANAGER_PROGRAM,
   TokenManagerData,
@@ -68,6 +87,7 @@ import {
 } from "./programs/tokenManager";
 import { getTokenManager } from "./programs/tokenManager/accounts";
 import {
+  findCountedTokenManagerAddress,
   findMintCounterId,
   findMintManagerId,
   findReceiptMintManagerId,
@@ -80,7 +100,10 @@ import {
   getRemainingAccountsForKind,
   getRemainingAccountsForTransfer,
   getRemainingAccountsForUnissue,
+  withRemainingAccountsForBatchInvalidate,
+  withRemainingAccountsForEscrowRelease,
   withRemainingAccountsForInvalidate,
+  withRemainingAccountsForNativePayment,
   withRemainingAccountsForReturn,
 } from "./programs/tokenManager/utils";
 import {
@@ -88,12 +111,16 @@ import {
   getTransferAuthorityByName,
 } from "./programs/transferAuthority/accounts";
 import { findListingAddress } from "./programs/transferAuthority/pda";
-import type { UseInvalidationParams } from "./programs/useInvalidator";
+import type {
+  UseAuthorityQuota,
+  UseInvalidationParams,
+} from "./programs/useInvalidator";
 import { useInvalidatorProgram } from "./programs/useInvalidator";
 import { findUseInvalidatorAddress } from "./programs/useInvalidator/pda";
 
 export type IssueParameters = {
   claimPayment?: ClaimApproverParams;
+  securityDeposit?: SecurityDepositParams;
   timeInvalidation?: TimeInvalidationParams;
   useInvalidation?: UseInvalidationParams;
   transferAuthorityInfo?: {
@@ -112,6 +139,7 @@ export type IssueParameters = {
   };
   rulesetId?: PublicKey;
   customInvalidators?: PublicKey[];
+  counted?: boolean;
 };
 
 /**
@@ -128,6 +156,7 @@ export const withIssueToken = async (
   wallet: Wallet,
   {
     claimPayment,
+    securityDeposit,
     timeInvalidation,
     useInvalidation,
     mint,
@@ -141,6 +170,7 @@ export const withIssueToken = async (
     receiptOptions = undefined,
     customInvalidators = undefined,
     rulesetId = undefined,
+    counted = false,
   }: IssueParameters,
   payer = wallet.publicKey
 ): Promise<[Transaction, PublicKey, Keypair | undefined]> => {
@@ -183,25 +213,42 @@ export const withIssueToken = async (
       ? 1
       : 0) +
     (transferAuthorityInfo?.creator ? 1 : 0);
-  const tokenManagerId = findTokenManagerAddress(mint);
   const mintCounterId = findMintCounterId(mint);
-  const tokenManagerIx = await tmManagerProgram.methods
-    .init({
-      amount: amount,
-      kind: kind,
-      invalidationType: invalidationType,
-      numInvalidators: numInvalidator,
-    })
-    .accounts({
-      tokenManager: tokenManagerId,
-      mintCounter: mintCounterId,
-      mint: mint,
-      issuer: wallet.publicKey,
-      payer: wallet.publicKey,
-      issuerTokenAccount: issuerTokenAccountId,
-      systemProgram: SystemProgram.programId,
-    })
-    .instruction();
+  let tokenManagerId = findTokenManagerAddress(mint);
+  if (counted) {
+    // counted token managers are derived from the next mint counter value
+    const mintCounterData = await tryGetAccount(() =>
+      tokenManager.accounts.getMintCounter(connection, mintCounterId)
+    );
+    tokenManagerId = findCountedTokenManagerAddress(
+      mint,
+      (mintCounterData?.parsed.count ?? new BN(0)).add(new BN(1))
+    );
+  }
+  const initParams = {
+    amount: amount,
+    kind: kind,
+    invalidationType: invalidationType,
+    numInvalidators: numInvalidator,
+  };
+  const initAccounts = {
+    tokenManager: tokenManagerId,
+    mintCounter: mintCounterId,
+    mint: mint,
+    issuer: wallet.publicKey,
+    payer: wallet.publicKey,
+    issuerTokenAccount: issuerTokenAccountId,
+    systemProgram: SystemProgram.programId,
+  };
+  const tokenManagerIx = counted
+    ? await tmManagerProgram.methods
+        .initCounted(initParams)
+        .accounts(initAccounts)
+        .instruction()
+    : await tmManagerProgram.methods
+        .init(initParams)
+        .accounts(initAccounts)
+        .instruction();
   transaction.add(tokenManagerIx);
 
   if (transferAuthorityInfo) {
@@ -268,6 +315,38 @@ export const withIssueToken = async (
       })
       .instruction();
     transaction.add(setClaimApproverIx);
+  } else if (securityDeposit) {
+    if (visibility !== "public") {
+      throw "Security deposit rentals currently must be public";
+    }
+    const securityDepositClaimApproverId =
+      findSecurityDepositClaimApproverAddress(tokenManagerId);
+    const securityDepositClaimApproverIx = await securityDepositProgram(
+      connection,
+      wallet
+    )
+      .methods.init({
+        depositMint: securityDeposit.depositMint ?? NATIVE_DEPOSIT_MINT,
+        depositAmount: new BN(securityDeposit.depositAmount),
+        collector: securityDeposit.collector || CRANK_KEY,
+      })
+      .accounts({
+        tokenManager: tokenManagerId,
+        claimApprover: securityDepositClaimApproverId,
+        issuer: wallet.publicKey,
+        payer: payer ?? wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+    transaction.add(securityDepositClaimApproverIx);
+    const setClaimApproverIx = await tmManagerProgram.methods
+      .setClaimApprover(securityDepositClaimApproverId)
+      .accounts({
+        tokenManager: tokenManagerId,
+        issuer: wallet.publicKey,
+      })
+      .instruction();
+    transaction.add(setClaimApproverIx);
   } else if (visibility === "private") {
     otp = Keypair.generate();
     const setClaimApproverIx = await tmManagerProgram.methods
@@ -322,6 +401,34 @@ export const withIssueToken = async (
             : null,
         disablePartialExtension:
           timeInvalidation.extension?.disablePartialExtension || null,
+        gracePeriodSeconds:
+          timeInvalidation.gracePeriodSeconds !== undefined
+            ? new BN(timeInvalidation.gracePeriodSeconds)
+            : null,
+        gracePeriodPenaltyBasisPoints:
+          timeInvalidation.extension?.gracePeriodPenaltyBasisPoints ?? null,
+        overduePenaltyAmount:
+          timeInvalidation.extension?.overduePenaltyAmount !== undefined
+            ? new BN(timeInvalidation.extension?.overduePenaltyAmount)
+            : null,
+        overduePenaltyDurationSeconds:
+          timeInvalidation.extension?.overduePenaltyDurationSeconds !==
+          undefined
+            ? new BN(timeInvalidation.extension?.overduePenaltyDurationSeconds)
+            : null,
+        overduePenaltyCollectorBasisPoints:
+          timeInvalidation.extension?.overduePenaltyCollectorBasisPoints ??
+          null,
+        refundBasisPoints:
+          timeInvalidation.extension?.refundBasisPoints ?? null,
+        escrowExtensionPayments:
+          timeInvalidation.extension?.escrowExtensionPayments ?? null,
+        extensionPriceTiers:
+          timeInvalidation.extension?.extensionPriceTiers?.map((tier) => ({
+            thresholdSeconds: new BN(tier.thresholdSeconds),
+            paymentAmount: new BN(tier.paymentAmount),
+            durationSeconds: new BN(tier.durationSeconds),
+          })) ?? null,
       })
       .accounts({
         tokenManager: tokenManagerId,
@@ -385,6 +492,29 @@ export const withIssueToken = async (
         extensionUsages: useInvalidation.extension?.extensionUsages
           ? new BN(useInvalidation.extension.extensionUsages)
           : null,
+        escrowExtensionPayments:
+          useInvalidation.extension?.escrowExtensionPayments ?? null,
+        usagePriceTiers:
+          useInvalidation.extension?.usagePriceTiers?.map((tier) => ({
+            minUsages: new BN(tier.minUsages),
+            paymentAmount: new BN(tier.paymentAmount),
+            extensionUsages: new BN(tier.extensionUsages),
+          })) ?? null,
+        minPricePerUsage: useInvalidation.extension?.minPricePerUsage
+          ? new BN(useInvalidation.extension.minPricePerUsage)
+          : null,
+        maxUsagesPerPeriod: useInvalidation.rateLimit
+          ? new BN(useInvalidation.rateLimit.maxUsagesPerPeriod)
+          : null,
+        periodSeconds: useInvalidation.rateLimit
+          ? new BN(useInvalidation.rateLimit.periodSeconds)
+          : null,
+        useAuthorities: useInvalidation.useAuthorities
+          ? useAuthorityQuotas(useInvalidation.useAuthorities)
+          : null,
+        usagesResetSeconds: useInvalidation.usagesResetSeconds
+          ? new BN(useInvalidation.usagesResetSeconds)
+          : null,
       })
       .accounts({
         tokenManager: tokenManagerId,
@@ -528,9 +658,12 @@ export const withClaimToken = async (
   buySideTokenAccountId?: PublicKey
 ): Promise<Transaction> => {
   const claimApproverId = findClaimApproverAddress(tokenManagerId);
+  const securityDepositClaimApproverId =
+    findSecurityDepositClaimApproverAddress(tokenManagerId);
   const accountData = await fetchAccountDataById(connection, [
     tokenManagerId,
     claimApproverId,
+    securityDepositClaimApproverId,
   ]);
   const tokenManagerInfo = accountData[tokenManagerId.toString()];
   if (!tokenManagerInfo?.data) throw "Token manager not found";
@@ -547,6 +680,18 @@ export const withClaimToken = async (
         CLAIM_APPROVER_IDL
       )
     : null;
+  const securityDepositClaimApproverInfo =
+    accountData[securityDepositClaimApproverId.toString()];
+  const securityDepositClaimApproverData = securityDepositClaimApproverInfo
+    ? tryDecodeIdlAccount<
+        "securityDepositClaimApprover",
+        SECURITY_DEPOSIT_PROGRAM
+      >(
+        securityDepositClaimApproverInfo,
+        "securityDepositClaimApprover",
+        SECURITY_DEPOSIT_IDL
+      )
+    : null;
 
   const metadataId = findMintMetadataId(tokenManagerData.parsed.mint);
   const metadata = await tryNull(
@@ -576,29 +721,46 @@ export const withClaimToken = async (
     tokenManagerData.parsed.claimApprover.toString() ===
       claimApproverId.toString()
   ) {
-    const payerTokenAccountId = getAssociatedTokenAddressSync(
-      claimApproverData.parsed.paymentMint,
-      wallet.publicKey
+    // native payments are paid from and to wallets
+    const nativePayment = claimApproverData.parsed.paymentMint.equals(
+      PublicKey.default
     );
+    const payerTokenAccountId = nativePayment
+      ? wallet.publicKey
+      : getAssociatedTokenAddressSync(
+          claimApproverData.parsed.paymentMint,
+          wallet.publicKey
+        );
 
     const [
       issuerTokenAccountId,
       feeCollectorTokenAccountId,
       remainingAccounts,
-    ] = await withRemainingAccountsForPayment(
-      transaction,
-      connection,
-      wallet,
-      tokenManagerData.parsed.mint,
-      claimApproverData.parsed.paymentMint,
-      tokenManagerData.parsed.issuer,
-      claimApproverData.parsed.paymentManager,
-      buySideTokenAccountId,
-      {
-        receiptMint: tokenManagerData.parsed.receiptMint,
-        payer: additionalOptions?.payer,
-      }
-    );
+    ] = nativePayment
+      ? await withRemainingAccountsForNativePayment(
+          transaction,
+          connection,
+          wallet,
+          tokenManagerData.parsed.mint,
+          tokenManagerData.parsed.issuer,
+          claimApproverData.parsed.paymentManager,
+          buySideTokenAccountId,
+          { receiptMint: tokenManagerData.parsed.receiptMint }
+        )
+      : await withRemainingAccountsForPayment(
+          transaction,
+          connection,
+          wallet,
+          tokenManagerData.parsed.mint,
+          claimApproverData.parsed.paymentMint,
+          tokenManagerData.parsed.issuer,
+          claimApproverData.parsed.paymentManager,
+          buySideTokenAccountId,
+          {
+            receiptMint: tokenManagerData.parsed.receiptMint,
+            payer: additionalOptions?.payer,
+          }
+        );
 
     const payIx = await claimApproverProgram(connection, wallet)
       .methods.pay()
@@ -619,6 +781,53 @@ export const withClaimToken = async (
       .remainingAccounts(remainingAccounts)
       .instruction();
     transaction.add(payIx);
+  } else if (
+    securityDepositClaimApproverData?.parsed &&
+    tokenManagerData.parsed.claimApprover &&
+    tokenManagerData.parsed.claimApprover.toString() ===
+      securityDepositClaimApproverId.toString()
+  ) {
+    const depositId = findDepositAddress(tokenManagerId, wallet.publicKey);
+    const depositMint = securityDepositClaimApproverData.parsed.depositMint;
+    const remainingAccounts: AccountMeta[] = [];
+    if (!depositMint.equals(NATIVE_DEPOSIT_MINT)) {
+      const depositTokenAccountId = getAssociatedTokenAddressSync(
+        depositMint,
+        depositId,
+        true
+      );
+      transaction.add(
+        createAssociatedTokenAccountIdempotentInstruction(
+          additionalOptions?.payer ?? wallet.publicKey,
+          depositTokenAccountId,
+          depositId,
+          depositMint
+        )
+      );
+      remainingAccounts.push(
+        {
+          pubkey: getAssociatedTokenAddressSync(depositMint, wallet.publicKey),
+          isSigner: false,
+          isWritable: true,
+        },
+        { pubkey: depositTokenAccountId, isSigner: false, isWritable: true }
+      );
+    }
+    const depositIx = await securityDepositProgram(connection, wallet)
+      .methods.deposit()
+      .accounts({
+        tokenManager: tokenManagerId,
+        claimApprover: securityDepositClaimApproverId,
+        deposit: depositId,
+        depositor: wallet.publicKey,
+        claimReceipt: claimReceiptId,
+        solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+    transaction.add(depositIx);
   } else if (tokenManagerData.parsed.claimApprover) {
     const createClaimReceiptIx = await tokenManagerProgram(connection, wallet)
       .methods.createClaimReceipt(wallet.publicKey)
@@ -839,6 +1048,13 @@ export const withInvalidate = async (
     timeInvalidatorData &&
     shouldTimeInvalidate(tokenManagerData, timeInvalidatorData, UTCNow)
   ) {
+    const settleExtensionEscrowAccounts =
+      await withRemainingAccountsForSettleExtensionEscrow(
+        transaction,
+        connection,
+        wallet,
+        timeInvalidatorData
+      );
     const invalidateIx = await tmeInvalidatorProgram.methods
       .invalidate()
       .accounts({
@@ -852,7 +1068,10 @@ export const withInvalidate = async (
         recipientTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
         rent: SYSVAR_RENT_PUBKEY,
       })
-      .remainingAccounts(remainingAccounts)
+      .remainingAccounts([
+        ...settleExtensionEscrowAccounts,
+        ...remainingAccounts,
+      ])
       .instruction();
     transaction.add(invalidateIx);
     const closeIx = await tmeInvalidatorProgram.methods
@@ -891,6 +1110,106 @@ export const withInvalidate = async (
   return transaction;
 };
 
+/**
+ * Invalidates expired time invalidated token managers in a single crank
+ * Entries that are already closed or have not expired are skipped
+ */
+export const withBatchTimeInvalidate = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintIds: PublicKey[]
+): Promise<Transaction> => {
+  const timeInvalidatorIds = mintIds.map((mintId) =>
+    timeInvalidator.pda.findTimeInvalidatorAddress(
+      tokenManagerAddressFromMint(mintId)
+    )
+  );
+  const timeInvalidatorDatas =
+    await timeInvalidator.accounts.getTimeInvalidators(
+      connection,
+      timeInvalidatorIds
+    );
+  const entries = [];
+  for (const [i, mintId] of mintIds.entries()) {
+    const timeInvalidatorData = timeInvalidatorDatas[i]!;
+    entries.push({
+      mintId,
+      invalidatorId: timeInvalidatorIds[i]!,
+      invalidatorAccounts: timeInvalidatorData.parsed
+        ? await withRemainingAccountsForSettleExtensionEscrow(
+            transaction,
+            connection,
+            wallet,
+            {
+              ...timeInvalidatorData,
+              parsed: timeInvalidatorData.parsed,
+            }
+          )
+        : [],
+    });
+  }
+  const [remainingAccountsLengths, remainingAccounts] =
+    await withRemainingAccountsForBatchInvalidate(
+      transaction,
+      connection,
+      wallet,
+      entries
+    );
+  const batchInvalidateIx = await timeInvalidatorProgram(connection, wallet)
+    .methods.batchInvalidate({
+      remainingAccountsLengths: Buffer.from(remainingAccountsLengths),
+    })
+    .accounts({
+      invalidator: wallet.publicKey,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  transaction.add(batchInvalidateIx);
+  return transaction;
+};
+
+/**
+ * Invalidates exhausted use invalidated token managers in a single crank
+ * Entries that are already closed or have usages remaining are skipped
+ */
+export const withBatchUseInvalidate = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintIds: PublicKey[]
+): Promise<Transaction> => {
+  const [remainingAccountsLengths, remainingAccounts] =
+    await withRemainingAccountsForBatchInvalidate(
+      transaction,
+      connection,
+      wallet,
+      mintIds.map((mintId) => ({
+        mintId,
+        invalidatorId: useInvalidator.pda.findUseInvalidatorAddress(
+          tokenManagerAddressFromMint(mintId)
+        ),
+      }))
+    );
+  const batchInvalidateIx = await useInvalidatorProgram(connection, wallet)
+    .methods.batchInvalidate({
+      remainingAccountsLengths: Buffer.from(remainingAccountsLengths),
+    })
+    .accounts({
+      invalidator: wallet.publicKey,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  transaction.add(batchInvalidateIx);
+  return transaction;
+};
+
 export const withReturn = async (
   transaction: Transaction,
   connection: Connection,
@@ -964,7 +1283,8 @@ export const withUse = async (
   wallet: Wallet,
   mintId: PublicKey,
   usages: number,
-  collector?: PublicKey
+  collector?: PublicKey,
+  memo?: number[]
 ): Promise<Transaction> => {
   const tokenManagerId = tokenManagerAddressFromMint(mintId);
   const usgInvalidatorProgram = useInvalidatorProgram(connection, wallet);
@@ -993,6 +1313,13 @@ export const withUse = async (
         extensionPaymentAmount: null,
         extensionPaymentMint: null,
         extensionUsages: null,
+        escrowExtensionPayments: null,
+        usagePriceTiers: null,
+        minPricePerUsage: null,
+        maxUsagesPerPeriod: null,
+        periodSeconds: null,
+        useAuthorities: null,
+        usagesResetSeconds: null,
       })
       .accounts({
         tokenManager: tokenManagerId,
@@ -1009,19 +1336,37 @@ export const withUse = async (
     throw new Error("Token manager has not been claimed");
 
   // use
-  const incrementUsagesIx = await usgInvalidatorProgram.methods
-    .incrementUsages(new BN(usages))
-    .accounts({
-      tokenManager: tokenManagerId,
-      useInvalidator: useInvalidatorId,
-      recipientTokenAccount: tokenManagerData?.parsed.recipientTokenAccount,
-      user: wallet.publicKey,
-    })
-    .instruction();
+  const incrementUsagesAccounts = {
+    tokenManager: tokenManagerId,
+    useInvalidator: useInvalidatorId,
+    recipientTokenAccount: tokenManagerData?.parsed.recipientTokenAccount,
+    user: wallet.publicKey,
+  };
+  // every use is recorded once the use invalidator has a usage log
+  const usageLogId = useInvalidatorData?.parsed.usageLog;
+  const usageLogAccounts = usageLogId
+    ? [{ pubkey: usageLogId, isSigner: false, isWritable: true }]
+    : [];
+  if (memo && !usageLogId) {
+    throw new Error("Memos are only recorded in a usage log");
+  }
+  const incrementUsagesIx = memo
+    ? await usgInvalidatorProgram.methods
+        .incrementUsagesWithMemo(new BN(usages), memo)
+        .accounts(incrementUsagesAccounts)
+        .remainingAccounts(usageLogAccounts)
+        .instruction()
+    : await usgInvalidatorProgram.methods
+        .incrementUsages(new BN(usages))
+        .accounts(incrementUsagesAccounts)
+        .remainingAccounts(usageLogAccounts)
+        .instruction();
   transaction.add(incrementUsagesIx);
 
+  // usages that reset each period stay valid after the last use of a period
   if (
     useInvalidatorData?.parsed.totalUsages &&
+    !useInvalidatorData?.parsed.usagesResetSeconds &&
     useInvalidatorData?.parsed.usages
       .add(new BN(usages))
       .gte(useInvalidatorData?.parsed.totalUsages)
@@ -1101,29 +1446,126 @@ export const withExtendExpiration = async (
   ]);
 
   if (timeInvalidatorData && timeInvalidatorData.parsed.extensionPaymentMint) {
-    const payerTokenAccountId = await findAta(
-      timeInvalidatorData.parsed.extensionPaymentMint,
-      wallet.publicKey
-    );
+    // native payments are paid from and to wallets
+    const nativePayment =
+      timeInvalidatorData.parsed.extensionPaymentMint.equals(PublicKey.default);
+    const payerTokenAccountId = nativePayment
+      ? wallet.publicKey
+      : await findAta(
+          timeInvalidatorData.parsed.extensionPaymentMint,
+          wallet.publicKey
+        );
 
     const [
       paymentTokenAccountId,
       feeCollectorTokenAccountId,
       remainingAccounts,
-    ] = await withRemainingAccountsForPayment(
-      transaction,
-      connection,
-      wallet,
-      tokenManagerData.parsed.mint,
-      timeInvalidatorData.parsed.extensionPaymentMint,
-      tokenManagerData.parsed.issuer,
-      timeInvalidatorData.parsed.paymentManager,
-      buySideTokenAccountId,
-      {
-        receiptMint: tokenManagerData.parsed.receiptMint,
-        payer: options?.payer,
-      }
+    ] = nativePayment
+      ? await withRemainingAccountsForNativePayment(
+          transaction,
+          connection,
+          wallet,
+          tokenManagerData.parsed.mint,
+          tokenManagerData.parsed.issuer,
+          timeInvalidatorData.parsed.paymentManager,
+          buySideTokenAccountId,
+          { receiptMint: tokenManagerData.parsed.receiptMint }
+        )
+      : await withRemainingAccountsForPayment(
+          transaction,
+          connection,
+          wallet,
+          tokenManagerData.parsed.mint,
+          timeInvalidatorData.parsed.extensionPaymentMint,
+          tokenManagerData.parsed.issuer,
+          timeInvalidatorData.parsed.paymentManager,
+          buySideTokenAccountId,
+          {
+            receiptMint: tokenManagerData.parsed.receiptMint,
+            payer: options?.payer,
+          }
+        );
+
+    // payment target accounts come before the penalty collector and escrow
+    const paymentTargetAccounts = remainingAccounts.splice(
+      0,
+      tokenManagerData.parsed.receiptMint ? 1 : 0
+    );
+    const extensionAccounts: AccountMeta[] = [];
+    const { collectorPenalty } = overduePenalty(
+      tokenManagerData,
+      timeInvalidatorData
     );
+    if (collectorPenalty.gt(new BN(0))) {
+      const collectorTokenAccountId = nativePayment
+        ? timeInvalidatorData.parsed.collector
+        : await withFindOrInitAssociatedTokenAccount(
+            transaction,
+            connection,
+            timeInvalidatorData.parsed.extensionPaymentMint,
+            timeInvalidatorData.parsed.collector,
+            options?.payer ?? wallet.publicKey,
+            true
+          );
+      extensionAccounts.push({
+        pubkey: collectorTokenAccountId,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    if (usesExtensionEscrow(timeInvalidatorData)) {
+      const extensionEscrowId =
+        timeInvalidator.pda.findExtensionEscrowAddress(timeInvalidatorId);
+      const escrowTokenAccountId = getAssociatedTokenAddressSync(
+        timeInvalidatorData.parsed.extensionPaymentMint,
+        extensionEscrowId,
+        true
+      );
+      const extensionEscrowData = await tryGetAccount(() =>
+        timeInvalidator.accounts.getExtensionEscrow(
+          connection,
+          extensionEscrowId
+        )
+      );
+      if (!extensionEscrowData) {
+        const initExtensionEscrowIx = await tmeInvalidatorProgram.methods
+          .initExtensionEscrow()
+          .accounts({
+            timeInvalidator: timeInvalidatorId,
+            extensionEscrow: extensionEscrowId,
+            escrowTokenAccount: escrowTokenAccountId,
+            paymentMint: timeInvalidatorData.parsed.extensionPaymentMint,
+            payer: options?.payer ?? wallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            tokenManager: tokenManagerId,
+            paymentTokenAccount: paymentTokenAccountId,
+            paymentManager: timeInvalidatorData.parsed.paymentManager,
+          })
+          .remainingAccounts(paymentTargetAccounts)
+          .instruction();
+        transaction.add(initExtensionEscrowIx);
+      } else if (
+        (extensionEscrowData.parsed.amount.gt(new BN(0)) ||
+          extensionEscrowData.parsed.seconds.gt(new BN(0))) &&
+        !extensionEscrowData.parsed.claimedAt.eq(
+          tokenManagerData.parsed.stateChangedAt
+        )
+      ) {
+        // escrow left over from an earlier rental is released before extending
+        await withReleaseEscrow(
+          transaction,
+          connection,
+          wallet,
+          tokenManagerId
+        );
+      }
+      extensionAccounts.push(
+        { pubkey: extensionEscrowId, isSigner: false, isWritable: true },
+        { pubkey: escrowTokenAccountId, isSigner: false, isWritable: true }
+      );
+    }
 
     const extendExpirationIx = await tmeInvalidatorProgram.methods
       .extendExpiration(new BN(secondsToAdd))
@@ -1137,8 +1579,13 @@ export const withExtendExpiration = async (
         payerTokenAccount: payerTokenAccountId,
         tokenProgram: TOKEN_PROGRAM_ID,
         solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+        systemProgram: SystemProgram.programId,
       })
-      .remainingAccounts(remainingAccounts)
+      .remainingAccounts([
+        ...paymentTargetAccounts,
+        ...extensionAccounts,
+        ...remainingAccounts,
+      ])
       .instruction();
     transaction.add(extendExpirationIx);
   } else {
@@ -1148,6 +1595,141 @@ export const withExtendExpiration = async (
   return transaction;
 };
 
+/**
+ * Returns a rental before it expires
+ * Pays the issuer for used time and refunds unused time to the recipient
+ */
+export const withEarlyReturn = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const tokenManagerId = tokenManagerAddressFromMint(mintId);
+  const timeInvalidatorId =
+    timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId);
+  const extensionEscrowId =
+    timeInvalidator.pda.findExtensionEscrowAddress(timeInvalidatorId);
+  const [tokenManagerData, extensionEscrowData, metadata] = await Promise.all([
+    tokenManager.accounts.getTokenManager(connection, tokenManagerId),
+    timeInvalidator.accounts.getExtensionEscrow(connection, extensionEscrowId),
+    tryNull(
+      Metadata.fromAccountAddress(connection, findMintMetadataId(mintId))
+    ),
+  ]);
+
+  const recipientPaymentTokenAccountId =
+    await withFindOrInitAssociatedTokenAccount(
+      transaction,
+      connection,
+      extensionEscrowData.parsed.paymentMint,
+      wallet.publicKey,
+      wallet.publicKey
+    );
+  const tokenManagerTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    mintId,
+    tokenManagerId,
+    wallet.publicKey,
+    true
+  );
+  const releaseAccounts = await withRemainingAccountsForEscrowRelease(
+    transaction,
+    connection,
+    wallet,
+    extensionEscrowData.parsed.paymentManager,
+    extensionEscrowData.parsed.paymentMint,
+    extensionEscrowData.parsed.mint
+  );
+  const remainingAccounts = await withRemainingAccountsForInvalidate(
+    transaction,
+    connection,
+    wallet,
+    mintId,
+    tokenManagerData,
+    wallet.publicKey,
+    metadata
+  );
+
+  const earlyReturnIx = await timeInvalidatorProgram(connection, wallet)
+    .methods.earlyReturn()
+    .accounts({
+      tokenManager: tokenManagerId,
+      timeInvalidator: timeInvalidatorId,
+      extensionEscrow: extensionEscrowId,
+      escrowTokenAccount: getAssociatedTokenAddressSync(
+        extensionEscrowData.parsed.paymentMint,
+        extensionEscrowId,
+        true
+      ),
+      paymentTokenAccount: extensionEscrowData.parsed.paymentTokenAccount,
+      paymentManager: extensionEscrowData.parsed.paymentManager,
+      recipient: wallet.publicKey,
+      recipientPaymentTokenAccount: recipientPaymentTokenAccountId,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenManagerTokenAccount: tokenManagerTokenAccountId,
+      mint: mintId,
+      recipientTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .remainingAccounts([...releaseAccounts, ...remainingAccounts])
+    .instruction();
+  transaction.add(earlyReturnIx);
+  return transaction;
+};
+
+/**
+ * Pays the issuer for the escrowed extension time used so far
+ * Once the rental is over everything left in the escrow is released
+ */
+export const withReleaseEscrow = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey
+): Promise<Transaction> => {
+  const timeInvalidatorId =
+    timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId);
+  const extensionEscrowId =
+    timeInvalidator.pda.findExtensionEscrowAddress(timeInvalidatorId);
+  const extensionEscrowData = await timeInvalidator.accounts.getExtensionEscrow(
+    connection,
+    extensionEscrowId
+  );
+
+  const releaseAccounts = await withRemainingAccountsForEscrowRelease(
+    transaction,
+    connection,
+    wallet,
+    extensionEscrowData.parsed.paymentManager,
+    extensionEscrowData.parsed.paymentMint,
+    extensionEscrowData.parsed.mint
+  );
+
+  const releaseEscrowIx = await timeInvalidatorProgram(connection, wallet)
+    .methods.releaseEscrow()
+    .accounts({
+      tokenManager: tokenManagerId,
+      timeInvalidator: timeInvalidatorId,
+      extensionEscrow: extensionEscrowId,
+      escrowTokenAccount: getAssociatedTokenAddressSync(
+        extensionEscrowData.parsed.paymentMint,
+        extensionEscrowId,
+        true
+      ),
+      paymentTokenAccount: extensionEscrowData.parsed.paymentTokenAccount,
+      paymentManager: extensionEscrowData.parsed.paymentManager,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .remainingAccounts(releaseAccounts)
+    .instruction();
+  transaction.add(releaseEscrowIx);
+  return transaction;
+};
+
 export const withExtendUsages = async (
   transaction: Transaction,
   connection: Connection,
@@ -1168,32 +1750,89 @@ export const withExtendUsages = async (
   ]);
 
   if (useInvalidatorData && useInvalidatorData.parsed.extensionPaymentMint) {
-    const payerTokenAccountId = await withFindOrInitAssociatedTokenAccount(
-      transaction,
-      connection,
-      useInvalidatorData.parsed.extensionPaymentMint,
-      wallet.publicKey,
-      wallet.publicKey
-    );
+    // native payments are paid from and to wallets
+    const nativePayment =
+      useInvalidatorData.parsed.extensionPaymentMint.equals(PublicKey.default);
+    const payerTokenAccountId = nativePayment
+      ? wallet.publicKey
+      : await withFindOrInitAssociatedTokenAccount(
+          transaction,
+          connection,
+          useInvalidatorData.parsed.extensionPaymentMint,
+          wallet.publicKey,
+          wallet.publicKey
+        );
 
     const [
       paymentTokenAccountId,
       feeCollectorTokenAccountId,
       remainingAccounts,
-    ] = await withRemainingAccountsForPayment(
-      transaction,
-      connection,
-      wallet,
-      tokenManagerData.parsed.mint,
-      useInvalidatorData.parsed.extensionPaymentMint,
-      tokenManagerData.parsed.issuer,
-      useInvalidatorData.parsed.paymentManager,
-      buySideTokenAccountId,
-      {
-        receiptMint: tokenManagerData.parsed.receiptMint,
-        payer: options?.payer,
-      }
+    ] = nativePayment
+      ? await withRemainingAccountsForNativePayment(
+          transaction,
+          connection,
+          wallet,
+          tokenManagerData.parsed.mint,
+          tokenManagerData.parsed.issuer,
+          useInvalidatorData.parsed.paymentManager,
+          buySideTokenAccountId,
+          { receiptMint: tokenManagerData.parsed.receiptMint }
+        )
+      : await withRemainingAccountsForPayment(
+          transaction,
+          connection,
+          wallet,
+          tokenManagerData.parsed.mint,
+          useInvalidatorData.parsed.extensionPaymentMint,
+          tokenManagerData.parsed.issuer,
+          useInvalidatorData.parsed.paymentManager,
+          buySideTokenAccountId,
+          {
+            receiptMint: tokenManagerData.parsed.receiptMint,
+            payer: options?.payer,
+          }
+        );
+
+    // escrow accounts follow the payment target accounts
+    const paymentTargetAccounts = remainingAccounts.splice(
+      0,
+      tokenManagerData.parsed.receiptMint ? 1 : 0
     );
+    const escrowAccounts: AccountMeta[] = [];
+    if (useInvalidatorData.parsed.escrowExtensionPayments) {
+      const usageEscrowId =
+        useInvalidator.pda.findUsageEscrowAddress(useInvalidatorId);
+      const escrowTokenAccountId = getAssociatedTokenAddressSync(
+        useInvalidatorData.parsed.extensionPaymentMint,
+        usageEscrowId,
+        true
+      );
+      const usageEscrowInfo = await connection.getAccountInfo(usageEscrowId);
+      if (!usageEscrowInfo) {
+        const initUsageEscrowIx = await usgInvalidatorProgram.methods
+          .initUsageEscrow()
+          .accounts({
+            useInvalidator: useInvalidatorId,
+            usageEscrow: usageEscrowId,
+            escrowTokenAccount: escrowTokenAccountId,
+            paymentMint: useInvalidatorData.parsed.extensionPaymentMint,
+            payer: options?.payer ?? wallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            tokenManager: tokenManagerId,
+            paymentTokenAccount: paymentTokenAccountId,
+            paymentManager: useInvalidatorData.parsed.paymentManager,
+          })
+          .remainingAccounts(paymentTargetAccounts)
+          .instruction();
+        transaction.add(initUsageEscrowIx);
+      }
+      escrowAccounts.push(
+        { pubkey: usageEscrowId, isSigner: false, isWritable: true },
+        { pubkey: escrowTokenAccountId, isSigner: false, isWritable: true }
+      );
+    }
 
     const extendUsagesIx = await usgInvalidatorProgram.methods
       .extendUsages(new BN(usagesToAdd))
@@ -1207,8 +1846,13 @@ export const withExtendUsages = async (
         payerTokenAccount: payerTokenAccountId,
         tokenProgram: TOKEN_PROGRAM_ID,
         solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+        systemProgram: SystemProgram.programId,
       })
-      .remainingAccounts(remainingAccounts)
+      .remainingAccounts([
+        ...paymentTargetAccounts,
+        ...escrowAccounts,
+        ...remainingAccounts,
+      ])
       .instruction();
     transaction.add(extendUsagesIx);
   }
@@ -1216,6 +1860,266 @@ export const withExtendUsages = async (
   return transaction;
 };
 
+/**
+ * Pays the issuer for the escrowed usages consumed so far
+ * Once the token manager is over everything left in the escrow is released
+ */
+export const withReleaseUsageEscrow = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey
+): Promise<Transaction> => {
+  const useInvalidatorId =
+    useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId);
+  const usageEscrowId =
+    useInvalidator.pda.findUsageEscrowAddress(useInvalidatorId);
+  const usageEscrowData = await useInvalidator.accounts.getUsageEscrow(
+    connection,
+    usageEscrowId
+  );
+
+  const releaseAccounts = await withRemainingAccountsForEscrowRelease(
+    transaction,
+    connection,
+    wallet,
+    usageEscrowData.parsed.paymentManager,
+    usageEscrowData.parsed.paymentMint,
+    usageEscrowData.parsed.mint
+  );
+
+  const releaseEscrowIx = await useInvalidatorProgram(connection, wallet)
+    .methods.releaseEscrow()
+    .accounts({
+      tokenManager: tokenManagerId,
+      useInvalidator: useInvalidatorId,
+      usageEscrow: usageEscrowId,
+      escrowTokenAccount: getAssociatedTokenAddressSync(
+        usageEscrowData.parsed.paymentMint,
+        usageEscrowId,
+        true
+      ),
+      paymentTokenAccount: usageEscrowData.parsed.paymentTokenAccount,
+      paymentManager: usageEscrowData.parsed.paymentManager,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .remainingAccounts(releaseAccounts)
+    .instruction();
+  transaction.add(releaseEscrowIx);
+  return transaction;
+};
+
+/**
+ * Refunds a security deposit once the token is returned
+ * or can no longer be claimed with the depositor's receipt
+ * Forfeits it to the issuer when the token cannot be recovered
+ * Invalidation does not settle the deposit, anyone can crank this after it
+ */
+export const withResolveDeposit = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  depositor = wallet.publicKey
+): Promise<Transaction> => {
+  const depositId = findDepositAddress(tokenManagerId, depositor);
+  const claimReceiptId = tokenManager.pda.findClaimReceiptId(
+    tokenManagerId,
+    depositor
+  );
+  const accountData = await fetchAccountDataById(connection, [
+    depositId,
+    tokenManagerId,
+    claimReceiptId,
+  ]);
+  const depositInfo = accountData[depositId.toString()];
+  if (!depositInfo?.data) throw "Deposit not found";
+  const depositData = decodeIdlAccount<"deposit", SECURITY_DEPOSIT_PROGRAM>(
+    depositInfo,
+    "deposit",
+    SECURITY_DEPOSIT_IDL
+  );
+  const tokenManagerInfo = accountData[tokenManagerId.toString()];
+  const tokenManagerData = tokenManagerInfo
+    ? tryDecodeIdlAccount<"tokenManager", TOKEN_MANAGER_PROGRAM>(
+        tokenManagerInfo,
+        "tokenManager",
+        TOKEN_MANAGER_IDL
+      )
+    : null;
+
+  // the claim receipt is closed when the depositor claims
+  const claimed = !accountData[claimReceiptId.toString()];
+  const forfeit =
+    claimed &&
+    tokenManagerData?.parsed?.state === TokenManagerState.Invalidated;
+  const issuer = tokenManagerData?.parsed?.issuer ?? depositor;
+  const destination = forfeit ? issuer : depositor;
+
+  const remainingAccounts: AccountMeta[] = [];
+  if (!depositData.parsed.mint.equals(NATIVE_DEPOSIT_MINT)) {
+    const destinationTokenAccountId = getAssociatedTokenAddressSync(
+      depositData.parsed.mint,
+      destination,
+      true
+    );
+    transaction.add(
+      createAssociatedTokenAccountIdempotentInstruction(
+        wallet.publicKey,
+        destinationTokenAccountId,
+        destination,
+        depositData.parsed.mint
+      )
+    );
+    remainingAccounts.push(
+      {
+        pubkey: getAssociatedTokenAddressSync(
+          depositData.parsed.mint,
+          depositId,
+          true
+        ),
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: destinationTokenAccountId, isSigner: false, isWritable: true }
+    );
+  }
+
+  const resolveIx = await securityDepositProgram(connection, wallet)
+    .methods.resolve()
+    .accounts({
+      tokenManager: tokenManagerId,
+      deposit: depositId,
+      claimReceipt: claimReceiptId,
+      depositor: depositor,
+      issuer: issuer,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  transaction.add(resolveIx);
+  return transaction;
+};
+
+/**
+ * Grows a time invalidator created before its latest fields were added
+ * Does nothing if it is already the current size
+ */
+export const withResizeTimeInvalidator = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey
+): Promise<Transaction> => {
+  const resizeIx = await timeInvalidatorProgram(connection, wallet)
+    .methods.resize()
+    .accounts({
+      timeInvalidator:
+        timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId),
+      payer: wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(resizeIx);
+  return transaction;
+};
+
+/**
+ * Creates a usage log that records every later use of this token manager
+ * Use invalidators created before usage logs are resized first
+ */
+export const withInitUsageLog = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  payer = wallet.publicKey
+): Promise<Transaction> => {
+  const useInvalidatorId =
+    useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId);
+  await withResizeUseInvalidator(
+    transaction,
+    connection,
+    wallet,
+    tokenManagerId
+  );
+  const initUsageLogIx = await useInvalidatorProgram(connection, wallet)
+    .methods.initUsageLog()
+    .accounts({
+      tokenManager: tokenManagerId,
+      useInvalidator: useInvalidatorId,
+      usageLog: useInvalidator.pda.findUsageLogAddress(useInvalidatorId),
+      issuer: wallet.publicKey,
+      payer,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(initUsageLogIx);
+  return transaction;
+};
+
+const useAuthorityQuotas = (useAuthorities: UseAuthorityQuota[]) =>
+  useAuthorities.map(({ authority, quota }) => ({
+    authority,
+    quota: quota !== undefined ? new BN(quota) : null,
+  }));
+
+/**
+ * Replaces the use authorities allowed to use this token manager
+ * Usages already counted against a kept authority carry over to its new quota
+ * Use invalidators created before use authorities are resized first
+ */
+export const withSetUseAuthorities = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  useAuthorities: UseAuthorityQuota[]
+): Promise<Transaction> => {
+  await withResizeUseInvalidator(
+    transaction,
+    connection,
+    wallet,
+    tokenManagerId
+  );
+  const setUseAuthoritiesIx = await useInvalidatorProgram(connection, wallet)
+    .methods.setUseAuthorities({
+      useAuthorities: useAuthorityQuotas(useAuthorities),
+    })
+    .accounts({
+      tokenManager: tokenManagerId,
+      useInvalidator:
+        useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId),
+      issuer: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(setUseAuthoritiesIx);
+  return transaction;
+};
+
+/**
+ * Grows a use invalidator created before its latest fields were added
+ * Does nothing if it is already the current size
+ */
+export const withResizeUseInvalidator = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey
+): Promise<Transaction> => {
+  const resizeIx = await useInvalidatorProgram(connection, wallet)
+    .methods.resize()
+    .accounts({
+      useInvalidator:
+        useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId),
+      payer: wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(resizeIx);
+  return transaction;
+};
+
 export const withResetExpiration = async (
   transaction: Transaction,
   connection: Connection,
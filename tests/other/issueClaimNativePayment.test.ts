@@ -0,0 +1,98 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { rentals } from "../../src";
+import { tokenManager } from "../../src/programs";
+import { TokenManagerState } from "../../src/programs/tokenManager";
+
+describe("Issue Claim Native Payment", () => {
+  let provider: SolanaProvider;
+  const RENTAL_PAYMENT_AMOUNT = LAMPORTS_PER_SOL / 100;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Claim pays the issuer in native SOL", async () => {
+    const [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        claimPayment: {
+          paymentAmount: RENTAL_PAYMENT_AMOUNT,
+          paymentMint: PublicKey.default,
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const issuerLamportsBefore = await provider.connection.getBalance(
+      user.publicKey
+    );
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+    const issuerLamportsAfter = await provider.connection.getBalance(
+      user.publicKey
+    );
+    expect(issuerLamportsAfter - issuerLamportsBefore).toEqual(
+      RENTAL_PAYMENT_AMOUNT
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Claimed);
+
+    const checkRecipientTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(rentalMint, recipient.publicKey)
+    );
+    expect(checkRecipientTokenAccount.amount.toString()).toEqual("1");
+  });
+});
@@ -0,0 +1,91 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { issueToken } from "../../src";
+import { tokenManager } from "../../src/programs";
+import { TokenManagerState } from "../../src/programs/tokenManager";
+
+describe("Issue Counted", () => {
+  let provider: SolanaProvider;
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user),
+      { amount: 2 }
+    );
+  });
+
+  it("Issue two counted token managers for the same mint", async () => {
+    const tokenManagerIds: PublicKey[] = [];
+    for (let i = 0; i < 2; i++) {
+      const [transaction, tokenManagerId] = await issueToken(
+        provider.connection,
+        new Wallet(user),
+        {
+          mint: rentalMint,
+          issuerTokenAccountId: issuerTokenAccountId,
+          amount: new BN(1),
+          counted: true,
+        }
+      );
+      await executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(user)
+      );
+      tokenManagerIds.push(tokenManagerId);
+    }
+    expect(tokenManagerIds[0]!.toString()).not.toEqual(
+      tokenManagerIds[1]!.toString()
+    );
+
+    for (let i = 0; i < 2; i++) {
+      const tokenManagerId = tokenManagerIds[i]!;
+      expect(tokenManagerId.toString()).toEqual(
+        tokenManager.pda
+          .findCountedTokenManagerAddress(rentalMint, new BN(i + 1))
+          .toString()
+      );
+      const tokenManagerData = await tokenManager.accounts.getTokenManager(
+        provider.connection,
+        tokenManagerId
+      );
+      expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Issued);
+      expect(tokenManagerData.parsed.count.toNumber()).toEqual(i + 1);
+      expect(tokenManagerData.parsed.amount.toNumber()).toEqual(1);
+    }
+
+    const mintCounterData = await tokenManager.accounts.getMintCounter(
+      provider.connection,
+      tokenManager.pda.findMintCounterId(rentalMint)
+    );
+    expect(mintCounterData.parsed.count.toNumber()).toEqual(2);
+
+    const checkIssuerTokenAccount = await getAccount(
+      provider.connection,
+      issuerTokenAccountId
+    );
+    expect(checkIssuerTokenAccount.amount.toString()).toEqual("0");
+  });
+});
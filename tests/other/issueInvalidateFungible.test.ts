@@ -0,0 +1,120 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+
+import { invalidate, issueToken } from "../../src";
+import { tokenManager } from "../../src/programs";
+import { TokenManagerState } from "../../src/programs/tokenManager";
+
+describe("Issue Invalidate Fungible", () => {
+  let provider: SolanaProvider;
+  const MINT_AMOUNT = 100;
+  const ISSUE_AMOUNT = 10;
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    // create fungible rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user),
+      { amount: MINT_AMOUNT }
+    );
+  });
+
+  it("Fails to issue more than the issuer holds", async () => {
+    const [transaction] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        timeInvalidation: { maxExpiration: Date.now() / 1000 },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(MINT_AMOUNT + 1),
+      }
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(user))
+    ).rejects.toThrow();
+  });
+
+  it("Issue fungible amount", async () => {
+    const [transaction, tokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        timeInvalidation: { maxExpiration: Date.now() / 1000 },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(ISSUE_AMOUNT),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Issued);
+    expect(tokenManagerData.parsed.amount.toNumber()).toEqual(ISSUE_AMOUNT);
+
+    const checkIssuerTokenAccount = await getAccount(
+      provider.connection,
+      issuerTokenAccountId
+    );
+    expect(checkIssuerTokenAccount.amount.toString()).toEqual(
+      (MINT_AMOUNT - ISSUE_AMOUNT).toString()
+    );
+  });
+
+  it("Invalidate", async () => {
+    await new Promise((r) => setTimeout(r, 2000));
+
+    const transaction = await invalidate(
+      provider.connection,
+      new Wallet(user),
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+    const tokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(provider.connection, tokenManagerId)
+    );
+    expect(tokenManagerData).toEqual(null);
+
+    const checkIssuerTokenAccount = await getAccount(
+      provider.connection,
+      issuerTokenAccountId
+    );
+    expect(checkIssuerTokenAccount.amount.toString()).toEqual(
+      MINT_AMOUNT.toString()
+    );
+  });
+});
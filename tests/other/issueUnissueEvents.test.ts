@@ -0,0 +1,108 @@
+import { BN, BorshCoder, EventParser, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { issueToken, unissueToken } from "../../src";
+import {
+  TOKEN_MANAGER_ADDRESS,
+  TOKEN_MANAGER_IDL,
+} from "../../src/programs/tokenManager";
+
+describe("Issue Unissue Events", () => {
+  let provider: SolanaProvider;
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerTokenAccountId: PublicKey;
+  let kind: number;
+  const eventParser = new EventParser(
+    TOKEN_MANAGER_ADDRESS,
+    new BorshCoder(TOKEN_MANAGER_IDL)
+  );
+
+  const getEvents = async (txid: string) => {
+    const tx = await provider.connection.getTransaction(txid, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    return [...eventParser.parseLogs(tx?.meta?.logMessages ?? [])];
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Emits issued event", async () => {
+    const [transaction, tokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    const txid = await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const events = await getEvents(txid);
+    const issued = events.find((e) => e.name === "TokenManagerIssued");
+    expect(issued).toBeTruthy();
+    expect(issued?.data.tokenManager.toString()).toEqual(
+      tokenManagerId.toString()
+    );
+    expect(issued?.data.mint.toString()).toEqual(rentalMint.toString());
+    expect(issued?.data.issuer.toString()).toEqual(user.publicKey.toString());
+    expect(issued?.data.recipientTokenAccount).toBeTruthy();
+    expect(Number(issued?.data.timestamp)).toBeGreaterThan(0);
+    tokenManagerTokenAccountId = issued?.data
+      .recipientTokenAccount as PublicKey;
+    kind = issued?.data.kind as number;
+  });
+
+  it("Emits unissued event", async () => {
+    const transaction = await unissueToken(
+      provider.connection,
+      new Wallet(user),
+      rentalMint
+    );
+    const txid = await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const events = await getEvents(txid);
+    const unissued = events.find((e) => e.name === "TokenManagerUnissued");
+    expect(unissued).toBeTruthy();
+    expect(unissued?.data.mint.toString()).toEqual(rentalMint.toString());
+    expect(unissued?.data.issuer.toString()).toEqual(
+      user.publicKey.toString()
+    );
+    expect(unissued?.data.recipientTokenAccount.toString()).toEqual(
+      tokenManagerTokenAccountId.toString()
+    );
+    expect(unissued?.data.kind).toEqual(kind);
+    expect(Number(unissued?.data.timestamp)).toBeGreaterThan(0);
+  });
+});
@@ -0,0 +1,142 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { rentals } from "../../src";
+import { claimApprover, timeInvalidator } from "../../src/programs";
+
+describe("Quotes", () => {
+  let provider: SolanaProvider;
+  const RECIPIENT_START_PAYMENT_AMOUNT = 1000;
+  const CLAIM_PAYMENT_AMOUNT = 10;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let recipientPaymentTokenAccountId: PublicKey;
+  let issuerTokenAccountId: PublicKey;
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  const getRecipientPaymentAmount = async () =>
+    new BN(
+      (
+        await getAccount(provider.connection, recipientPaymentTokenAccountId)
+      ).amount.toString()
+    );
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create payment mint
+    [recipientPaymentTokenAccountId, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Quote claim matches the claim payment", async () => {
+    let transaction;
+    [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        claimPayment: {
+          paymentAmount: CLAIM_PAYMENT_AMOUNT,
+          paymentMint: paymentMint,
+        },
+        timeInvalidation: {
+          durationSeconds: 1000,
+          extension: {
+            extensionPaymentAmount: 10,
+            extensionDurationSeconds: 1000,
+            extensionPaymentMint: paymentMint,
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const quote = await claimApprover.utils.quoteClaim(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    expect(quote.toNumber()).toEqual(CLAIM_PAYMENT_AMOUNT);
+
+    const balanceBefore = await getRecipientPaymentAmount();
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+    const balanceAfter = await getRecipientPaymentAmount();
+    expect(balanceBefore.sub(balanceAfter).toString()).toEqual(
+      quote.toString()
+    );
+  });
+
+  it("Quote extension matches the extension payment", async () => {
+    const quote = await timeInvalidator.utils.quoteExtension(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      2000
+    );
+    expect(quote.toNumber()).toEqual(20);
+
+    const balanceBefore = await getRecipientPaymentAmount();
+    const transaction = await rentals.extendRentalExpiration(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      2000
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+    const balanceAfter = await getRecipientPaymentAmount();
+    expect(balanceBefore.sub(balanceAfter).toString()).toEqual(
+      quote.toString()
+    );
+  });
+});
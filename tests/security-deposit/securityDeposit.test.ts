@@ -0,0 +1,198 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+
+import { invalidate, rentals, resolveDeposit } from "../../src";
+import { securityDeposit, tokenManager } from "../../src/programs";
+import {
+  InvalidationType,
+  TokenManagerState,
+} from "../../src/programs/tokenManager";
+
+describe("Security deposit", () => {
+  let provider: SolanaProvider;
+  const RECIPIENT_START_DEPOSIT_AMOUNT = 1000;
+  const DEPOSIT_AMOUNT = 100;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let depositMint: PublicKey;
+  let recipientDepositTokenAccountId: PublicKey;
+
+  const issueRental = async (
+    invalidationType: InvalidationType,
+    mint?: PublicKey
+  ) => {
+    const [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+    const [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        securityDeposit: {
+          depositMint: mint,
+          depositAmount: DEPOSIT_AMOUNT,
+        },
+        timeInvalidation: { durationSeconds: 1 },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+        invalidationType,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+    return [tokenManagerId, rentalMint] as const;
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create deposit mint
+    [recipientDepositTokenAccountId, depositMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_DEPOSIT_AMOUNT }
+    );
+  });
+
+  it("Refunds a token deposit once the rental is reissued", async () => {
+    const [tokenManagerId, rentalMint] = await issueRental(
+      InvalidationType.Reissue,
+      depositMint
+    );
+    const checkRecipientDepositTokenAccount = await getAccount(
+      provider.connection,
+      recipientDepositTokenAccountId
+    );
+    expect(checkRecipientDepositTokenAccount.amount.toString()).toEqual(
+      (RECIPIENT_START_DEPOSIT_AMOUNT - DEPOSIT_AMOUNT).toString()
+    );
+
+    // locked while the rental is claimed
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await resolveDeposit(
+          provider.connection,
+          new Wallet(recipient),
+          tokenManagerId
+        ),
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+
+    await new Promise((r) => setTimeout(r, 2000));
+    await executeTransaction(
+      provider.connection,
+      await invalidate(provider.connection, new Wallet(recipient), rentalMint),
+      new Wallet(recipient)
+    );
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Issued);
+
+    await executeTransaction(
+      provider.connection,
+      await resolveDeposit(
+        provider.connection,
+        new Wallet(recipient),
+        tokenManagerId
+      ),
+      new Wallet(recipient)
+    );
+    const checkRefundedTokenAccount = await getAccount(
+      provider.connection,
+      recipientDepositTokenAccountId
+    );
+    expect(checkRefundedTokenAccount.amount.toString()).toEqual(
+      RECIPIENT_START_DEPOSIT_AMOUNT.toString()
+    );
+
+    const depositData = await tryGetAccount(() =>
+      securityDeposit.accounts.getDeposit(
+        provider.connection,
+        securityDeposit.pda.findDepositAddress(
+          tokenManagerId,
+          recipient.publicKey
+        )
+      )
+    );
+    expect(depositData).toEqual(null);
+  });
+
+  it("Forfeits a native deposit when the token is not returned", async () => {
+    const [tokenManagerId, rentalMint] = await issueRental(
+      InvalidationType.Invalidate
+    );
+
+    await new Promise((r) => setTimeout(r, 2000));
+    await executeTransaction(
+      provider.connection,
+      await invalidate(provider.connection, new Wallet(recipient), rentalMint),
+      new Wallet(recipient)
+    );
+
+    const issuerLamportsBefore = await provider.connection.getBalance(
+      user.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      await resolveDeposit(
+        provider.connection,
+        new Wallet(recipient),
+        tokenManagerId
+      ),
+      new Wallet(recipient)
+    );
+    const issuerLamportsAfter = await provider.connection.getBalance(
+      user.publicKey
+    );
+    expect(issuerLamportsAfter - issuerLamportsBefore).toEqual(DEPOSIT_AMOUNT);
+
+    // the recipient keeps the token
+    const checkRecipientTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(rentalMint, recipient.publicKey)
+    );
+    expect(checkRecipientTokenAccount.amount.toString()).toEqual("1");
+  });
+});
@@ -0,0 +1,180 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SYSVAR_RENT_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+
+import { invalidate, issueToken, withBatchTimeInvalidate } from "../../src";
+import { timeInvalidator, tokenManager } from "../../src/programs";
+import { timeInvalidatorProgram } from "../../src/programs/timeInvalidator";
+import {
+  TOKEN_MANAGER_ADDRESS,
+  TokenManagerState,
+} from "../../src/programs/tokenManager";
+
+describe("Batch time invalidate", () => {
+  let provider: SolanaProvider;
+  const user = Keypair.generate();
+  // expired, unexpired, closed before the batch and a second unexpired rental
+  const rentals: { mint: PublicKey; issuerTokenAccountId: PublicKey }[] = [];
+
+  const issueRental = async (maxExpiration: number) => {
+    const [issuerTokenAccountId, mint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+    const [transaction] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        timeInvalidation: { maxExpiration },
+        mint,
+        issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+    rentals.push({ mint, issuerTokenAccountId });
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    await issueRental(Date.now() / 1000 + 2);
+    await issueRental(Date.now() / 1000 + 1000);
+    await issueRental(Date.now() / 1000 + 2);
+    await issueRental(Date.now() / 1000 + 1000);
+    await new Promise((r) => setTimeout(r, 4000));
+  });
+
+  it("Invalidate one rental before the batch", async () => {
+    const transaction = await invalidate(
+      provider.connection,
+      new Wallet(user),
+      rentals[2]!.mint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const tokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(
+        provider.connection,
+        tokenManager.pda.tokenManagerAddressFromMint(rentals[2]!.mint)
+      )
+    );
+    expect(tokenManagerData).toEqual(null);
+  });
+
+  it("Batch invalidate skips closed and unexpired entries", async () => {
+    const transaction = await withBatchTimeInvalidate(
+      new Transaction(),
+      provider.connection,
+      new Wallet(user),
+      rentals.slice(0, 3).map(({ mint }) => mint)
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    // expired rental is returned
+    const expiredTokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(
+        provider.connection,
+        tokenManager.pda.tokenManagerAddressFromMint(rentals[0]!.mint)
+      )
+    );
+    expect(expiredTokenManagerData).toEqual(null);
+    const checkIssuerTokenAccount = await getAccount(
+      provider.connection,
+      rentals[0]!.issuerTokenAccountId
+    );
+    expect(checkIssuerTokenAccount.amount.toString()).toEqual("1");
+
+    // unexpired rental is left issued
+    const unexpiredTokenManagerData =
+      await tokenManager.accounts.getTokenManager(
+        provider.connection,
+        tokenManager.pda.tokenManagerAddressFromMint(rentals[1]!.mint)
+      );
+    expect(unexpiredTokenManagerData.parsed.state).toEqual(
+      TokenManagerState.Issued
+    );
+  });
+
+  it("Batch invalidate fails for a mismatched time invalidator", async () => {
+    const { mint } = rentals[1]!;
+    const tokenManagerId = tokenManager.pda.tokenManagerAddressFromMint(mint);
+    const otherTimeInvalidatorId =
+      timeInvalidator.pda.findTimeInvalidatorAddress(
+        tokenManager.pda.tokenManagerAddressFromMint(rentals[3]!.mint)
+      );
+    const tokenManagerTokenAccountId = getAssociatedTokenAddressSync(
+      mint,
+      tokenManagerId,
+      true
+    );
+
+    const transaction = new Transaction();
+    transaction.add(
+      await timeInvalidatorProgram(provider.connection, new Wallet(user))
+        .methods.batchInvalidate({
+          remainingAccountsLengths: Buffer.from([0]),
+        })
+        .accounts({
+          invalidator: user.publicKey,
+          solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .remainingAccounts([
+          { pubkey: tokenManagerId, isSigner: false, isWritable: true },
+          { pubkey: otherTimeInvalidatorId, isSigner: false, isWritable: true },
+          {
+            pubkey: tokenManagerTokenAccountId,
+            isSigner: false,
+            isWritable: true,
+          },
+          { pubkey: mint, isSigner: false, isWritable: true },
+          {
+            pubkey: tokenManagerTokenAccountId,
+            isSigner: false,
+            isWritable: true,
+          },
+        ])
+        .instruction()
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(user))
+    ).rejects.toThrow();
+  });
+});
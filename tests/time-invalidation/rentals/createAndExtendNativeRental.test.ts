@@ -0,0 +1,118 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { rentals } from "../../../src";
+import { timeInvalidator, tokenManager } from "../../../src/programs";
+
+describe("Create and Extend Native Rental", () => {
+  let provider: SolanaProvider;
+  const EXTENSION_PAYMENT_AMOUNT = LAMPORTS_PER_SOL / 100;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create and claim rental", async () => {
+    let transaction;
+    [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        timeInvalidation: {
+          durationSeconds: 1000,
+          extension: {
+            extensionPaymentAmount: EXTENSION_PAYMENT_AMOUNT,
+            extensionDurationSeconds: 1000,
+            extensionPaymentMint: PublicKey.default,
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+  });
+
+  it("Extend pays the issuer in native SOL", async () => {
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    const issuerLamportsBefore = await provider.connection.getBalance(
+      user.publicKey
+    );
+
+    const transaction = await rentals.extendRentalExpiration(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      1000
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const issuerLamportsAfter = await provider.connection.getBalance(
+      user.publicKey
+    );
+    expect(issuerLamportsAfter - issuerLamportsBefore).toEqual(
+      EXTENSION_PAYMENT_AMOUNT
+    );
+
+    const timeInvalidatorData =
+      await timeInvalidator.accounts.getTimeInvalidator(
+        provider.connection,
+        timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId)
+      );
+    expect(timeInvalidatorData.parsed.expiration?.toNumber()).toEqual(
+      tokenManagerData.parsed.stateChangedAt.toNumber() + 2000
+    );
+  });
+});
@@ -0,0 +1,160 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { rentals } from "../../../src";
+import { timeInvalidator, tokenManager } from "../../../src/programs";
+import { TokenManagerState } from "../../../src/programs/tokenManager";
+
+describe("Create and Extend Overdue Rental", () => {
+  let provider: SolanaProvider;
+  const RECIPIENT_START_PAYMENT_AMOUNT = 10000;
+  const EXTENSION_PAYMENT_AMOUNT = 10;
+  const OVERDUE_PENALTY_AMOUNT = 100;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  const collector = Keypair.generate();
+  let recipientPaymentTokenAccountId: PublicKey;
+  let issuerTokenAccountId: PublicKey;
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create payment mint
+    [recipientPaymentTokenAccountId, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create rental", async () => {
+    const [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        timeInvalidation: {
+          collector: collector.publicKey,
+          durationSeconds: 1,
+          gracePeriodSeconds: 1000,
+          extension: {
+            extensionPaymentAmount: EXTENSION_PAYMENT_AMOUNT,
+            extensionDurationSeconds: 1000,
+            extensionPaymentMint: paymentMint,
+            overduePenaltyAmount: OVERDUE_PENALTY_AMOUNT,
+            overduePenaltyDurationSeconds: 1,
+            overduePenaltyCollectorBasisPoints: 5000,
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const checkTimeInvalidator =
+      await timeInvalidator.accounts.getTimeInvalidator(
+        provider.connection,
+        timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId)
+      );
+    expect(
+      checkTimeInvalidator.parsed.overduePenaltyAmount?.toNumber()
+    ).toEqual(OVERDUE_PENALTY_AMOUNT);
+    expect(
+      checkTimeInvalidator.parsed.overduePenaltyCollectorBasisPoints
+    ).toEqual(5000);
+  });
+
+  it("Claim rental", async () => {
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+
+    const transaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Claimed);
+  });
+
+  it("Extend overdue rental pays the penalty", async () => {
+    await new Promise((r) => setTimeout(r, 3000));
+
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+    const transaction = await rentals.extendRentalExpiration(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      1000
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    // at least two overdue seconds are charged and half goes to the collector
+    const collectorTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMint, collector.publicKey)
+    );
+    expect(Number(collectorTokenAccount.amount)).toBeGreaterThanOrEqual(
+      OVERDUE_PENALTY_AMOUNT
+    );
+
+    const checkRecipientPaymentTokenAccount = await getAccount(
+      provider.connection,
+      recipientPaymentTokenAccountId
+    );
+    expect(
+      RECIPIENT_START_PAYMENT_AMOUNT -
+        Number(checkRecipientPaymentTokenAccount.amount)
+    ).toBeGreaterThanOrEqual(
+      EXTENSION_PAYMENT_AMOUNT + 2 * OVERDUE_PENALTY_AMOUNT
+    );
+  });
+});
@@ -0,0 +1,178 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { rentals, withResizeTimeInvalidator } from "../../../src";
+import { timeInvalidator } from "../../../src/programs";
+import { timeInvalidatorProgram } from "../../../src/programs/timeInvalidator";
+
+describe("Create and Extend Tiered Rental", () => {
+  let provider: SolanaProvider;
+  const RECIPIENT_START_PAYMENT_AMOUNT = 1000;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let recipientPaymentTokenAccountId: PublicKey;
+  let issuerTokenAccountId: PublicKey;
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create payment mint
+    [recipientPaymentTokenAccountId, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create and claim rental", async () => {
+    let transaction;
+    [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        timeInvalidation: {
+          durationSeconds: 1000,
+          extension: {
+            extensionPaymentAmount: 10,
+            extensionDurationSeconds: 1000,
+            extensionPaymentMint: paymentMint,
+            // half price for every second past the first 2000
+            extensionPriceTiers: [
+              {
+                thresholdSeconds: 2000,
+                paymentAmount: 5,
+                durationSeconds: 1000,
+              },
+            ],
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+  });
+
+  it("Extend charges the blended price", async () => {
+    const transaction = await rentals.extendRentalExpiration(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      3000
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const checkRecipientPaymentTokenAccount = await getAccount(
+      provider.connection,
+      recipientPaymentTokenAccountId
+    );
+    expect(checkRecipientPaymentTokenAccount.amount.toString()).toEqual(
+      (RECIPIENT_START_PAYMENT_AMOUNT - 25).toString()
+    );
+  });
+
+  it("Resize leaves a current time invalidator unchanged", async () => {
+    const timeInvalidatorId =
+      timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId);
+    const accountInfoBefore = await provider.connection.getAccountInfo(
+      timeInvalidatorId
+    );
+
+    const transaction = await withResizeTimeInvalidator(
+      new Transaction(),
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const accountInfoAfter = await provider.connection.getAccountInfo(
+      timeInvalidatorId
+    );
+    expect(accountInfoAfter?.data.length).toEqual(
+      accountInfoBefore?.data.length
+    );
+    expect(accountInfoAfter?.lamports).toEqual(accountInfoBefore?.lamports);
+  });
+
+  it("Resize fails for an account that is not a time invalidator", async () => {
+    const transaction = new Transaction();
+    transaction.add(
+      await timeInvalidatorProgram(provider.connection, new Wallet(recipient))
+        .methods.resize()
+        .accounts({
+          timeInvalidator: timeInvalidator.pda.findExtensionEscrowAddress(
+            timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId)
+          ),
+          payer: recipient.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction()
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+  });
+});
@@ -0,0 +1,200 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { earlyReturn, invalidate, rentals } from "../../../src";
+import { timeInvalidator, tokenManager } from "../../../src/programs";
+import {
+  InvalidationType,
+  TokenManagerState,
+} from "../../../src/programs/tokenManager";
+
+describe("Create rental extension escrow direct return", () => {
+  let provider: SolanaProvider;
+  const RECIPIENT_START_PAYMENT_AMOUNT = 1000;
+  const EXTENSION_PAYMENT_AMOUNT = 10;
+  const recipient = Keypair.generate();
+  const nextRecipient = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+
+  const getExtensionEscrow = async () => {
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+    return timeInvalidator.accounts.getExtensionEscrow(
+      provider.connection,
+      timeInvalidator.pda.findExtensionEscrowAddress(
+        timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId)
+      )
+    );
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient, nextRecipient]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create payment mint
+    [, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create rental", async () => {
+    const [transaction] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        timeInvalidation: {
+          durationSeconds: 1000,
+          extension: {
+            extensionPaymentAmount: EXTENSION_PAYMENT_AMOUNT,
+            extensionDurationSeconds: 2,
+            extensionPaymentMint: paymentMint,
+            refundBasisPoints: 10000,
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+        invalidationType: InvalidationType.Reissue,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+  });
+
+  it("Claim and extend rental", async () => {
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+
+    const extendTransaction = await rentals.extendRentalExpiration(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      2
+    );
+    await executeTransaction(
+      provider.connection,
+      extendTransaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    const extensionEscrowData = await getExtensionEscrow();
+    expect(extensionEscrowData.parsed.amount.toNumber()).toEqual(
+      EXTENSION_PAYMENT_AMOUNT
+    );
+    expect(extensionEscrowData.parsed.claimedAt.toNumber()).toEqual(
+      tokenManagerData.parsed.stateChangedAt.toNumber()
+    );
+  });
+
+  it("Recipient returns without settling the escrow", async () => {
+    const transaction = await invalidate(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint)
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Issued);
+
+    // direct token manager invalidation leaves the escrow in place
+    const extensionEscrowData = await getExtensionEscrow();
+    expect(extensionEscrowData.parsed.amount.toNumber()).toEqual(
+      EXTENSION_PAYMENT_AMOUNT
+    );
+  });
+
+  it("Next renter cannot be refunded the previous prepaid time", async () => {
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(nextRecipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(nextRecipient)
+    );
+
+    const transaction = await earlyReturn(
+      provider.connection,
+      new Wallet(nextRecipient),
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(nextRecipient)
+    );
+
+    const extensionEscrowData = await getExtensionEscrow();
+    expect(extensionEscrowData.parsed.amount.toNumber()).toEqual(0);
+    expect(extensionEscrowData.parsed.seconds.toNumber()).toEqual(0);
+
+    const nextRecipientPaymentTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMint, nextRecipient.publicKey)
+    );
+    expect(nextRecipientPaymentTokenAccount.amount.toString()).toEqual("0");
+    const issuerPaymentTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMint, user.publicKey)
+    );
+    expect(issuerPaymentTokenAccount.amount.toString()).toEqual(
+      EXTENSION_PAYMENT_AMOUNT.toString()
+    );
+  });
+});
@@ -0,0 +1,166 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { invalidate, rentals } from "../../../src";
+import { timeInvalidator, tokenManager } from "../../../src/programs";
+import {
+  InvalidationType,
+  TokenManagerState,
+} from "../../../src/programs/tokenManager";
+
+describe("Create rental extension escrow reissue", () => {
+  let provider: SolanaProvider;
+  const RECIPIENT_START_PAYMENT_AMOUNT = 1000;
+  const EXTENSION_PAYMENT_AMOUNT = 10;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+
+  const getExtensionEscrow = async () => {
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+    return timeInvalidator.accounts.getExtensionEscrow(
+      provider.connection,
+      timeInvalidator.pda.findExtensionEscrowAddress(
+        timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId)
+      )
+    );
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create payment mint
+    [, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create rental", async () => {
+    const [transaction] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        timeInvalidation: {
+          durationSeconds: 1,
+          extension: {
+            extensionPaymentAmount: EXTENSION_PAYMENT_AMOUNT,
+            extensionDurationSeconds: 2,
+            extensionPaymentMint: paymentMint,
+            refundBasisPoints: 10000,
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+        invalidationType: InvalidationType.Reissue,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+  });
+
+  it("Claim and extend rental", async () => {
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+
+    const extendTransaction = await rentals.extendRentalExpiration(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      2
+    );
+    await executeTransaction(
+      provider.connection,
+      extendTransaction,
+      new Wallet(recipient)
+    );
+
+    // payment target is fixed when the escrow is created
+    const extensionEscrowData = await getExtensionEscrow();
+    expect(extensionEscrowData.parsed.amount.toNumber()).toEqual(
+      EXTENSION_PAYMENT_AMOUNT
+    );
+    expect(extensionEscrowData.parsed.seconds.toNumber()).toEqual(2);
+    expect(extensionEscrowData.parsed.paymentTokenAccount.toString()).toEqual(
+      (await findAta(paymentMint, user.publicKey)).toString()
+    );
+  });
+
+  it("Invalidate settles and resets the escrow", async () => {
+    await new Promise((r) => setTimeout(r, 4000));
+
+    const transaction = await invalidate(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint)
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Issued);
+
+    const extensionEscrowData = await getExtensionEscrow();
+    expect(extensionEscrowData.parsed.amount.toNumber()).toEqual(0);
+    expect(extensionEscrowData.parsed.seconds.toNumber()).toEqual(0);
+
+    const issuerPaymentTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMint, user.publicKey)
+    );
+    expect(issuerPaymentTokenAccount.amount.toString()).toEqual(
+      EXTENSION_PAYMENT_AMOUNT.toString()
+    );
+  });
+});
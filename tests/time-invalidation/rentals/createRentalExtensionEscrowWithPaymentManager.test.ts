@@ -0,0 +1,227 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+import { DEFAULT_BUY_SIDE_FEE_SHARE } from "@solana-nft-programs/payment-manager";
+import { findPaymentManagerAddress } from "@solana-nft-programs/payment-manager/dist/cjs/pda";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+
+import { invalidate, rentals } from "../../../src";
+import { timeInvalidator, tokenManager } from "../../../src/programs";
+import {
+  InvalidationType,
+  TokenManagerState,
+} from "../../../src/programs/tokenManager";
+
+describe("Create rental extension escrow with payment manager", () => {
+  let provider: SolanaProvider;
+  const RECIPIENT_START_PAYMENT_AMOUNT = 100000;
+  const EXTENSION_PAYMENT_AMOUNT = 1000;
+  const MAKER_FEE = 500;
+  const TAKER_FEE = 300;
+  const BASIS_POINTS_DIVISOR = 10000;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  const paymentManagerName = Math.random().toString(36).slice(2, 7);
+  const feeCollector = Keypair.generate();
+  let recipientPaymentTokenAccountId: PublicKey;
+  let issuerTokenAccountId: PublicKey;
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+  let paymentManagerId: PublicKey;
+
+  const takerFee = Math.ceil(
+    (EXTENSION_PAYMENT_AMOUNT * TAKER_FEE) / BASIS_POINTS_DIVISOR
+  );
+
+  const getExtensionEscrow = async () => {
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+    return timeInvalidator.accounts.getExtensionEscrow(
+      provider.connection,
+      timeInvalidator.pda.findExtensionEscrowAddress(
+        timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId)
+      )
+    );
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create payment mint
+    [recipientPaymentTokenAccountId, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+
+    paymentManagerId = findPaymentManagerAddress(paymentManagerName);
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, new Wallet(user), {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: MAKER_FEE,
+      takerFeeBasisPoints: TAKER_FEE,
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      payer: user.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, new Wallet(user));
+  });
+
+  it("Create rental", async () => {
+    const [transaction] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        timeInvalidation: {
+          paymentManager: paymentManagerId,
+          durationSeconds: 1,
+          extension: {
+            extensionPaymentAmount: EXTENSION_PAYMENT_AMOUNT,
+            extensionDurationSeconds: 2,
+            extensionPaymentMint: paymentMint,
+            escrowExtensionPayments: true,
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+        invalidationType: InvalidationType.Reissue,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+  });
+
+  it("Claim and extend rental into escrow", async () => {
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+
+    const extendTransaction = await rentals.extendRentalExpiration(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      2
+    );
+    await executeTransaction(
+      provider.connection,
+      extendTransaction,
+      new Wallet(recipient)
+    );
+
+    const extensionEscrowData = await getExtensionEscrow();
+    expect(extensionEscrowData.parsed.amount.toNumber()).toEqual(
+      EXTENSION_PAYMENT_AMOUNT
+    );
+    expect(extensionEscrowData.parsed.paymentManager.toString()).toEqual(
+      paymentManagerId.toString()
+    );
+
+    // the taker fee paid on release is escrowed on top of the extension
+    const escrowTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMint, extensionEscrowData.pubkey, true)
+    );
+    expect(escrowTokenAccount.amount.toString()).toEqual(
+      (EXTENSION_PAYMENT_AMOUNT + takerFee).toString()
+    );
+    const recipientPaymentTokenAccount = await getAccount(
+      provider.connection,
+      recipientPaymentTokenAccountId
+    );
+    expect(recipientPaymentTokenAccount.amount.toString()).toEqual(
+      (
+        RECIPIENT_START_PAYMENT_AMOUNT -
+        EXTENSION_PAYMENT_AMOUNT -
+        takerFee
+      ).toString()
+    );
+  });
+
+  it("Invalidate pays the escrow out through the payment manager", async () => {
+    await new Promise((r) => setTimeout(r, 4000));
+
+    const transaction = await invalidate(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint)
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Issued);
+
+    const extensionEscrowData = await getExtensionEscrow();
+    expect(extensionEscrowData.parsed.amount.toNumber()).toEqual(0);
+    expect(extensionEscrowData.parsed.seconds.toNumber()).toEqual(0);
+
+    const buySideFee = Math.floor(
+      (EXTENSION_PAYMENT_AMOUNT * DEFAULT_BUY_SIDE_FEE_SHARE) /
+        BASIS_POINTS_DIVISOR
+    );
+    const fees = Math.floor(
+      (EXTENSION_PAYMENT_AMOUNT * (MAKER_FEE + TAKER_FEE)) /
+        BASIS_POINTS_DIVISOR
+    );
+    const feeCollectorTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMint, feeCollector.publicKey)
+    );
+    expect(feeCollectorTokenAccount.amount.toString()).toEqual(
+      (fees + buySideFee).toString()
+    );
+    const issuerPaymentTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMint, user.publicKey)
+    );
+    expect(issuerPaymentTokenAccount.amount.toString()).toEqual(
+      (EXTENSION_PAYMENT_AMOUNT + takerFee - fees - buySideFee).toString()
+    );
+  });
+});
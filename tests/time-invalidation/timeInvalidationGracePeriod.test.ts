@@ -0,0 +1,207 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SYSVAR_RENT_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+
+import { invalidate, rentals } from "../../src";
+import { timeInvalidator, tokenManager } from "../../src/programs";
+import { timeInvalidatorProgram } from "../../src/programs/timeInvalidator";
+import { shouldTimeInvalidate } from "../../src/programs/timeInvalidator/utils";
+import {
+  TOKEN_MANAGER_ADDRESS,
+  TokenManagerState,
+} from "../../src/programs/tokenManager";
+
+describe("Time invalidation grace period", () => {
+  let provider: SolanaProvider;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  const durationSeconds = 1;
+  const gracePeriodSeconds = 5;
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create rental", async () => {
+    const [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        timeInvalidation: {
+          durationSeconds,
+          gracePeriodSeconds,
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const checkTimeInvalidator =
+      await timeInvalidator.accounts.getTimeInvalidator(
+        provider.connection,
+        timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId)
+      );
+    expect(checkTimeInvalidator.parsed.gracePeriodSeconds?.toNumber()).toEqual(
+      gracePeriodSeconds
+    );
+  });
+
+  it("Claim rental", async () => {
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+
+    const transaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Claimed);
+  });
+
+  it("Fails to invalidate within the grace period", async () => {
+    await new Promise((r) => setTimeout(r, 2000));
+
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+    const timeInvalidatorId =
+      timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId);
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    const timeInvalidatorData =
+      await timeInvalidator.accounts.getTimeInvalidator(
+        provider.connection,
+        timeInvalidatorId
+      );
+    expect(shouldTimeInvalidate(tokenManagerData, timeInvalidatorData)).toEqual(
+      false
+    );
+
+    const transaction = new Transaction();
+    transaction.add(
+      await timeInvalidatorProgram(provider.connection, new Wallet(recipient))
+        .methods.invalidate()
+        .accounts({
+          tokenManager: tokenManagerId,
+          timeInvalidator: timeInvalidatorId,
+          invalidator: recipient.publicKey,
+          solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenManagerTokenAccount: getAssociatedTokenAddressSync(
+            rentalMint,
+            tokenManagerId,
+            true
+          ),
+          mint: rentalMint,
+          recipientTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .remainingAccounts([
+          {
+            pubkey: tokenManager.pda.findMintManagerId(rentalMint),
+            isSigner: false,
+            isWritable: true,
+          },
+          { pubkey: issuerTokenAccountId, isSigner: false, isWritable: true },
+        ])
+        .instruction()
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+
+    const recipientAtaId = await findAta(rentalMint, recipient.publicKey);
+    const checkRecipientTokenAccount = await getAccount(
+      provider.connection,
+      recipientAtaId
+    );
+    expect(checkRecipientTokenAccount.amount.toString()).toEqual("1");
+  });
+
+  it("Invalidate after the grace period", async () => {
+    await new Promise((r) => setTimeout(r, gracePeriodSeconds * 1000));
+
+    const transaction = await invalidate(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerId =
+      tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+    const tokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(provider.connection, tokenManagerId)
+    );
+    expect(tokenManagerData).toEqual(null);
+
+    const checkIssuerTokenAccount = await getAccount(
+      provider.connection,
+      issuerTokenAccountId
+    );
+    expect(checkIssuerTokenAccount.amount.toString()).toEqual("1");
+  });
+});
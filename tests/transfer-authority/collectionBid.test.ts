@@ -0,0 +1,267 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  createCreateMasterEditionV3Instruction,
+  createCreateMetadataAccountV3Instruction,
+  createVerifyCollectionInstruction,
+} from "@metaplex-foundation/mpl-token-metadata";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  findMintEditionId,
+  findMintMetadataId,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  withAcceptCollectionBid,
+  withInitMarketplace,
+  withInitTransferAuthority,
+  withMakeCollectionBid,
+  withWrapToken,
+} from "../../src";
+import { getCollectionBid } from "../../src/programs/transferAuthority/accounts";
+import { findCollectionBidEscrowAddress } from "../../src/programs/transferAuthority/pda";
+
+describe("Collection Bid", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `lst-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const holder = Keypair.generate();
+  const buyer = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  let collectionMint: PublicKey;
+  let rentalMint: PublicKey;
+  let otherMint: PublicKey;
+  let collectionBidId: PublicKey;
+  const bidPaymentAmount = new BN(1197485);
+
+  const createNft = async (collection?: PublicKey) => {
+    const [, mint] = await createMint(provider.connection, new Wallet(holder));
+    const metadataId = findMintMetadataId(mint);
+    const tx = new Transaction();
+    tx.add(
+      createCreateMetadataAccountV3Instruction(
+        {
+          metadata: metadataId,
+          updateAuthority: holder.publicKey,
+          mint: mint,
+          mintAuthority: holder.publicKey,
+          payer: holder.publicKey,
+        },
+        {
+          createMetadataAccountArgsV3: {
+            data: {
+              name: "test",
+              symbol: "TST",
+              uri: "http://test/",
+              sellerFeeBasisPoints: 0,
+              creators: null,
+              collection: collection
+                ? { key: collection, verified: false }
+                : null,
+              uses: null,
+            },
+            isMutable: true,
+            collectionDetails: null,
+          },
+        }
+      ),
+      createCreateMasterEditionV3Instruction(
+        {
+          edition: findMintEditionId(mint),
+          metadata: metadataId,
+          updateAuthority: holder.publicKey,
+          mint: mint,
+          mintAuthority: holder.publicKey,
+          payer: holder.publicKey,
+        },
+        {
+          createMasterEditionArgs: {
+            maxSupply: new BN(0),
+          },
+        }
+      )
+    );
+    if (collection) {
+      tx.add(
+        createVerifyCollectionInstruction({
+          metadata: metadataId,
+          collectionAuthority: holder.publicKey,
+          payer: holder.publicKey,
+          collectionMint: collection,
+          collection: findMintMetadataId(collection),
+          collectionMasterEditionAccount: findMintEditionId(collection),
+        })
+      );
+    }
+    await executeTransaction(provider.connection, tx, new Wallet(holder));
+    return mint;
+  };
+
+  const wrap = async (mint: PublicKey) => {
+    const wrapTransaction = new Transaction();
+    await withWrapToken(
+      wrapTransaction,
+      provider.connection,
+      new Wallet(holder),
+      mint,
+      { transferAuthorityName: transferAuthorityName }
+    );
+    await executeTransaction(
+      provider.connection,
+      wrapTransaction,
+      new Wallet(holder)
+    );
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [holder, buyer, feeCollector]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: 360,
+      takerFeeBasisPoints: 640,
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    collectionMint = await createNft();
+    rentalMint = await createNft(collectionMint);
+    otherMint = await createNft();
+    await wrap(rentalMint);
+    await wrap(otherMint);
+  });
+
+  it("Make collection bid", async () => {
+    const transaction = new Transaction();
+    [, collectionBidId] = await withMakeCollectionBid(
+      transaction,
+      provider.connection,
+      new Wallet(buyer),
+      transferAuthorityName,
+      marketplaceName,
+      collectionMint,
+      bidPaymentAmount
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(buyer)
+    );
+
+    const collectionBidData = await getCollectionBid(
+      provider.connection,
+      collectionBidId
+    );
+    expect(collectionBidData.parsed.collection.toString()).toEqual(
+      collectionMint.toString()
+    );
+    expect(collectionBidData.parsed.paymentAmount.toString()).toEqual(
+      bidPaymentAmount.toString()
+    );
+  });
+
+  it("Accept fails for a mint outside the collection", async () => {
+    const transaction = new Transaction();
+    await withAcceptCollectionBid(
+      transaction,
+      provider.connection,
+      new Wallet(holder),
+      otherMint,
+      collectionBidId,
+      bidPaymentAmount
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(holder), {
+        signers: [buyer],
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Accept fails without the buyer signature", async () => {
+    const transaction = new Transaction();
+    await withAcceptCollectionBid(
+      transaction,
+      provider.connection,
+      new Wallet(holder),
+      rentalMint,
+      collectionBidId,
+      bidPaymentAmount
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(holder))
+    ).rejects.toThrow();
+  });
+
+  it("Accept transfers the token to the buyer", async () => {
+    const transaction = new Transaction();
+    await withAcceptCollectionBid(
+      transaction,
+      provider.connection,
+      new Wallet(holder),
+      rentalMint,
+      collectionBidId,
+      bidPaymentAmount
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(holder),
+      { signers: [buyer] }
+    );
+
+    const buyerMintTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(rentalMint, buyer.publicKey, true)
+    );
+    expect(buyerMintTokenAccount.amount.toString()).toEqual("1");
+    expect(
+      await provider.connection.getBalance(
+        findCollectionBidEscrowAddress(collectionBidId)
+      )
+    ).toEqual(0);
+    const collectionBidData = await tryGetAccount(() =>
+      getCollectionBid(provider.connection, collectionBidId)
+    );
+    expect(collectionBidData).toEqual(null);
+  });
+});
@@ -0,0 +1,228 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  createCreateMasterEditionV3Instruction,
+  createCreateMetadataAccountV3Instruction,
+} from "@metaplex-foundation/mpl-token-metadata";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  findMintEditionId,
+  findMintMetadataId,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  withAcceptDutchAuction,
+  withCreateDutchAuction,
+  withInitMarketplace,
+  withInitTransferAuthority,
+  withWrapToken,
+} from "../../src";
+import { getDutchAuction } from "../../src/programs/transferAuthority/accounts";
+
+describe("Dutch Auction", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `lst-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const lister = Keypair.generate();
+  const buyer = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  let rentalMint: PublicKey;
+  const startAmount = new BN(2000000);
+  const floorAmount = new BN(1000000);
+  const TAKER_FEE = new BN(640);
+
+  const createNft = async () => {
+    const [, mint] = await createMint(provider.connection, new Wallet(lister));
+    const metadataId = findMintMetadataId(mint);
+    const tx = new Transaction();
+    tx.add(
+      createCreateMetadataAccountV3Instruction(
+        {
+          metadata: metadataId,
+          updateAuthority: lister.publicKey,
+          mint: mint,
+          mintAuthority: lister.publicKey,
+          payer: lister.publicKey,
+        },
+        {
+          createMetadataAccountArgsV3: {
+            data: {
+              name: "test",
+              symbol: "TST",
+              uri: "http://test/",
+              sellerFeeBasisPoints: 0,
+              creators: null,
+              collection: null,
+              uses: null,
+            },
+            isMutable: true,
+            collectionDetails: null,
+          },
+        }
+      ),
+      createCreateMasterEditionV3Instruction(
+        {
+          edition: findMintEditionId(mint),
+          metadata: metadataId,
+          updateAuthority: lister.publicKey,
+          mint: mint,
+          mintAuthority: lister.publicKey,
+          payer: lister.publicKey,
+        },
+        {
+          createMasterEditionArgs: {
+            maxSupply: new BN(0),
+          },
+        }
+      )
+    );
+    await executeTransaction(provider.connection, tx, new Wallet(lister));
+
+    const wrapTransaction = new Transaction();
+    await withWrapToken(
+      wrapTransaction,
+      provider.connection,
+      new Wallet(lister),
+      mint,
+      { transferAuthorityName: transferAuthorityName }
+    );
+    await executeTransaction(
+      provider.connection,
+      wrapTransaction,
+      new Wallet(lister)
+    );
+    return mint;
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [lister, buyer, feeCollector]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: 360,
+      takerFeeBasisPoints: TAKER_FEE.toNumber(),
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    rentalMint = await createNft();
+  });
+
+  it("Create dutch auction", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const [transaction] = await withCreateDutchAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(lister),
+      rentalMint,
+      marketplaceName,
+      {
+        startAmount: startAmount,
+        floorAmount: floorAmount,
+        startAt: now - 500,
+        endAt: now + 500,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+
+    const dutchAuctionData = await getDutchAuction(
+      provider.connection,
+      rentalMint
+    );
+    expect(dutchAuctionData.parsed.startAmount.toString()).toEqual(
+      startAmount.toString()
+    );
+    expect(dutchAuctionData.parsed.floorAmount.toString()).toEqual(
+      floorAmount.toString()
+    );
+  });
+
+  it("Accept fails below the decayed price", async () => {
+    const transaction = await withAcceptDutchAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(buyer),
+      rentalMint,
+      floorAmount
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(buyer))
+    ).rejects.toThrow();
+  });
+
+  it("Accept at the decayed price", async () => {
+    const listerLamportsBefore = await provider.connection.getBalance(
+      lister.publicKey
+    );
+    const transaction = await withAcceptDutchAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(buyer),
+      rentalMint,
+      startAmount
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(buyer)
+    );
+
+    const buyerMintTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(rentalMint, buyer.publicKey, true)
+    );
+    expect(buyerMintTokenAccount.amount.toString()).toEqual("1");
+    const dutchAuctionData = await tryGetAccount(() =>
+      getDutchAuction(provider.connection, rentalMint)
+    );
+    expect(dutchAuctionData).toBeNull();
+    // half way through the decay the price is above the floor
+    expect(
+      await provider.connection.getBalance(lister.publicKey)
+    ).toBeGreaterThan(listerLamportsBefore + floorAmount.toNumber());
+  });
+});
@@ -0,0 +1,507 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  createCreateMasterEditionV3Instruction,
+  createCreateMetadataAccountV3Instruction,
+} from "@metaplex-foundation/mpl-token-metadata";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  findMintEditionId,
+  findMintMetadataId,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  withAcceptDutchAuction,
+  withAcceptOffer,
+  withClaimEnglishAuction,
+  withCloseEnglishAuction,
+  withCreateDutchAuction,
+  withCreateEnglishAuction,
+  withCreateListing,
+  withInitMarketplace,
+  withInitTransferAuthority,
+  withMakeOffer,
+  withPlaceBid,
+  withRelease,
+  withSettleEnglishAuction,
+  withWrapToken,
+} from "../../src";
+import { getEnglishAuction } from "../../src/programs/transferAuthority/accounts";
+import {
+  findEnglishAuctionAddress,
+  findEnglishAuctionEscrowAddress,
+  findTransferAuthorityAddress,
+} from "../../src/programs/transferAuthority/pda";
+
+describe("English Auction", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `lst-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const lister = Keypair.generate();
+  const bidder = Keypair.generate();
+  const otherBidder = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  let rentalMint: PublicKey;
+  let releasedMint: PublicKey;
+  const reserveAmount = new BN(1000000);
+  const minIncrement = new BN(100000);
+  const TAKER_FEE = new BN(640);
+  const BASIS_POINTS_DIVISOR = new BN(10000);
+
+  const escrowAmount = (bid: BN) =>
+    bid.add(bid.mul(TAKER_FEE).div(BASIS_POINTS_DIVISOR));
+
+  const createNft = async () => {
+    const [, mint] = await createMint(provider.connection, new Wallet(lister));
+    const metadataId = findMintMetadataId(mint);
+    const tx = new Transaction();
+    tx.add(
+      createCreateMetadataAccountV3Instruction(
+        {
+          metadata: metadataId,
+          updateAuthority: lister.publicKey,
+          mint: mint,
+          mintAuthority: lister.publicKey,
+          payer: lister.publicKey,
+        },
+        {
+          createMetadataAccountArgsV3: {
+            data: {
+              name: "test",
+              symbol: "TST",
+              uri: "http://test/",
+              sellerFeeBasisPoints: 0,
+              creators: null,
+              collection: null,
+              uses: null,
+            },
+            isMutable: true,
+            collectionDetails: null,
+          },
+        }
+      ),
+      createCreateMasterEditionV3Instruction(
+        {
+          edition: findMintEditionId(mint),
+          metadata: metadataId,
+          updateAuthority: lister.publicKey,
+          mint: mint,
+          mintAuthority: lister.publicKey,
+          payer: lister.publicKey,
+        },
+        {
+          createMasterEditionArgs: {
+            maxSupply: new BN(0),
+          },
+        }
+      )
+    );
+    await executeTransaction(provider.connection, tx, new Wallet(lister));
+
+    const wrapTransaction = new Transaction();
+    await withWrapToken(
+      wrapTransaction,
+      provider.connection,
+      new Wallet(lister),
+      mint,
+      { transferAuthorityName: transferAuthorityName }
+    );
+    await executeTransaction(
+      provider.connection,
+      wrapTransaction,
+      new Wallet(lister)
+    );
+    return mint;
+  };
+
+  const placeBid = async (keypair: Keypair, mint: PublicKey, bid: BN) => {
+    const transaction = await withPlaceBid(
+      new Transaction(),
+      provider.connection,
+      new Wallet(keypair),
+      mint,
+      bid
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(keypair)
+    );
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [lister, bidder, otherBidder, feeCollector]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: 360,
+      takerFeeBasisPoints: TAKER_FEE.toNumber(),
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    rentalMint = await createNft();
+    releasedMint = await createNft();
+  });
+
+  it("Create dutch auction and offer before the english auction", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const [transaction] = await withCreateDutchAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(lister),
+      rentalMint,
+      marketplaceName,
+      {
+        startAmount: reserveAmount,
+        floorAmount: reserveAmount,
+        startAt: now - 10,
+        endAt: now + 1000,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+
+    const offerTransaction = await withMakeOffer(
+      new Transaction(),
+      provider.connection,
+      new Wallet(otherBidder),
+      rentalMint,
+      marketplaceName,
+      reserveAmount
+    );
+    await executeTransaction(
+      provider.connection,
+      offerTransaction,
+      new Wallet(otherBidder)
+    );
+  });
+
+  it("Create english auction", async () => {
+    const endAt = Math.floor(Date.now() / 1000) + 8;
+    const [transaction] = await withCreateEnglishAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(lister),
+      rentalMint,
+      marketplaceName,
+      {
+        reserveAmount: reserveAmount,
+        minIncrement: minIncrement,
+        endAt: endAt,
+        antiSnipeSeconds: 1,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+
+    const englishAuctionData = await getEnglishAuction(
+      provider.connection,
+      rentalMint
+    );
+    expect(englishAuctionData.parsed.endAt.toNumber()).toEqual(endAt);
+    expect(englishAuctionData.parsed.highestBidder).toBeNull();
+    expect(englishAuctionData.parsed.settled).toBeFalsy();
+  });
+
+  it("Dutch auction is blocked by the english auction", async () => {
+    const transaction = await withAcceptDutchAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(bidder),
+      rentalMint,
+      reserveAmount
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(bidder))
+    ).rejects.toThrow();
+  });
+
+  it("Offer is blocked by the english auction", async () => {
+    const transaction = await withAcceptOffer(
+      new Transaction(),
+      provider.connection,
+      new Wallet(lister),
+      rentalMint,
+      otherBidder.publicKey,
+      reserveAmount
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(lister), {
+        signers: [otherBidder],
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Listing is blocked by the english auction", async () => {
+    const [transaction] = await withCreateListing(
+      new Transaction(),
+      provider.connection,
+      new Wallet(lister),
+      rentalMint,
+      marketplaceName,
+      reserveAmount
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(lister))
+    ).rejects.toThrow();
+  });
+
+  it("Bid below the reserve fails", async () => {
+    await expect(
+      placeBid(bidder, rentalMint, reserveAmount.sub(new BN(1)))
+    ).rejects.toThrow();
+  });
+
+  it("Outbid refunds the previous bidder", async () => {
+    await placeBid(bidder, rentalMint, reserveAmount);
+    const bidderLamportsBefore = await provider.connection.getBalance(
+      bidder.publicKey
+    );
+
+    const outbid = reserveAmount.add(minIncrement);
+    await placeBid(otherBidder, rentalMint, outbid);
+
+    const englishAuctionData = await getEnglishAuction(
+      provider.connection,
+      rentalMint
+    );
+    expect(englishAuctionData.parsed.highestBidder?.toString()).toEqual(
+      otherBidder.publicKey.toString()
+    );
+    expect(englishAuctionData.parsed.highestBid.toString()).toEqual(
+      outbid.toString()
+    );
+    expect(await provider.connection.getBalance(bidder.publicKey)).toEqual(
+      bidderLamportsBefore + escrowAmount(reserveAmount).toNumber()
+    );
+    expect(
+      await provider.connection.getBalance(
+        findEnglishAuctionEscrowAddress(englishAuctionData.pubkey)
+      )
+    ).toEqual(escrowAmount(outbid).toNumber());
+  });
+
+  it("Settle fails before the auction ends", async () => {
+    const transaction = await withSettleEnglishAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(lister),
+      rentalMint
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(lister))
+    ).rejects.toThrow();
+  });
+
+  it("Outbid bidder cannot settle", async () => {
+    await new Promise((r) => setTimeout(r, 10000));
+    const transaction = await withSettleEnglishAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(bidder),
+      rentalMint
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(bidder))
+    ).rejects.toThrow();
+  });
+
+  it("Lister settles into the auction escrow", async () => {
+    const transaction = await withSettleEnglishAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(lister),
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+
+    const englishAuctionData = await getEnglishAuction(
+      provider.connection,
+      rentalMint
+    );
+    expect(englishAuctionData.parsed.settled).toBeTruthy();
+    const escrowMintTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(
+        rentalMint,
+        findEnglishAuctionEscrowAddress(englishAuctionData.pubkey),
+        true
+      )
+    );
+    expect(escrowMintTokenAccount.amount.toString()).toEqual("1");
+    // the lister is only paid once the highest bidder claims the token
+    expect(
+      await provider.connection.getBalance(
+        findEnglishAuctionEscrowAddress(englishAuctionData.pubkey)
+      )
+    ).toEqual(escrowAmount(reserveAmount.add(minIncrement)).toNumber());
+  });
+
+  it("Highest bidder claims the token", async () => {
+    const englishAuctionId = findEnglishAuctionAddress(rentalMint);
+    const listerLamportsBefore = await provider.connection.getBalance(
+      lister.publicKey
+    );
+    const transaction = await withClaimEnglishAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(otherBidder),
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(otherBidder)
+    );
+
+    const bidderMintTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(rentalMint, otherBidder.publicKey, true)
+    );
+    expect(bidderMintTokenAccount.amount.toString()).toEqual("1");
+    const englishAuctionData = await tryGetAccount(() =>
+      getEnglishAuction(provider.connection, rentalMint)
+    );
+    expect(englishAuctionData).toBeNull();
+    expect(
+      await provider.connection.getBalance(
+        findEnglishAuctionEscrowAddress(englishAuctionId)
+      )
+    ).toEqual(0);
+    const listerLamportsAfter = await provider.connection.getBalance(
+      lister.publicKey
+    );
+    expect(listerLamportsAfter).toBeGreaterThan(listerLamportsBefore);
+  });
+
+  it("Close fails while the auction can be settled", async () => {
+    const [transaction] = await withCreateEnglishAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(lister),
+      releasedMint,
+      marketplaceName,
+      {
+        reserveAmount: reserveAmount,
+        minIncrement: minIncrement,
+        endAt: Math.floor(Date.now() / 1000) + 1000,
+        antiSnipeSeconds: 0,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+    await placeBid(bidder, releasedMint, reserveAmount);
+
+    const closeTransaction = await withCloseEnglishAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(otherBidder),
+      releasedMint
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        closeTransaction,
+        new Wallet(otherBidder)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Anyone closes the auction once the token is released", async () => {
+    const releaseTransaction = await withRelease(
+      new Transaction(),
+      provider.connection,
+      new Wallet(lister),
+      releasedMint,
+      findTransferAuthorityAddress(transferAuthorityName),
+      await findAta(releasedMint, lister.publicKey, true)
+    );
+    await executeTransaction(
+      provider.connection,
+      releaseTransaction,
+      new Wallet(lister)
+    );
+
+    const bidderLamportsBefore = await provider.connection.getBalance(
+      bidder.publicKey
+    );
+    const transaction = await withCloseEnglishAuction(
+      new Transaction(),
+      provider.connection,
+      new Wallet(otherBidder),
+      releasedMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(otherBidder)
+    );
+
+    expect(await provider.connection.getBalance(bidder.publicKey)).toEqual(
+      bidderLamportsBefore + escrowAmount(reserveAmount).toNumber()
+    );
+    const englishAuctionData = await tryGetAccount(() =>
+      getEnglishAuction(provider.connection, releasedMint)
+    );
+    expect(englishAuctionData).toBeNull();
+    expect(
+      await provider.connection.getBalance(
+        findEnglishAuctionAddress(releasedMint)
+      )
+    ).toEqual(0);
+  });
+});
@@ -0,0 +1,266 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  createCreateMasterEditionV3Instruction,
+  createCreateMetadataAccountV3Instruction,
+} from "@metaplex-foundation/mpl-token-metadata";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  findMintEditionId,
+  findMintMetadataId,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  withAcceptOffer,
+  withInitMarketplace,
+  withInitTransferAuthority,
+  withMakeOffer,
+  withWrapToken,
+} from "../../src";
+import { findTokenManagerAddress } from "../../src/programs/tokenManager/pda";
+import { getOffer } from "../../src/programs/transferAuthority/accounts";
+import {
+  findOfferAddress,
+  findOfferEscrowAddress,
+} from "../../src/programs/transferAuthority/pda";
+
+describe("Offer", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `lst-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const holder = Keypair.generate();
+  const buyer = Keypair.generate();
+  const creator = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  let rentalMint: PublicKey;
+  const offerPaymentAmount = new BN(1197485);
+  const MAKER_FEE = new BN(360);
+  const TAKER_FEE = new BN(640);
+  const BASIS_POINTS_DIVISOR = new BN(10000);
+
+  const offerId = () =>
+    findOfferAddress(findTokenManagerAddress(rentalMint), buyer.publicKey);
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [holder, buyer, creator, feeCollector]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [, rentalMint] = await createMint(provider.connection, new Wallet(holder));
+
+    const metadataId = findMintMetadataId(rentalMint);
+    const metadataIx = createCreateMetadataAccountV3Instruction(
+      {
+        metadata: metadataId,
+        updateAuthority: holder.publicKey,
+        mint: rentalMint,
+        mintAuthority: holder.publicKey,
+        payer: holder.publicKey,
+      },
+      {
+        createMetadataAccountArgsV3: {
+          data: {
+            name: "test",
+            symbol: "TST",
+            uri: "http://test/",
+            sellerFeeBasisPoints: 0,
+            creators: [
+              {
+                address: creator.publicKey,
+                verified: false,
+                share: 100,
+              },
+            ],
+            collection: null,
+            uses: null,
+          },
+          isMutable: true,
+          collectionDetails: null,
+        },
+      }
+    );
+    const masterEditionIx = createCreateMasterEditionV3Instruction(
+      {
+        edition: findMintEditionId(rentalMint),
+        metadata: metadataId,
+        updateAuthority: holder.publicKey,
+        mint: rentalMint,
+        mintAuthority: holder.publicKey,
+        payer: holder.publicKey,
+      },
+      {
+        createMasterEditionArgs: {
+          maxSupply: new BN(0),
+        },
+      }
+    );
+    const tx = new Transaction();
+    tx.instructions = [metadataIx, masterEditionIx];
+    await executeTransaction(provider.connection, tx, new Wallet(holder));
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: MAKER_FEE.toNumber(),
+      takerFeeBasisPoints: TAKER_FEE.toNumber(),
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const wrapTransaction = new Transaction();
+    await withWrapToken(
+      wrapTransaction,
+      provider.connection,
+      new Wallet(holder),
+      rentalMint,
+      { transferAuthorityName: transferAuthorityName }
+    );
+    await executeTransaction(
+      provider.connection,
+      wrapTransaction,
+      new Wallet(holder)
+    );
+  });
+
+  it("Make offer escrows the payment and taker fee", async () => {
+    const escrowId = findOfferEscrowAddress(offerId());
+    const transaction = new Transaction();
+    await withMakeOffer(
+      transaction,
+      provider.connection,
+      new Wallet(buyer),
+      rentalMint,
+      marketplaceName,
+      offerPaymentAmount
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(buyer)
+    );
+
+    const offerData = await getOffer(provider.connection, offerId());
+    expect(offerData.parsed.buyer.toString()).toEqual(
+      buyer.publicKey.toString()
+    );
+    expect(offerData.parsed.paymentAmount.toString()).toEqual(
+      offerPaymentAmount.toString()
+    );
+    const takerFee = offerPaymentAmount
+      .mul(TAKER_FEE)
+      .div(BASIS_POINTS_DIVISOR);
+    expect(await provider.connection.getBalance(escrowId)).toEqual(
+      offerPaymentAmount.add(takerFee).toNumber()
+    );
+  });
+
+  it("Accept offer fails without the transfer accounts", async () => {
+    const transaction = new Transaction();
+    await withAcceptOffer(
+      transaction,
+      provider.connection,
+      new Wallet(holder),
+      rentalMint,
+      buyer.publicKey,
+      offerPaymentAmount
+    );
+    const acceptOfferIx =
+      transaction.instructions[transaction.instructions.length - 1]!;
+    acceptOfferIx.keys = acceptOfferIx.keys.slice(0, 25);
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(holder), {
+        signers: [buyer],
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Accept offer fails without the buyer signature", async () => {
+    const transaction = new Transaction();
+    await withAcceptOffer(
+      transaction,
+      provider.connection,
+      new Wallet(holder),
+      rentalMint,
+      buyer.publicKey,
+      offerPaymentAmount
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(holder))
+    ).rejects.toThrow();
+  });
+
+  it("Accept offer transfers the token to the buyer", async () => {
+    const holderLamportsBefore = await provider.connection.getBalance(
+      holder.publicKey
+    );
+    const transaction = new Transaction();
+    await withAcceptOffer(
+      transaction,
+      provider.connection,
+      new Wallet(holder),
+      rentalMint,
+      buyer.publicKey,
+      offerPaymentAmount
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(holder),
+      { signers: [buyer] }
+    );
+
+    const buyerMintTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(rentalMint, buyer.publicKey, true)
+    );
+    expect(buyerMintTokenAccount.amount.toString()).toEqual("1");
+    expect(
+      await provider.connection.getBalance(holder.publicKey)
+    ).toBeGreaterThan(holderLamportsBefore);
+    expect(
+      await provider.connection.getBalance(findOfferEscrowAddress(offerId()))
+    ).toEqual(0);
+    const offerData = await tryGetAccount(() =>
+      getOffer(provider.connection, offerId())
+    );
+    expect(offerData).toEqual(null);
+  });
+});
@@ -0,0 +1,219 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  createCreateMasterEditionV3Instruction,
+  createCreateMetadataAccountV3Instruction,
+} from "@metaplex-foundation/mpl-token-metadata";
+import { getAccount } from "@solana/spl-token";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  findMintEditionId,
+  findMintMetadataId,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  withAcceptListing,
+  withCreateListing,
+  withInitMarketplace,
+  withInitTransferAuthority,
+  withWrapToken,
+} from "../../src";
+import { getListing } from "../../src/programs/transferAuthority/accounts";
+
+describe("Private Listing", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `lst-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const lister = Keypair.generate();
+  const buyer = Keypair.generate();
+  const otherBuyer = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  let rentalMint: PublicKey;
+  const rentalPaymentAmount = new BN(100);
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [lister, buyer, otherBuyer]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [, rentalMint] = await createMint(provider.connection, new Wallet(lister));
+
+    const metadataId = findMintMetadataId(rentalMint);
+    const metadataIx = createCreateMetadataAccountV3Instruction(
+      {
+        metadata: metadataId,
+        updateAuthority: lister.publicKey,
+        mint: rentalMint,
+        mintAuthority: lister.publicKey,
+        payer: lister.publicKey,
+      },
+      {
+        createMetadataAccountArgsV3: {
+          data: {
+            name: "test",
+            symbol: "TST",
+            uri: "http://test/",
+            sellerFeeBasisPoints: 0,
+            creators: null,
+            collection: null,
+            uses: null,
+          },
+          isMutable: true,
+          collectionDetails: null,
+        },
+      }
+    );
+    const masterEditionIx = createCreateMasterEditionV3Instruction(
+      {
+        edition: findMintEditionId(rentalMint),
+        metadata: metadataId,
+        updateAuthority: lister.publicKey,
+        mint: rentalMint,
+        mintAuthority: lister.publicKey,
+        payer: lister.publicKey,
+      },
+      {
+        createMasterEditionArgs: {
+          maxSupply: new BN(0),
+        },
+      }
+    );
+    const tx = new Transaction();
+    tx.instructions = [metadataIx, masterEditionIx];
+    await executeTransaction(provider.connection, tx, new Wallet(lister));
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: 500,
+      takerFeeBasisPoints: 0,
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const wrapTransaction = new Transaction();
+    await withWrapToken(
+      wrapTransaction,
+      provider.connection,
+      new Wallet(lister),
+      rentalMint,
+      { transferAuthorityName: transferAuthorityName }
+    );
+    await executeTransaction(
+      provider.connection,
+      wrapTransaction,
+      new Wallet(lister)
+    );
+  });
+
+  it("Create listing for a single buyer", async () => {
+    const [transaction] = await withCreateListing(
+      new Transaction(),
+      provider.connection,
+      new Wallet(lister),
+      rentalMint,
+      marketplaceName,
+      rentalPaymentAmount,
+      PublicKey.default,
+      undefined,
+      { allowedBuyer: buyer.publicKey }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+
+    const checkListing = await getListing(provider.connection, rentalMint);
+    expect(checkListing.parsed.allowedBuyer?.toString()).toEqual(
+      buyer.publicKey.toString()
+    );
+  });
+
+  it("Accept fails for another buyer", async () => {
+    const transaction = await withAcceptListing(
+      new Transaction(),
+      provider.connection,
+      new Wallet(otherBuyer),
+      otherBuyer.publicKey,
+      rentalMint,
+      rentalPaymentAmount,
+      PublicKey.default
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(otherBuyer)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Allowed buyer accepts the listing", async () => {
+    const transaction = await withAcceptListing(
+      new Transaction(),
+      provider.connection,
+      new Wallet(buyer),
+      buyer.publicKey,
+      rentalMint,
+      rentalPaymentAmount,
+      PublicKey.default
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(buyer)
+    );
+
+    const buyerMintTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(rentalMint, buyer.publicKey, true)
+    );
+    expect(buyerMintTokenAccount.amount.toString()).toEqual("1");
+    const checkListing = await tryGetAccount(() =>
+      getListing(provider.connection, rentalMint)
+    );
+    expect(checkListing).toBeNull();
+  });
+});
@@ -0,0 +1,208 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { createCreateMetadataAccountV3Instruction } from "@metaplex-foundation/mpl-token-metadata";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  findMintMetadataId,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  withCreateListing,
+  withInitMarketplace,
+  withInitTransferAuthority,
+  withRemoveExpiredListing,
+  withWrapToken,
+} from "../../src";
+import { findTokenManagerAddress } from "../../src/programs/tokenManager/pda";
+import { getListing } from "../../src/programs/transferAuthority/accounts";
+
+describe("Remove Expired Listing", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `lst-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const lister = Keypair.generate();
+  const cranker = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  let rentalMint: PublicKey;
+  const rentalPaymentAmount = new BN(100);
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [lister, cranker]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [, rentalMint] = await createMint(provider.connection, new Wallet(lister));
+
+    const metadataId = findMintMetadataId(rentalMint);
+    const metadataIx = createCreateMetadataAccountV3Instruction(
+      {
+        metadata: metadataId,
+        updateAuthority: lister.publicKey,
+        mint: rentalMint,
+        mintAuthority: lister.publicKey,
+        payer: lister.publicKey,
+      },
+      {
+        createMetadataAccountArgsV3: {
+          data: {
+            name: "test",
+            symbol: "TST",
+            uri: "http://test/",
+            sellerFeeBasisPoints: 0,
+            creators: null,
+            collection: null,
+            uses: null,
+          },
+          isMutable: true,
+          collectionDetails: null,
+        },
+      }
+    );
+    const tx = new Transaction();
+    tx.instructions = [metadataIx];
+    await executeTransaction(provider.connection, tx, new Wallet(lister));
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: 500,
+      takerFeeBasisPoints: 0,
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const wrapTransaction = new Transaction();
+    await withWrapToken(
+      wrapTransaction,
+      provider.connection,
+      new Wallet(lister),
+      rentalMint,
+      { transferAuthorityName: transferAuthorityName }
+    );
+    await executeTransaction(
+      provider.connection,
+      wrapTransaction,
+      new Wallet(lister)
+    );
+  });
+
+  it("Create listing with an expiration", async () => {
+    const expiresAt = Math.floor(Date.now() / 1000) + 3;
+    const [transaction] = await withCreateListing(
+      new Transaction(),
+      provider.connection,
+      new Wallet(lister),
+      rentalMint,
+      marketplaceName,
+      rentalPaymentAmount,
+      undefined,
+      undefined,
+      { expiresAt }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+
+    const checkListing = await getListing(provider.connection, rentalMint);
+    expect(checkListing.parsed.startAt).toBeNull();
+    expect(checkListing.parsed.expiresAt?.toNumber()).toEqual(expiresAt);
+
+    // without an edition the token is permissioned and delegated while listed
+    const listerMintTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(rentalMint, lister.publicKey, true)
+    );
+    expect(listerMintTokenAccount.delegate?.toString()).toEqual(
+      findTokenManagerAddress(rentalMint).toString()
+    );
+  });
+
+  it("Remove fails before the listing expires", async () => {
+    const transaction = await withRemoveExpiredListing(
+      new Transaction(),
+      provider.connection,
+      new Wallet(cranker),
+      rentalMint
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(cranker))
+    ).rejects.toThrow();
+  });
+
+  it("Anyone removes an expired listing", async () => {
+    await new Promise((r) => setTimeout(r, 5000));
+    const listerLamportsBefore = await provider.connection.getBalance(
+      lister.publicKey
+    );
+
+    const transaction = await withRemoveExpiredListing(
+      new Transaction(),
+      provider.connection,
+      new Wallet(cranker),
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(cranker)
+    );
+
+    const checkListing = await tryGetAccount(() =>
+      getListing(provider.connection, rentalMint)
+    );
+    expect(checkListing).toBeNull();
+    // listing rent goes back to the lister
+    expect(
+      await provider.connection.getBalance(lister.publicKey)
+    ).toBeGreaterThan(listerLamportsBefore);
+
+    // the delegate is revoked without the lister signing
+    const listerMintTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(rentalMint, lister.publicKey, true)
+    );
+    expect(listerMintTokenAccount.delegate).toBeNull();
+    expect(listerMintTokenAccount.amount.toString()).toEqual("1");
+    expect(listerMintTokenAccount.isFrozen).toBeTruthy();
+  });
+});
@@ -0,0 +1,114 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { extendUsages, rentals } from "../../src";
+import { useInvalidator } from "../../src/programs";
+
+describe("Create and Extend Native Usages", () => {
+  let provider: SolanaProvider;
+  const EXTENSION_PAYMENT_AMOUNT = LAMPORTS_PER_SOL / 100;
+  const TOTAL_USAGES = 1;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create and claim rental", async () => {
+    let transaction;
+    [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          totalUsages: TOTAL_USAGES,
+          extension: {
+            extensionPaymentAmount: EXTENSION_PAYMENT_AMOUNT,
+            extensionUsages: 1,
+            extensionPaymentMint: PublicKey.default,
+            maxUsages: 2,
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+  });
+
+  it("Extend pays the issuer in native SOL", async () => {
+    const issuerLamportsBefore = await provider.connection.getBalance(
+      user.publicKey
+    );
+
+    const transaction = await extendUsages(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      1
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const issuerLamportsAfter = await provider.connection.getBalance(
+      user.publicKey
+    );
+    expect(issuerLamportsAfter - issuerLamportsBefore).toEqual(
+      EXTENSION_PAYMENT_AMOUNT
+    );
+
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+    );
+    expect(useInvalidatorData.parsed.totalUsages?.toNumber()).toEqual(
+      TOTAL_USAGES + 1
+    );
+  });
+});
@@ -0,0 +1,154 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { extendUsages, rentals, withResizeUseInvalidator } from "../../src";
+import { useInvalidator } from "../../src/programs";
+
+describe("Create and Extend Tiered Usages", () => {
+  let provider: SolanaProvider;
+  const RECIPIENT_START_PAYMENT_AMOUNT = 1000;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let recipientPaymentTokenAccountId: PublicKey;
+  let issuerTokenAccountId: PublicKey;
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create payment mint
+    [recipientPaymentTokenAccountId, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create and claim rental", async () => {
+    let transaction;
+    [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          totalUsages: 1,
+          extension: {
+            extensionPaymentAmount: 10,
+            extensionUsages: 1,
+            extensionPaymentMint: paymentMint,
+            maxUsages: 10,
+            // bulk rate from 3 usages, never below 6 per usage
+            usagePriceTiers: [
+              { minUsages: 3, paymentAmount: 5, extensionUsages: 1 },
+            ],
+            minPricePerUsage: 6,
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+  });
+
+  it("Quote and extend charge the floored bulk price", async () => {
+    const quote = await useInvalidator.utils.quoteUsages(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      3
+    );
+    expect(quote.toNumber()).toEqual(18);
+
+    const transaction = await extendUsages(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      3
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const checkRecipientPaymentTokenAccount = await getAccount(
+      provider.connection,
+      recipientPaymentTokenAccountId
+    );
+    expect(checkRecipientPaymentTokenAccount.amount.toString()).toEqual(
+      (RECIPIENT_START_PAYMENT_AMOUNT - 18).toString()
+    );
+  });
+
+  it("Resize leaves a current use invalidator unchanged", async () => {
+    const useInvalidatorId =
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId);
+    const accountInfoBefore = await provider.connection.getAccountInfo(
+      useInvalidatorId
+    );
+
+    const transaction = await withResizeUseInvalidator(
+      new Transaction(),
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const accountInfoAfter = await provider.connection.getAccountInfo(
+      useInvalidatorId
+    );
+    expect(accountInfoAfter?.data.length).toEqual(
+      accountInfoBefore?.data.length
+    );
+    expect(accountInfoAfter?.lamports).toEqual(accountInfoBefore?.lamports);
+  });
+});
@@ -0,0 +1,194 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import {
+  extendUsages,
+  releaseUsageEscrow,
+  rentals,
+  useTransaction,
+} from "../../src";
+import { useInvalidator } from "../../src/programs";
+
+describe("Create and extend usages with escrow", () => {
+  let provider: SolanaProvider;
+  const RECIPIENT_START_PAYMENT_AMOUNT = 1000;
+  const EXTENSION_PAYMENT_AMOUNT = 10;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  const getUsageEscrow = async () =>
+    useInvalidator.accounts.getUsageEscrow(
+      provider.connection,
+      useInvalidator.pda.findUsageEscrowAddress(
+        useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+      )
+    );
+
+  const getIssuerPaymentAmount = async () =>
+    (
+      await getAccount(
+        provider.connection,
+        await findAta(paymentMint, user.publicKey)
+      )
+    ).amount.toString();
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create payment mint
+    [, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create and claim rental", async () => {
+    let transaction;
+    [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          totalUsages: 1,
+          extension: {
+            extensionPaymentAmount: EXTENSION_PAYMENT_AMOUNT,
+            extensionUsages: 1,
+            extensionPaymentMint: paymentMint,
+            maxUsages: 2,
+            escrowExtensionPayments: true,
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+  });
+
+  it("Extend pays into the escrow", async () => {
+    const transaction = await extendUsages(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      1
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    // payment target is fixed when the escrow is created
+    const usageEscrowData = await getUsageEscrow();
+    expect(usageEscrowData.parsed.amount.toNumber()).toEqual(
+      EXTENSION_PAYMENT_AMOUNT
+    );
+    expect(usageEscrowData.parsed.usages.toNumber()).toEqual(1);
+    expect(usageEscrowData.parsed.paymentTokenAccount.toString()).toEqual(
+      (await findAta(paymentMint, user.publicKey)).toString()
+    );
+    expect(await getIssuerPaymentAmount()).toEqual("0");
+  });
+
+  it("Release holds back unconsumed usages", async () => {
+    const useTx = await useTransaction(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint,
+      1
+    );
+    await executeTransaction(provider.connection, useTx, new Wallet(recipient));
+
+    const transaction = await releaseUsageEscrow(
+      provider.connection,
+      new Wallet(user),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const usageEscrowData = await getUsageEscrow();
+    expect(usageEscrowData.parsed.amount.toNumber()).toEqual(
+      EXTENSION_PAYMENT_AMOUNT
+    );
+    expect(await getIssuerPaymentAmount()).toEqual("0");
+  });
+
+  it("Release pays out consumed usages", async () => {
+    const useTx = await useTransaction(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint,
+      1
+    );
+    await executeTransaction(provider.connection, useTx, new Wallet(recipient));
+
+    const transaction = await releaseUsageEscrow(
+      provider.connection,
+      new Wallet(user),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const usageEscrowData = await getUsageEscrow();
+    expect(usageEscrowData.parsed.amount.toNumber()).toEqual(0);
+    expect(usageEscrowData.parsed.usages.toNumber()).toEqual(0);
+    expect(await getIssuerPaymentAmount()).toEqual(
+      EXTENSION_PAYMENT_AMOUNT.toString()
+    );
+  });
+});
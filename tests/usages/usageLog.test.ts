@@ -0,0 +1,167 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { initUsageLog, rentals, useTransaction } from "../../src";
+import { tokenManager, useInvalidator } from "../../src/programs";
+import { useInvalidatorProgram } from "../../src/programs/useInvalidator";
+
+describe("Usage log", () => {
+  let provider: SolanaProvider;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  const memo = Array.from(Buffer.from("gate-a".padEnd(32, "\0")));
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  const getUsageLog = async () =>
+    useInvalidator.accounts.getUsageLog(
+      provider.connection,
+      useInvalidator.pda.findUsageLogAddress(
+        useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+      )
+    );
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create and claim rental", async () => {
+    let transaction;
+    [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: { totalUsages: 5 },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+  });
+
+  it("Use without a usage log", async () => {
+    await executeTransaction(
+      provider.connection,
+      await useTransaction(
+        provider.connection,
+        new Wallet(recipient),
+        rentalMint,
+        1
+      ),
+      new Wallet(recipient)
+    );
+  });
+
+  it("Init usage log", async () => {
+    await executeTransaction(
+      provider.connection,
+      await initUsageLog(provider.connection, new Wallet(user), tokenManagerId),
+      new Wallet(user)
+    );
+
+    const usageLogData = await getUsageLog();
+    expect(usageLogData.parsed.totalEntries.toNumber()).toEqual(0);
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+    );
+    expect(useInvalidatorData.parsed.usageLog?.toString()).toEqual(
+      usageLogData.pubkey.toString()
+    );
+  });
+
+  it("Use with a memo is recorded", async () => {
+    await executeTransaction(
+      provider.connection,
+      await useTransaction(
+        provider.connection,
+        new Wallet(recipient),
+        rentalMint,
+        2,
+        undefined,
+        memo
+      ),
+      new Wallet(recipient)
+    );
+
+    const usageLogData = await getUsageLog();
+    expect(usageLogData.parsed.totalEntries.toNumber()).toEqual(1);
+    const entry = usageLogData.parsed.entries[0]!;
+    expect(entry.user.toString()).toEqual(recipient.publicKey.toString());
+    expect(entry.count.toNumber()).toEqual(2);
+    expect(entry.memo).toEqual(memo);
+  });
+
+  it("Use without the usage log account fails", async () => {
+    const useInvalidatorId =
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId);
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    const transaction = new Transaction();
+    transaction.add(
+      await useInvalidatorProgram(provider.connection, new Wallet(recipient))
+        .methods.incrementUsages(new BN(1))
+        .accounts({
+          tokenManager: tokenManagerId,
+          useInvalidator: useInvalidatorId,
+          recipientTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+          user: recipient.publicKey,
+        })
+        .instruction()
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+
+    const usageLogData = await getUsageLog();
+    expect(usageLogData.parsed.totalEntries.toNumber()).toEqual(1);
+  });
+});
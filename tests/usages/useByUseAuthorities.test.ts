@@ -0,0 +1,134 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { rentals, setUseAuthorities, useTransaction } from "../../src";
+import { useInvalidator } from "../../src/programs";
+
+describe("Use by use authorities", () => {
+  let provider: SolanaProvider;
+  const recipient = Keypair.generate();
+  const venueA = Keypair.generate();
+  const venueB = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  const use = async (wallet: Keypair) =>
+    executeTransaction(
+      provider.connection,
+      await useTransaction(
+        provider.connection,
+        new Wallet(wallet),
+        rentalMint,
+        1
+      ),
+      new Wallet(wallet)
+    );
+
+  const getUseAuthorities = async () =>
+    (
+      await useInvalidator.accounts.getUseInvalidator(
+        provider.connection,
+        useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+      )
+    ).parsed.useAuthorities;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient, venueA, venueB]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create and claim rental", async () => {
+    let transaction;
+    [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          totalUsages: 5,
+          useAuthorities: [
+            { authority: venueA.publicKey, quota: 1 },
+            { authority: venueB.publicKey },
+          ],
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+  });
+
+  it("Each use authority is held to its quota", async () => {
+    await expect(use(recipient)).rejects.toThrow();
+
+    await use(venueA);
+    await expect(use(venueA)).rejects.toThrow();
+
+    await use(venueB);
+    await use(venueB);
+
+    const useAuthorities = await getUseAuthorities();
+    expect(useAuthorities?.map((a) => a.usages.toNumber())).toEqual([1, 2]);
+  });
+
+  it("Set use authorities keeps counted usages", async () => {
+    await executeTransaction(
+      provider.connection,
+      await setUseAuthorities(
+        provider.connection,
+        new Wallet(user),
+        tokenManagerId,
+        [{ authority: venueA.publicKey, quota: 2 }]
+      ),
+      new Wallet(user)
+    );
+
+    await use(venueA);
+    await expect(use(venueA)).rejects.toThrow();
+    await expect(use(venueB)).rejects.toThrow();
+
+    const useAuthorities = await getUseAuthorities();
+    expect(useAuthorities?.length).toEqual(1);
+    expect(useAuthorities?.[0]?.authority.toString()).toEqual(
+      venueA.publicKey.toString()
+    );
+    expect(useAuthorities?.[0]?.usages.toNumber()).toEqual(2);
+  });
+});
@@ -0,0 +1,115 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { rentals, useTransaction } from "../../src";
+import { useInvalidator } from "../../src/programs";
+
+describe("Use rate limited", () => {
+  let provider: SolanaProvider;
+  const PERIOD_SECONDS = 3;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  const use = async (usages: number) =>
+    executeTransaction(
+      provider.connection,
+      await useTransaction(
+        provider.connection,
+        new Wallet(recipient),
+        rentalMint,
+        usages
+      ),
+      new Wallet(recipient)
+    );
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdropCreator = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropCreator);
+
+    const airdropRecipient = await provider.connection.requestAirdrop(
+      recipient.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropRecipient);
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create and claim rental", async () => {
+    let transaction;
+    [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          totalUsages: 10,
+          rateLimit: { maxUsagesPerPeriod: 2, periodSeconds: PERIOD_SECONDS },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+  });
+
+  it("Rejects a burst over the period limit", async () => {
+    await expect(use(3)).rejects.toThrow();
+
+    await use(2);
+    await expect(use(1)).rejects.toThrow();
+
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+    );
+    expect(useInvalidatorData.parsed.usages.toNumber()).toEqual(2);
+    expect(useInvalidatorData.parsed.periodUsages.toNumber()).toEqual(2);
+  });
+
+  it("Allows usages again once the period has ended", async () => {
+    await new Promise((r) => setTimeout(r, (PERIOD_SECONDS + 1) * 1000));
+    await use(2);
+
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+    );
+    expect(useInvalidatorData.parsed.usages.toNumber()).toEqual(4);
+    expect(useInvalidatorData.parsed.periodUsages.toNumber()).toEqual(2);
+  });
+});
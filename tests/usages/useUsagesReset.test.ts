@@ -0,0 +1,135 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { invalidate, rentals, useTransaction } from "../../src";
+import { tokenManager, useInvalidator } from "../../src/programs";
+import { TokenManagerState } from "../../src/programs/tokenManager";
+
+describe("Use usages reset", () => {
+  let provider: SolanaProvider;
+  const USAGES_RESET_SECONDS = 3;
+  const recipient = Keypair.generate();
+  const venue = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  const use = async () =>
+    executeTransaction(
+      provider.connection,
+      await useTransaction(
+        provider.connection,
+        new Wallet(venue),
+        rentalMint,
+        1
+      ),
+      new Wallet(venue)
+    );
+
+  const getUseInvalidator = async () =>
+    useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+    );
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient, venue]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create and claim rental", async () => {
+    let transaction;
+    [transaction, tokenManagerId] = await rentals.createRental(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          totalUsages: 2,
+          usagesResetSeconds: USAGES_RESET_SECONDS,
+          useAuthorities: [{ authority: venue.publicKey, quota: 2 }],
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+  });
+
+  it("Using up a period does not invalidate the token", async () => {
+    await use();
+    await use();
+    await expect(use()).rejects.toThrow();
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Claimed);
+    const useInvalidatorData = await getUseInvalidator();
+    expect(useInvalidatorData.parsed.usages.toNumber()).toEqual(2);
+  });
+
+  it("Invalidate fails once a period's usages are used up", async () => {
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await invalidate(provider.connection, new Wallet(user), rentalMint),
+        new Wallet(user)
+      )
+    ).rejects.toThrow();
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Claimed);
+  });
+
+  it("Usages and quotas reset with the next period", async () => {
+    await new Promise((r) => setTimeout(r, (USAGES_RESET_SECONDS + 1) * 1000));
+    await use();
+
+    const useInvalidatorData = await getUseInvalidator();
+    expect(useInvalidatorData.parsed.usages.toNumber()).toEqual(1);
+    expect(
+      useInvalidatorData.parsed.useAuthorities?.[0]?.usages.toNumber()
+    ).toEqual(1);
+  });
+});